@@ -0,0 +1,84 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use serde_json::{json, Value};
+use std::fs;
+use telegram_types::bot::methods::{GetUpdates, Method};
+use telegram_types::bot::types::Message;
+
+/// A `getUpdates` response of 100 updates, cycling through the fixtures of every update kind
+/// and of messages with every media type.
+fn mixed_updates() -> String {
+    let mut fixtures = Vec::new();
+    for dir in &["tests/json/updates", "tests/json/media"] {
+        let mut paths: Vec<_> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        paths.sort();
+        for path in paths {
+            let json = fs::read_to_string(&path).unwrap();
+            if dir.ends_with("media") {
+                fixtures.push(format!(r#"{{"update_id":1,"message":{}}}"#, json));
+            } else {
+                fixtures.push(json);
+            }
+        }
+    }
+    let updates: Vec<&str> = fixtures.iter().cycle().take(100).map(|s| &s[..]).collect();
+    format!(r#"{{"ok":true,"result":[{}]}}"#, updates.join(","))
+}
+
+/// A long captioned photo replying to another message, like a formatted digest post.
+fn large_message() -> String {
+    let raw = fs::read_to_string("tests/json/message.json").unwrap();
+    let reply: Value = serde_json::from_str(&raw).unwrap();
+    let mut message = reply.clone();
+    let tags: Vec<String> = (0..500).map(|i| format!("#tag{:03}", i)).collect();
+    let entities: Vec<Value> = (0..500)
+        .map(|i| json!({"offset": i * 8, "length": 7, "type": "hashtag"}))
+        .collect();
+    let photo: Vec<Value> = (0..4)
+        .map(|i| {
+            json!({
+                "file_id": format!("AgACAgIAAxkBAAIC{}", i),
+                "file_unique_id": format!("AQAD{}", i),
+                "width": 320 << i,
+                "height": 180 << i,
+            })
+        })
+        .collect();
+    let fields = message.as_object_mut().unwrap();
+    fields.remove("text");
+    fields.remove("entities");
+    fields.insert("reply_to_message".into(), reply);
+    fields.insert("forward_date".into(), json!(1528706000));
+    fields.insert("forward_sender_name".into(), json!("Madoka"));
+    fields.insert("caption".into(), json!(tags.join(" ")));
+    fields.insert("caption_entities".into(), json!(entities));
+    fields.insert("photo".into(), json!(photo));
+    message.to_string()
+}
+
+fn batch(c: &mut Criterion) {
+    let raw = mixed_updates();
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Elements(100));
+    group.bench_function("100 mixed updates", |b| {
+        b.iter(|| {
+            GetUpdates::response_from_slice(raw.as_bytes())
+                .unwrap()
+                .unwrap()
+        })
+    });
+    group.finish();
+
+    let raw = large_message();
+    let mut group = c.benchmark_group("message");
+    group.throughput(Throughput::Bytes(raw.len() as u64));
+    group.bench_function("large", |b| {
+        b.iter(|| serde_json::from_str::<Message>(&raw).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, batch);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use telegram_types::bot::methods::{GetUpdates, Method};
+
+fn updates(c: &mut Criterion) {
+    let raw = include_bytes!("../tests/json/update.json");
+    let mut group = c.benchmark_group("updates");
+    group.throughput(Throughput::Bytes(raw.len() as u64));
+    group.bench_function("serde_json", |b| {
+        b.iter(|| GetUpdates::response_from_slice(raw).unwrap().unwrap())
+    });
+    group.bench_function("simd-json", |b| {
+        b.iter_batched_ref(
+            || raw.to_vec(),
+            |bytes| {
+                GetUpdates::response_from_simd_slice(bytes)
+                    .unwrap()
+                    .unwrap()
+            },
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, updates);
+criterion_main!(benches);
@@ -1,21 +1,9 @@
-use telegram_types::bot::methods::{GetMe, Method, TelegramResult};
-
-async fn make_request<T: Method>(data: T) -> TelegramResult<T::Item> {
-    let token = std::env::var("BOT_TOKEN").unwrap();
-    let client = reqwest::Client::new();
-    let res = client
-        .post(T::url(&*token))
-        .body(serde_json::to_string(&data).unwrap())
-        .send()
-        .await
-        .unwrap();
-    let res = res.text().await.unwrap();
-    println!("{:?}", res);
-    serde_json::from_str(&res).unwrap()
-}
+use telegram_types::bot::methods::GetMe;
+use telegram_types::client::Bot;
 
 #[tokio::main]
 async fn main() {
-    let get_me = make_request(GetMe).await;
+    let bot = Bot::new(std::env::var("BOT_TOKEN").unwrap());
+    let get_me = bot.call(&GetMe).await;
     println!("{:?}", get_me);
 }
@@ -0,0 +1,10 @@
+//! A message on its own, which is most of what an update can carry.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use telegram_types::bot::types::Message;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(message) = serde_json::from_slice::<Message>(data) {
+        serde_json::to_vec(&message).unwrap();
+    }
+});
@@ -0,0 +1,10 @@
+//! A whole `getUpdates` response, successful or not.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use telegram_types::bot::methods::UpdateList;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(response) = serde_json::from_slice::<UpdateList>(data) {
+        let _ = response.into_result();
+    }
+});
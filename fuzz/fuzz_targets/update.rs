@@ -0,0 +1,10 @@
+//! A single webhook payload.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use telegram_types::bot::types::Update;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(update) = serde_json::from_slice::<Update>(data) {
+        serde_json::to_vec(&update).unwrap();
+    }
+});
@@ -0,0 +1,200 @@
+//! Borrowed counterparts of the most frequently received types.
+//!
+//! These mirror a subset of [`types`](super::types) with strings borrowed from the input
+//! where possible, so a webhook server can look at an update without copying every string
+//! in it. Fields not listed here are skipped; deserialize into the owned types when you
+//! need the whole object.
+//!
+//! Strings are only borrowed when the input contains no escape sequences, so deserialize
+//! from a `&str` or `&[u8]` that outlives the values (e.g. `serde_json::from_slice`).
+use super::types::{
+    ChatId, MessageEntityKind, MessageId, Time, UpdateId, UserId, Utf16Len, Utf16Offset,
+};
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use core::fmt;
+use serde::de::{Error as DeError, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+/// An incoming update, see [`types::Update`](super::types::Update).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Update<'a> {
+    pub update_id: UpdateId,
+    pub content: UpdateContent<'a>,
+}
+
+/// Content of an [`Update`], only the common kinds are distinguished.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UpdateContent<'a> {
+    Message(Message<'a>),
+    EditedMessage(Message<'a>),
+    ChannelPost(Message<'a>),
+    EditedChannelPost(Message<'a>),
+    CallbackQuery(CallbackQuery<'a>),
+    /// Any other kind of update.
+    Other,
+}
+
+/// A string borrowed if possible; `#[serde(borrow)]` only applies to bare `Cow<str>` fields.
+#[derive(Deserialize)]
+struct Str<'a>(#[serde(borrow)] Cow<'a, str>);
+
+fn optional_str<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Str<'a>>::deserialize(deserializer)?.map(|Str(s)| s))
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for Update<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Update<'a>, D::Error> {
+        struct UpdateVisitor<'a>(core::marker::PhantomData<&'a ()>);
+
+        impl<'de: 'a, 'a> Visitor<'de> for UpdateVisitor<'a> {
+            type Value = Update<'a>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an update object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Update<'a>, A::Error> {
+                use self::UpdateContent::*;
+                let mut update_id = None;
+                let mut content = None;
+                while let Some(Str(key)) = map.next_key()? {
+                    content = match &*key {
+                        "update_id" => {
+                            update_id = Some(map.next_value()?);
+                            continue;
+                        }
+                        _ if content.is_some() => {
+                            map.next_value::<IgnoredAny>()?;
+                            continue;
+                        }
+                        "message" => Some(Message(map.next_value()?)),
+                        "edited_message" => Some(EditedMessage(map.next_value()?)),
+                        "channel_post" => Some(ChannelPost(map.next_value()?)),
+                        "edited_channel_post" => Some(EditedChannelPost(map.next_value()?)),
+                        "callback_query" => Some(CallbackQuery(map.next_value()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                            None
+                        }
+                    };
+                }
+                Ok(Update {
+                    update_id: update_id.ok_or_else(|| A::Error::missing_field("update_id"))?,
+                    content: content.unwrap_or(Other),
+                })
+            }
+        }
+
+        deserializer.deserialize_map(UpdateVisitor(core::marker::PhantomData))
+    }
+}
+
+/// A message, see [`types::Message`](super::types::Message).
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Message<'a> {
+    pub message_id: MessageId,
+    #[serde(borrow)]
+    pub from: Option<User<'a>>,
+    #[serde(borrow)]
+    pub sender_chat: Option<Chat<'a>>,
+    pub date: Time,
+    #[serde(borrow)]
+    pub chat: Chat<'a>,
+    #[serde(borrow)]
+    pub reply_to_message: Option<Box<Message<'a>>>,
+    pub edit_date: Option<Time>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub media_group_id: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub author_signature: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub text: Option<Cow<'a, str>>,
+    #[serde(borrow, default)]
+    pub entities: Vec<MessageEntity<'a>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub caption: Option<Cow<'a, str>>,
+    #[serde(borrow, default)]
+    pub caption_entities: Vec<MessageEntity<'a>>,
+}
+
+/// A special entity in a text, see [`types::MessageEntity`](super::types::MessageEntity).
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MessageEntity<'a> {
+    #[serde(rename = "type")]
+    pub kind: MessageEntityKind,
+    pub offset: Utf16Offset,
+    pub length: Utf16Len,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub url: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub user: Option<User<'a>>,
+}
+
+/// A user or bot, see [`types::User`](super::types::User).
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct User<'a> {
+    pub id: UserId,
+    pub is_bot: bool,
+    #[serde(borrow)]
+    pub first_name: Cow<'a, str>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub last_name: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub username: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+/// A chat, see [`types::Chat`](super::types::Chat).
+///
+/// The fields of all chat types are flattened into one struct.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Chat<'a> {
+    pub id: ChatId,
+    #[serde(rename = "type")]
+    pub kind: ChatKind,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub title: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub username: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub first_name: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub last_name: Option<Cow<'a, str>>,
+}
+
+/// Type of a [`Chat`].
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatKind {
+    Private,
+    Group,
+    Supergroup,
+    Channel,
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+/// A callback query, see [`types::CallbackQuery`](super::types::CallbackQuery).
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CallbackQuery<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub from: User<'a>,
+    #[serde(borrow)]
+    pub message: Option<Box<Message<'a>>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub inline_message_id: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub chat_instance: Cow<'a, str>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub data: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "optional_str")]
+    pub game_short_name: Option<Cow<'a, str>>,
+}
@@ -1,4 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 /// A placeholder, currently holds no information.
 /// Use [BotFather](https://t.me/botfather) to set up your game.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CallbackGame;
@@ -8,20 +8,92 @@
 //! your bot’s name.
 
 use super::types::{InlineKeyboardMarkup, Location, ParseMode, User};
-use std::borrow::Cow;
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use core::fmt;
+#[cfg(feature = "full-serde")]
+use serde::de::Error as DeError;
+#[cfg(feature = "full-serde")]
+use serde::Deserializer;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::collections::hash_map::DefaultHasher;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::hash::{Hash, Hasher};
 
 /// Unique identifier for the answered query
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InlineQueryId(pub String);
 
 /// Unique identifier for the result
 ///
 /// 1-64 bytes
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ResultId(pub String);
 
+impl ResultId {
+    /// Maximum length of a result identifier, in bytes.
+    pub const MAX_LEN: usize = 64;
+
+    /// Check that `id` is 1-64 bytes long.
+    pub fn new<T: Into<String>>(id: T) -> Result<ResultId, InvalidResultId> {
+        let id = id.into();
+        if id.is_empty() || id.len() > ResultId::MAX_LEN {
+            Err(InvalidResultId(id))
+        } else {
+            Ok(ResultId(id))
+        }
+    }
+
+    /// Derive an identifier from the hash of `value`.
+    ///
+    /// Equal values get equal identifiers within one build of the program, which is
+    /// enough to keep results apart in a single answer. The hasher is not guaranteed
+    /// to be stable across Rust releases, so don't persist these identifiers.
+    #[cfg(feature = "std")]
+    pub fn from_hash<T: Hash + ?Sized>(value: &T) -> ResultId {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        ResultId(format!("{:016x}", hasher.finish()))
+    }
+
+    /// A random (v4) UUID in its 32 character simple form.
+    #[cfg(feature = "uuid")]
+    pub fn random() -> ResultId {
+        ResultId(uuid::Uuid::new_v4().simple().to_string())
+    }
+}
+
+/// A result identifier that is empty or longer than [`ResultId::MAX_LEN`] bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidResultId(pub String);
+
+impl fmt::Display for InvalidResultId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "result id must be 1-{} bytes, got {} bytes",
+            ResultId::MAX_LEN,
+            self.0.len()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidResultId {}
+
 /// An incoming inline query.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InlineQuery {
     /// Unique identifier for this query
     pub id: InlineQueryId,
@@ -39,16 +111,20 @@ pub struct InlineQuery {
 /// Use this method to send answers to an inline query.
 ///
 /// No more than 50 results per query are allowed.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AnswerInlineQuery<'a> {
     /// Unique identifier for the answered query
     pub inline_query_id: InlineQueryId,
     /// A array of results for the inline query
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub results: Cow<'a, [InlineQueryResult<'a>]>,
     /// The maximum amount of time in seconds that the result of the inline query may be cached
     /// on the server. Defaults to 300.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_time: Option<i32>,
+    pub cache_time: Option<i64>,
     /// Pass True, if results may be cached on the server side only for the user that sent
     /// the query. By default, results may be returned to any user who sends the same query
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -57,24 +133,186 @@ pub struct AnswerInlineQuery<'a> {
     /// receive more results. Pass an empty string if there are no more results or if you don‘t
     /// support pagination. Offset length can’t exceed 64 bytes.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub next_offset: Option<Cow<'a, str>>,
     /// If passed, clients will display a button with specified text that switches the user to
     /// a private chat with the bot and sends the bot a start message with the parameter
     /// switch_pm_parameter
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub switch_pm_text: Option<Cow<'a, str>>,
     /// Deep-linking parameter for the /start message sent to the bot when user presses the
     /// switch button. 1-64 characters, only `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub switch_pm_parameter: Option<Cow<'a, str>>,
 }
 
-impl_method!(AnswerInlineQuery<'_>, "answerInlineQuery", bool);
+impl_method!(AnswerInlineQuery<'_>, "answerInlineQuery", bool, "1.0");
+
+impl<'a> AnswerInlineQuery<'a> {
+    /// Maximum number of results in one answer.
+    pub const MAX_RESULTS: usize = 50;
+
+    pub fn new<T>(inline_query_id: InlineQueryId, results: T) -> AnswerInlineQuery<'a>
+    where
+        T: Into<Cow<'a, [InlineQueryResult<'a>]>>,
+    {
+        AnswerInlineQuery {
+            inline_query_id,
+            results: results.into(),
+            cache_time: None,
+            is_personal: None,
+            next_offset: None,
+            switch_pm_text: None,
+            switch_pm_parameter: None,
+        }
+    }
+
+    /// Answer with the page of `results` that `offset`, the [`InlineQuery::offset`] received,
+    /// asks for.
+    ///
+    /// Pages hold `per_page` results, clamped to 1-[`MAX_RESULTS`](Self::MAX_RESULTS). The
+    /// next page is set as [`next_offset`](Self::next_offset) if `results` has more, so
+    /// `results` must be in the same order for every query with the same text. An offset that
+    /// isn't a page number starts from the first page.
+    pub fn page<I>(
+        inline_query_id: InlineQueryId,
+        results: I,
+        offset: &str,
+        per_page: usize,
+    ) -> AnswerInlineQuery<'a>
+    where
+        I: IntoIterator<Item = InlineQueryResult<'a>>,
+    {
+        let per_page = per_page.clamp(1, AnswerInlineQuery::MAX_RESULTS);
+        let page = match InlineOffset::parse(offset) {
+            Some(InlineOffset::Page(page)) => page,
+            _ => 0,
+        };
+        let skip = (page as usize).saturating_mul(per_page);
+        let mut results = results.into_iter().skip(skip).peekable();
+        let page_results: Vec<_> = results.by_ref().take(per_page).collect();
+        let answer = AnswerInlineQuery::new(inline_query_id, page_results);
+        match (results.peek(), page.checked_add(1)) {
+            (Some(_), Some(next)) => answer.next_offset(InlineOffset::Page(next)),
+            _ => answer,
+        }
+    }
+
+    /// Set the offset the client sends for more results.
+    pub fn next_offset(self, offset: InlineOffset) -> AnswerInlineQuery<'a> {
+        AnswerInlineQuery {
+            next_offset: Some(offset.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn cache_time(self, seconds: i64) -> AnswerInlineQuery<'a> {
+        AnswerInlineQuery {
+            cache_time: Some(seconds),
+            ..self
+        }
+    }
+
+    pub fn is_personal(self, is_personal: bool) -> AnswerInlineQuery<'a> {
+        AnswerInlineQuery {
+            is_personal: Some(is_personal),
+            ..self
+        }
+    }
+}
+
+/// Where the results of an inline query continue, sent in [`AnswerInlineQuery::next_offset`]
+/// and received back in [`InlineQuery::offset`].
+///
+/// A page number is encoded as its digits and a cursor after a `~`, so the two never mix up.
+///
+/// ```
+/// use telegram_types::bot::inline_mode::InlineOffset;
+///
+/// let cursor = InlineOffset::cursor("2024-05-01/madoka").unwrap();
+/// assert_eq!(cursor.to_string(), "~2024-05-01/madoka");
+/// assert_eq!(InlineOffset::parse("~2024-05-01/madoka"), Some(cursor));
+/// assert_eq!(InlineOffset::parse(""), Some(InlineOffset::Page(0)));
+/// assert_eq!(InlineOffset::parse("3"), Some(InlineOffset::Page(3)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InlineOffset {
+    /// A page number, 0 for the first query.
+    Page(u32),
+    /// A position in the bot's own results, at most [`MAX_CURSOR_LEN`](Self::MAX_CURSOR_LEN)
+    /// bytes.
+    Cursor(String),
+}
+
+impl InlineOffset {
+    /// Maximum length of an encoded offset, in bytes.
+    pub const MAX_LEN: usize = 64;
+    /// Maximum length of a cursor, in bytes, leaving room for the `~`.
+    pub const MAX_CURSOR_LEN: usize = InlineOffset::MAX_LEN - 1;
+
+    /// Check that `cursor` is at most [`MAX_CURSOR_LEN`](Self::MAX_CURSOR_LEN) bytes long.
+    pub fn cursor<T: Into<String>>(cursor: T) -> Result<InlineOffset, InvalidInlineOffset> {
+        let cursor = cursor.into();
+        if cursor.len() > InlineOffset::MAX_CURSOR_LEN {
+            Err(InvalidInlineOffset(cursor))
+        } else {
+            Ok(InlineOffset::Cursor(cursor))
+        }
+    }
+
+    /// Decode the offset of a query. The first query has an empty offset, which is page 0.
+    /// `None` for an offset not encoded by this type.
+    pub fn parse(offset: &str) -> Option<InlineOffset> {
+        if offset.is_empty() {
+            return Some(InlineOffset::Page(0));
+        }
+        if let Some(cursor) = offset.strip_prefix('~') {
+            return InlineOffset::cursor(cursor).ok();
+        }
+        if !offset.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        offset.parse().ok().map(InlineOffset::Page)
+    }
+}
+
+impl fmt::Display for InlineOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InlineOffset::Page(page) => write!(f, "{}", page),
+            InlineOffset::Cursor(cursor) => write!(f, "~{}", cursor),
+        }
+    }
+}
+
+/// A cursor longer than [`InlineOffset::MAX_CURSOR_LEN`] bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidInlineOffset(pub String);
+
+impl fmt::Display for InvalidInlineOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cursor must be at most {} bytes, got {} bytes",
+            InlineOffset::MAX_CURSOR_LEN,
+            self.0.len()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidInlineOffset {}
 
 /// One result of an inline query.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
 pub enum InlineQueryResult<'a> {
     Article(InlineQueryResultArticle<'a>),
     // TODO: implement these placeholders
@@ -103,11 +341,15 @@ pub enum InlineQueryResult<'a> {
 }
 
 /// A link to an article or web page.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InlineQueryResultArticle<'a> {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: ResultId,
     /// Title of the result
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub title: Cow<'a, str>,
     /// Content of the message to be sent
     pub input_message_content: InputMessageContent<'a>,
@@ -116,26 +358,31 @@ pub struct InlineQueryResultArticle<'a> {
     pub reply_markup: Option<InlineKeyboardMarkup>,
     /// URL of the result
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub url: Option<Cow<'a, str>>,
     /// Pass True, if you don't want the URL to be shown in the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_url: Option<bool>,
     /// Short description of the result
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub description: Option<Cow<'a, str>>,
     /// Url of the thumbnail for the result
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub thumb_url: Option<Cow<'a, str>>,
     /// Thumbnail width
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumb_width: Option<i32>,
+    pub thumb_width: Option<i64>,
     /// Thumbnail height
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumb_height: Option<i32>,
+    pub thumb_height: Option<i64>,
 }
 
 /// The content of a message to be sent as a result of an inline query.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum InputMessageContent<'a> {
     Text(InputTextMessageContent<'a>),
@@ -144,10 +391,86 @@ pub enum InputMessageContent<'a> {
     Contact(InputContactMessageContent<'a>),
 }
 
+#[cfg(feature = "full-serde")]
+impl<'de, 'a> Deserialize<'de> for InputMessageContent<'a> {
+    /// Read the fields of every kind in one pass, then pick the kind by its required fields.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct AnyContent<'a> {
+            message_text: Option<Cow<'a, str>>,
+            parse_mode: Option<ParseMode>,
+            disable_web_page_preview: Option<bool>,
+            latitude: Option<f32>,
+            longitude: Option<f32>,
+            live_period: Option<i64>,
+            title: Option<Cow<'a, str>>,
+            address: Option<Cow<'a, str>>,
+            foursquare_id: Option<Cow<'a, str>>,
+            foursquare_type: Option<Cow<'a, str>>,
+            phone_number: Option<Cow<'a, str>>,
+            first_name: Option<Cow<'a, str>>,
+            last_name: Option<Cow<'a, str>>,
+            vcard: Option<Cow<'a, str>>,
+        }
+
+        let content = AnyContent::deserialize(deserializer)?;
+        if let Some(message_text) = content.message_text {
+            return Ok(InputMessageContent::Text(InputTextMessageContent {
+                message_text,
+                parse_mode: content.parse_mode,
+                disable_web_page_preview: content.disable_web_page_preview,
+            }));
+        }
+        if let Some(phone_number) = content.phone_number {
+            let first_name = content
+                .first_name
+                .ok_or_else(|| D::Error::missing_field("first_name"))?;
+            return Ok(InputMessageContent::Contact(InputContactMessageContent {
+                phone_number,
+                first_name,
+                last_name: content.last_name,
+                vcard: content.vcard,
+            }));
+        }
+        let (latitude, longitude) = match (content.latitude, content.longitude) {
+            (Some(latitude), Some(longitude)) => (latitude, longitude),
+            (Some(_), None) => return Err(D::Error::missing_field("longitude")),
+            (None, _) => {
+                return Err(D::Error::custom(
+                    "expected one of `message_text`, `phone_number` or `latitude`",
+                ))
+            }
+        };
+        match (content.title, content.address) {
+            (Some(title), Some(address)) => {
+                Ok(InputMessageContent::Venue(InputVenueMessageContent {
+                    latitude,
+                    longitude,
+                    title,
+                    address,
+                    foursquare_id: content.foursquare_id,
+                    foursquare_type: content.foursquare_type,
+                }))
+            }
+            (Some(_), None) => Err(D::Error::missing_field("address")),
+            (None, Some(_)) => Err(D::Error::missing_field("title")),
+            (None, None) => Ok(InputMessageContent::Location(InputLocationMessageContent {
+                latitude,
+                longitude,
+                live_period: content.live_period,
+            })),
+        }
+    }
+}
+
 /// The content of a text message to be sent as the result of an inline query.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InputTextMessageContent<'a> {
     /// Text of the message to be sent, 1-4096 characters
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub message_text: Cow<'a, str>,
     /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
     /// fixed-width text or inline URLs in your bot's message.
@@ -159,7 +482,10 @@ pub struct InputTextMessageContent<'a> {
 }
 
 /// The content of a location message to be sent as the result of an inline query.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InputLocationMessageContent {
     /// Latitude of the location in degrees
     pub latitude: f32,
@@ -168,46 +494,64 @@ pub struct InputLocationMessageContent {
     /// Period in seconds for which the location can be updated, should be
     /// between 60 and 86400.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub live_period: Option<i32>,
+    pub live_period: Option<i64>,
 }
 
 /// The content of a venue message to be sent as the result of an inline query.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InputVenueMessageContent<'a> {
     /// Latitude of the venue in degrees
     pub latitude: f32,
     /// Longitude of the venue in degrees
     pub longitude: f32,
     /// Name of the venue
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub title: Cow<'a, str>,
     /// Address of the venue
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub address: Cow<'a, str>,
     /// Foursquare identifier of the venue, if known
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub foursquare_id: Option<Cow<'a, str>>,
     /// Foursquare type of the venue, if known. (For example,
     /// “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub foursquare_type: Option<Cow<'a, str>>,
 }
 
 /// The content of a contact message to be sent as the result of an inline query.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InputContactMessageContent<'a> {
     /// Contact's phone number
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub phone_number: Cow<'a, str>,
     /// Contact's first name
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub first_name: Cow<'a, str>,
     /// Contact's last name
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub last_name: Option<Cow<'a, str>>,
     /// Additional data about the contact in the form of a vCard, 0-2048 bytes
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub vcard: Option<Cow<'a, str>>,
 }
 
 /// A result of an inline query that was chosen by the user and sent to their chat partner.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChosenInlineResult {
     /// The unique identifier for the result that was chosen
     pub result_id: ResultId,
@@ -220,6 +564,7 @@ pub struct ChosenInlineResult {
     /// [inline keyboard](InlineKeyboardMarkup) attached to the message. Will
     /// be also received in callback queries and can be used to edit the
     /// message.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
     /// The query that was used to obtain the result
     pub query: String,
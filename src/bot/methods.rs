@@ -2,23 +2,38 @@
 use super::types;
 use super::types::InputMedia;
 use super::types::{
-    ChatId, FileToSend, ForceReply, InlineKeyboardMarkup, MessageId, ParseMode,
-    ReplyKeyboardMarkup, ReplyKeyboardRemove, UpdateId, UserId,
+    Attachment, ChatAdministratorRights, ChatId, ChatPermissions, FileToSend, ForceReply,
+    InlineKeyboardMarkup, InputFile, MessageId, ParseMode, ReplyKeyboardMarkup,
+    ReplyKeyboardRemove, UpdateId, UserId,
 };
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use core::default::Default;
+use core::fmt;
 use serde::de::DeserializeOwned;
-use serde::Serialize;
-use std::borrow::Cow;
-use std::default::Default;
+#[cfg(feature = "full-serde")]
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
+#[cfg(feature = "std")]
 use std::net::IpAddr;
 
 /// Chat integer identifier or username
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum ChatTarget<'a> {
     Id(ChatId),
-    Username(Cow<'a, str>),
+    Username(#[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_username))] Cow<'a, str>),
+}
+
+/// An `@username`, bare numbers would deserialize as [`ChatTarget::Id`].
+#[cfg(feature = "arbitrary")]
+fn arbitrary_username<'b>(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Cow<'b, str>> {
+    Ok(Cow::Owned(format!("@{}", u.arbitrary::<String>()?)))
 }
 
 impl<'a> ChatTarget<'a> {
@@ -31,18 +46,152 @@ impl<'a> ChatTarget<'a> {
     }
 }
 
+/// The id, or the `@username` as it was given.
+impl fmt::Display for ChatTarget<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChatTarget::Id(id) => write!(f, "{}", id.0),
+            ChatTarget::Username(name) => f.write_str(name),
+        }
+    }
+}
+
+/// A field of an edit method, which can be left as it is, removed or replaced.
+///
+/// [`Clear`](FieldUpdate::Clear) is sent as the empty value of the field, an empty caption or
+/// keyboard, which is how the Bot API removes it. [`Keep`](FieldUpdate::Keep) leaves the field out
+/// of the request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum FieldUpdate<T> {
+    #[default]
+    Keep,
+    Clear,
+    Set(T),
+}
+
+impl<T> FieldUpdate<T> {
+    pub fn is_keep(&self) -> bool {
+        matches!(self, FieldUpdate::Keep)
+    }
+
+    /// The new value, if the field is replaced.
+    pub fn as_set(&self) -> Option<&T> {
+        match self {
+            FieldUpdate::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for FieldUpdate<T> {
+    fn from(value: T) -> FieldUpdate<T> {
+        FieldUpdate::Set(value)
+    }
+}
+
+impl<T: Serialize + Default> Serialize for FieldUpdate<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FieldUpdate::Keep => serializer.serialize_none(),
+            FieldUpdate::Clear => T::default().serialize(serializer),
+            FieldUpdate::Set(value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// A missing field is [`Keep`](FieldUpdate::Keep) through `#[serde(default)]`, and `null` or the
+/// empty value is [`Clear`](FieldUpdate::Clear).
+#[cfg(feature = "full-serde")]
+impl<'de, T: Deserialize<'de> + Default + PartialEq> Deserialize<'de> for FieldUpdate<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<FieldUpdate<T>, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) if value != T::default() => FieldUpdate::Set(value),
+            _ => FieldUpdate::Clear,
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema> schemars::JsonSchema for FieldUpdate<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        generator.subschema_for::<T>()
+    }
+}
+
+/// `Set` with the empty value is generated as `Clear`, which is what it deserializes back to.
+#[cfg(feature = "arbitrary")]
+impl<'b, T: arbitrary::Arbitrary<'b> + Default + PartialEq> arbitrary::Arbitrary<'b>
+    for FieldUpdate<T>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'b>) -> arbitrary::Result<FieldUpdate<T>> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => FieldUpdate::Keep,
+            1 => FieldUpdate::Clear,
+            _ => match T::arbitrary(u)? {
+                value if value == T::default() => FieldUpdate::Clear,
+                value => FieldUpdate::Set(value),
+            },
+        })
+    }
+}
+
+/// The message an `editMessage*` method applies to: one in a chat, or one sent via the bot in
+/// inline mode.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum EditTarget<'a> {
+    Chat {
+        chat_id: ChatTarget<'a>,
+        message_id: MessageId,
+    },
+    Inline {
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
+        inline_message_id: Cow<'a, str>,
+    },
+}
+
+impl<'a> EditTarget<'a> {
+    pub fn chat(chat_id: ChatTarget<'a>, message_id: MessageId) -> EditTarget<'a> {
+        EditTarget::Chat {
+            chat_id,
+            message_id,
+        }
+    }
+
+    pub fn inline<T: Into<Cow<'a, str>>>(inline_message_id: T) -> EditTarget<'a> {
+        EditTarget::Inline {
+            inline_message_id: inline_message_id.into(),
+        }
+    }
+}
+
 /// Use this method to receive incoming updates using long
 /// polling ([wiki](https://en.wikipedia.org/wiki/Push_technology#Long_polling)).
 /// An Array of [`Update`](types::Update) objects is returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetUpdates<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<UpdateId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i32>,
+    pub limit: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timeout: Option<i32>,
+    pub timeout: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub allowed_updates: Option<Cow<'a, [UpdateTypes]>>,
 }
 
@@ -56,19 +205,36 @@ impl<'a> GetUpdates<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ApiError {
     pub error_code: i32,
     pub description: String,
     pub parameters: Option<types::ResponseParameters>,
 }
 
+impl ApiError {
+    /// Whether the chat can't get messages from the bot any more: the user blocked the bot or
+    /// deleted their account, the bot was removed from the group, or the chat is gone. Sending
+    /// again won't help.
+    pub fn is_chat_unreachable(&self) -> bool {
+        self.error_code == 403
+            || (self.error_code == 400
+                && self
+                    .description
+                    .to_ascii_lowercase()
+                    .contains("chat not found"))
+    }
+}
+
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[ERROR] {}", self.description)
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for ApiError {
     fn description(&self) -> &str {
         self.description.as_ref()
@@ -79,13 +245,23 @@ impl Error for ApiError {
 /// Whenever there is an update for the bot, we will send an HTTPS POST request to the specified
 /// url, containing a JSON-serialized [`Update`](types::Update). In case of an unsuccessful request, we will give up
 /// after a reasonable amount of attempts. Returns True on success.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetWebhook<'a> {
     /// HTTPS url to send updates to. Use an empty string to remove webhook integration
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub url: Cow<'a, str>,
 
+    /// Public key certificate, uploaded, so Telegram can check a self-signed certificate of the
+    /// webhook server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<types::InputFile>,
+
     /// The fixed IP address which will be used to send webhook requests instead of the IP address
     /// resolved through DNS
+    #[cfg(feature = "std")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ip_address: Option<IpAddr>,
 
@@ -93,37 +269,85 @@ pub struct SetWebhook<'a> {
     /// 1-100. Defaults to *40*. Use lower values to limit the load on your bot's server, and higher
     /// values to increase your bot's throughput.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_connections: Option<i32>,
+    pub max_connections: Option<u32>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub allowed_updates: Option<Cow<'a, [UpdateTypes]>>,
 
     /// Pass True to drop all pending updates.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub drop_pending_updates: Option<bool>,
+
+    /// A secret token, 1-256 characters `A-Z`, `a-z`, `0-9`, `_` and `-`, sent in the
+    /// `X-Telegram-Bot-Api-Secret-Token` header of every webhook request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub secret_token: Option<Cow<'a, str>>,
 }
 
 impl<'a> SetWebhook<'a> {
     pub fn new<T: Into<Cow<'a, str>>>(url: T) -> SetWebhook<'a> {
         SetWebhook {
             url: url.into(),
+            certificate: None,
             max_connections: None,
             allowed_updates: None,
+            #[cfg(feature = "std")]
             ip_address: None,
             drop_pending_updates: None,
+            secret_token: None,
+        }
+    }
+
+    pub fn certificate(self, certificate: types::InputFile) -> SetWebhook<'a> {
+        SetWebhook {
+            certificate: Some(certificate),
+            ..self
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn ip_address(self, address: IpAddr) -> SetWebhook<'a> {
+        SetWebhook {
+            ip_address: Some(address),
+            ..self
         }
     }
 
-    pub fn max_connections(self, x: i32) -> SetWebhook<'a> {
+    pub fn max_connections(self, x: u32) -> SetWebhook<'a> {
         SetWebhook {
             max_connections: Some(x),
             ..self
         }
     }
+
+    pub fn allowed_updates<T: Into<Cow<'a, [UpdateTypes]>>>(self, updates: T) -> SetWebhook<'a> {
+        SetWebhook {
+            allowed_updates: Some(updates.into()),
+            ..self
+        }
+    }
+
+    pub fn drop_pending_updates(self) -> SetWebhook<'a> {
+        SetWebhook {
+            drop_pending_updates: Some(true),
+            ..self
+        }
+    }
+
+    pub fn secret_token<T: Into<Cow<'a, str>>>(self, token: T) -> SetWebhook<'a> {
+        SetWebhook {
+            secret_token: Some(token.into()),
+            ..self
+        }
+    }
 }
 
 /// Kinds of reply markup.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum ReplyMarkup {
     InlineKeyboard(InlineKeyboardMarkup),
@@ -132,10 +356,60 @@ pub enum ReplyMarkup {
     ForceReply(ForceReply),
 }
 
+#[cfg(feature = "full-serde")]
+impl<'de> Deserialize<'de> for ReplyMarkup {
+    /// Read the fields of every kind in one pass, then pick the kind by its required field.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ReplyMarkup, D::Error> {
+        #[derive(Deserialize)]
+        struct AnyMarkup {
+            inline_keyboard: Option<Vec<Vec<types::InlineKeyboardButton>>>,
+            keyboard: Option<Vec<Vec<types::KeyboardButton>>>,
+            resize_keyboard: Option<bool>,
+            one_time_keyboard: Option<bool>,
+            remove_keyboard: Option<bool>,
+            force_reply: Option<bool>,
+            selective: Option<bool>,
+        }
+
+        let markup = AnyMarkup::deserialize(deserializer)?;
+        let selective = markup.selective;
+        if let Some(inline_keyboard) = markup.inline_keyboard {
+            Ok(ReplyMarkup::InlineKeyboard(InlineKeyboardMarkup {
+                inline_keyboard,
+            }))
+        } else if let Some(keyboard) = markup.keyboard {
+            Ok(ReplyMarkup::ReplyKeyboard(ReplyKeyboardMarkup {
+                keyboard,
+                resize_keyboard: markup.resize_keyboard,
+                one_time_keyboard: markup.one_time_keyboard,
+                selective,
+            }))
+        } else if let Some(remove_keyboard) = markup.remove_keyboard {
+            Ok(ReplyMarkup::ReplyKeyboardRemove(ReplyKeyboardRemove {
+                remove_keyboard,
+                selective,
+            }))
+        } else if let Some(force_reply) = markup.force_reply {
+            Ok(ReplyMarkup::ForceReply(ForceReply {
+                force_reply,
+                selective,
+            }))
+        } else {
+            Err(D::Error::custom(
+                "expected one of `inline_keyboard`, `keyboard`, `remove_keyboard` or `force_reply`",
+            ))
+        }
+    }
+}
+
 /// Send text messages. On success, the sent [`Message`](types::Message) is returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SendMessage<'a> {
     pub chat_id: ChatTarget<'a>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub text: Cow<'a, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
@@ -184,8 +458,53 @@ impl<'a> SendMessage<'a> {
     }
 }
 
+/// Use this method when you need to tell the user that something is happening on the bot's
+/// side. The status is set for 5 seconds or less, until a message arrives from the bot.
+///
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendChatAction<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub action: ChatAction,
+}
+
+impl<'a> SendChatAction<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, action: ChatAction) -> SendChatAction<'a> {
+        SendChatAction { chat_id, action }
+    }
+}
+
+/// What the bot is doing, shown in the chat's header.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum ChatAction {
+    /// For text messages.
+    Typing,
+    UploadPhoto,
+    RecordVideo,
+    UploadVideo,
+    RecordVoice,
+    UploadVoice,
+    /// For general files.
+    UploadDocument,
+    ChooseSticker,
+    /// For location data.
+    FindLocation,
+    RecordVideoNote,
+    UploadVideoNote,
+}
+
 /// Use this method to send .webp stickers.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg(feature = "stickers")]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SendSticker<'a> {
     pub chat_id: ChatTarget<'a>,
     pub sticker: FileToSend,
@@ -197,6 +516,10 @@ pub struct SendSticker<'a> {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+#[cfg(feature = "stickers")]
+impl_method!(SendSticker<'_>, "sendSticker", types::Message, "1.0");
+
+#[cfg(feature = "stickers")]
 impl<'a> SendSticker<'a> {
     pub fn new(chat_id: ChatTarget<'a>, sticker: FileToSend) -> SendSticker<'a> {
         SendSticker {
@@ -223,12 +546,174 @@ impl<'a> SendSticker<'a> {
     }
 }
 
+/// Use this method to send an animated emoji that will display a random value.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendDice<'a> {
+    pub chat_id: ChatTarget<'a>,
+    /// Emoji on which the dice throw animation is based: 🎲 (the default), 🎯, 🏀, ⚽, 🎳 or 🎰
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub emoji: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'a> SendDice<'a> {
+    pub fn new(chat_id: ChatTarget<'a>) -> SendDice<'a> {
+        SendDice {
+            chat_id,
+            emoji: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn emoji<T: Into<Cow<'a, str>>>(self, emoji: T) -> SendDice<'a> {
+        SendDice {
+            emoji: Some(emoji.into()),
+            ..self
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> SendDice<'a> {
+        SendDice {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply(self, reply_to_message_id: MessageId) -> SendDice<'a> {
+        SendDice {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: ReplyMarkup) -> Self {
+        Self {
+            reply_markup: Some(markup),
+            ..self
+        }
+    }
+}
+
+/// Use this method to send a point on the map, or a live location that
+/// [`EditMessageLiveLocation`] moves until it expires or [`StopMessageLiveLocation`] stops it.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendLocation<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub latitude: f32,
+    pub longitude: f32,
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f32>,
+    /// Period in seconds during which the location will be updated, 60-86400, or 0x7FFFFFFF
+    /// for live locations that can be edited indefinitely
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+    /// For live locations, the direction in which the user is moving, in degrees; 1-360
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+    /// For live locations, the maximum distance for proximity alerts about approaching another
+    /// chat member, in meters; 1-100000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'a> SendLocation<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, latitude: f32, longitude: f32) -> SendLocation<'a> {
+        SendLocation {
+            chat_id,
+            latitude,
+            longitude,
+            horizontal_accuracy: None,
+            live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Send a live location, updated for `live_period` seconds.
+    pub fn live(self, live_period: i64) -> SendLocation<'a> {
+        SendLocation {
+            live_period: Some(live_period),
+            ..self
+        }
+    }
+
+    pub fn horizontal_accuracy(self, horizontal_accuracy: f32) -> SendLocation<'a> {
+        SendLocation {
+            horizontal_accuracy: Some(horizontal_accuracy),
+            ..self
+        }
+    }
+
+    pub fn heading(self, heading: i64) -> SendLocation<'a> {
+        SendLocation {
+            heading: Some(heading),
+            ..self
+        }
+    }
+
+    pub fn proximity_alert_radius(self, radius: i64) -> SendLocation<'a> {
+        SendLocation {
+            proximity_alert_radius: Some(radius),
+            ..self
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> SendLocation<'a> {
+        SendLocation {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply(self, reply_to_message_id: MessageId) -> SendLocation<'a> {
+        SendLocation {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: ReplyMarkup) -> Self {
+        Self {
+            reply_markup: Some(markup),
+            ..self
+        }
+    }
+}
+
 /// Use this method to send photos.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SendPhoto<'a> {
     pub chat_id: ChatTarget<'a>,
     pub photo: FileToSend,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub caption: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
@@ -275,11 +760,15 @@ impl<'a> SendPhoto<'a> {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SendDocument<'a> {
     pub chat_id: ChatTarget<'a>,
     pub document: FileToSend,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
     pub caption: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
@@ -326,83 +815,606 @@ impl<'a> SendDocument<'a> {
     }
 }
 
-/// Use this method to forward messages of any kind.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct ForwardMessage<'a> {
-    pub chat_id: ChatTarget<'a>,
-    pub from_chat_id: ChatTarget<'a>,
-    pub message_id: MessageId,
-}
-
-/// Use this method to copy messages of any kind. Service messages and invoice messages can't be
-/// copied. The method is analogous to the method `forwardMessage`, but the copied message doesn't
-/// have a link to the original message.
-///
-/// Returns the `MessageIdResult` of the sent message on success.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct CopyMessage<'a> {
-    /// Unique identifier for the target chat or username of the target channel
+/// Use this method to send audio files, to be shown in the music player. Must be in the .MP3 or
+/// .M4A format; use [`SendVoice`] for voice messages.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendAudio<'a> {
     pub chat_id: ChatTarget<'a>,
-
-    /// Unique identifier for the chat where the original message was sent
-    pub from_chat_id: ChatTarget<'a>,
-
-    pub message_id: types::MessageId,
-
-    /// New caption for media, 0-1024 characters after entities parsing. If not specified, the
-    /// original caption is kept
+    pub audio: FileToSend,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub caption: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<types::ParseMode>,
-
-    /// List of special entities that appear in the new caption, which can be specified instead
-    /// of `parse_mode`
+    pub parse_mode: Option<ParseMode>,
+    /// Duration of the audio in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption_entities: Option<Vec<types::MessageEntity>>,
-
-    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
-    /// Users will receive a notification with no sound.
+    pub duration: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_notification: Option<bool>,
-
-    /// If the message is a reply, ID of the original message
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub performer: Option<Cow<'a, str>>,
+    /// Track name
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_to_message_id: Option<types::MessageId>,
-
-    /// Pass True, if the message should be sent even if the specified replied-to message is not found
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub title: Option<Cow<'a, str>>,
+    /// JPEG thumbnail of at most 320x320 and 200 kB, which can't be a file id or URL
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allow_sending_without_reply: Option<bool>,
-
-    /// Additional interface options.
+    pub thumbnail: Option<InputFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
 }
 
-impl<'a> CopyMessage<'a> {
-    pub fn new(
-        chat_id: ChatTarget<'a>,
-        from_chat_id: ChatTarget<'a>,
-        message_id: types::MessageId,
-    ) -> Self {
-        Self {
+impl<'a> SendAudio<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, audio: FileToSend) -> SendAudio<'a> {
+        SendAudio {
             chat_id,
-            from_chat_id,
-            message_id,
+            audio,
             caption: None,
             parse_mode: None,
+            duration: None,
+            performer: None,
+            title: None,
+            thumbnail: None,
             disable_notification: None,
             reply_to_message_id: None,
-            allow_sending_without_reply: None,
             reply_markup: None,
-            caption_entities: None,
         }
     }
 
-    pub fn caption(self, caption: String) -> Self {
-        Self {
-            caption: Some(caption),
+    pub fn caption<T: Into<Cow<'a, str>>>(self, caption: T) -> SendAudio<'a> {
+        SendAudio {
+            caption: Some(caption.into()),
+            ..self
+        }
+    }
+
+    pub fn parse_mode(self, mode: ParseMode) -> SendAudio<'a> {
+        SendAudio {
+            parse_mode: Some(mode),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: i64) -> SendAudio<'a> {
+        SendAudio {
+            duration: Some(duration),
+            ..self
+        }
+    }
+
+    pub fn performer<T: Into<Cow<'a, str>>>(self, performer: T) -> SendAudio<'a> {
+        SendAudio {
+            performer: Some(performer.into()),
+            ..self
+        }
+    }
+
+    pub fn title<T: Into<Cow<'a, str>>>(self, title: T) -> SendAudio<'a> {
+        SendAudio {
+            title: Some(title.into()),
+            ..self
+        }
+    }
+
+    pub fn thumbnail(self, thumbnail: InputFile) -> SendAudio<'a> {
+        SendAudio {
+            thumbnail: Some(thumbnail),
+            ..self
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> SendAudio<'a> {
+        SendAudio {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply(self, reply_to_message_id: MessageId) -> SendAudio<'a> {
+        SendAudio {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: ReplyMarkup) -> Self {
+        Self {
+            reply_markup: Some(markup),
+            ..self
+        }
+    }
+}
+
+/// Use this method to send video files. Telegram clients support MPEG4 videos, other formats may
+/// be sent as a [`SendDocument`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendVideo<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub video: FileToSend,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub caption: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// Duration of the video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Pass True if the video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<bool>,
+    /// JPEG thumbnail of at most 320x320 and 200 kB, which can't be a file id or URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<InputFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'a> SendVideo<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, video: FileToSend) -> SendVideo<'a> {
+        SendVideo {
+            chat_id,
+            video,
+            caption: None,
+            parse_mode: None,
+            duration: None,
+            width: None,
+            height: None,
+            supports_streaming: None,
+            thumbnail: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn caption<T: Into<Cow<'a, str>>>(self, caption: T) -> SendVideo<'a> {
+        SendVideo {
+            caption: Some(caption.into()),
+            ..self
+        }
+    }
+
+    pub fn parse_mode(self, mode: ParseMode) -> SendVideo<'a> {
+        SendVideo {
+            parse_mode: Some(mode),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: i64) -> SendVideo<'a> {
+        SendVideo {
+            duration: Some(duration),
+            ..self
+        }
+    }
+
+    pub fn width(self, width: i64) -> SendVideo<'a> {
+        SendVideo {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    pub fn height(self, height: i64) -> SendVideo<'a> {
+        SendVideo {
+            height: Some(height),
+            ..self
+        }
+    }
+
+    pub fn supports_streaming(self, supports_streaming: bool) -> SendVideo<'a> {
+        SendVideo {
+            supports_streaming: Some(supports_streaming),
+            ..self
+        }
+    }
+
+    pub fn thumbnail(self, thumbnail: InputFile) -> SendVideo<'a> {
+        SendVideo {
+            thumbnail: Some(thumbnail),
+            ..self
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> SendVideo<'a> {
+        SendVideo {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply(self, reply_to_message_id: MessageId) -> SendVideo<'a> {
+        SendVideo {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: ReplyMarkup) -> Self {
+        Self {
+            reply_markup: Some(markup),
+            ..self
+        }
+    }
+}
+
+/// Use this method to send animations: GIF, or H.264/MPEG-4 AVC video without sound.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendAnimation<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub animation: FileToSend,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub caption: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// Duration of the animation in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// JPEG thumbnail of at most 320x320 and 200 kB, which can't be a file id or URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<InputFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'a> SendAnimation<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, animation: FileToSend) -> SendAnimation<'a> {
+        SendAnimation {
+            chat_id,
+            animation,
+            caption: None,
+            parse_mode: None,
+            duration: None,
+            width: None,
+            height: None,
+            thumbnail: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn caption<T: Into<Cow<'a, str>>>(self, caption: T) -> SendAnimation<'a> {
+        SendAnimation {
+            caption: Some(caption.into()),
+            ..self
+        }
+    }
+
+    pub fn parse_mode(self, mode: ParseMode) -> SendAnimation<'a> {
+        SendAnimation {
+            parse_mode: Some(mode),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: i64) -> SendAnimation<'a> {
+        SendAnimation {
+            duration: Some(duration),
+            ..self
+        }
+    }
+
+    pub fn width(self, width: i64) -> SendAnimation<'a> {
+        SendAnimation {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    pub fn height(self, height: i64) -> SendAnimation<'a> {
+        SendAnimation {
+            height: Some(height),
+            ..self
+        }
+    }
+
+    pub fn thumbnail(self, thumbnail: InputFile) -> SendAnimation<'a> {
+        SendAnimation {
+            thumbnail: Some(thumbnail),
+            ..self
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> SendAnimation<'a> {
+        SendAnimation {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply(self, reply_to_message_id: MessageId) -> SendAnimation<'a> {
+        SendAnimation {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: ReplyMarkup) -> Self {
+        Self {
+            reply_markup: Some(markup),
+            ..self
+        }
+    }
+}
+
+/// Use this method to send audio files to be shown as a playable voice message. Must be in an
+/// .OGG file encoded with OPUS, or in .MP3 or .M4A format.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendVoice<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub voice: FileToSend,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub caption: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// Duration of the voice message in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'a> SendVoice<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, voice: FileToSend) -> SendVoice<'a> {
+        SendVoice {
+            chat_id,
+            voice,
+            caption: None,
+            parse_mode: None,
+            duration: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn caption<T: Into<Cow<'a, str>>>(self, caption: T) -> SendVoice<'a> {
+        SendVoice {
+            caption: Some(caption.into()),
+            ..self
+        }
+    }
+
+    pub fn parse_mode(self, mode: ParseMode) -> SendVoice<'a> {
+        SendVoice {
+            parse_mode: Some(mode),
+            ..self
+        }
+    }
+
+    pub fn duration(self, duration: i64) -> SendVoice<'a> {
+        SendVoice {
+            duration: Some(duration),
+            ..self
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> SendVoice<'a> {
+        SendVoice {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply(self, reply_to_message_id: MessageId) -> SendVoice<'a> {
+        SendVoice {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: ReplyMarkup) -> Self {
+        Self {
+            reply_markup: Some(markup),
+            ..self
+        }
+    }
+}
+
+/// Use this method to send rounded square MPEG4 videos of up to 1 minute. Sending by URL is
+/// not supported.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendVideoNote<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub video_note: FileToSend,
+    /// Duration of the video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// Video width and height, the diameter of the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<i64>,
+    /// JPEG thumbnail of at most 320x320 and 200 kB, which can't be a file id or URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<InputFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'a> SendVideoNote<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, video_note: FileToSend) -> SendVideoNote<'a> {
+        SendVideoNote {
+            chat_id,
+            video_note,
+            duration: None,
+            length: None,
+            thumbnail: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn duration(self, duration: i64) -> SendVideoNote<'a> {
+        SendVideoNote {
+            duration: Some(duration),
+            ..self
+        }
+    }
+
+    pub fn length(self, length: i64) -> SendVideoNote<'a> {
+        SendVideoNote {
+            length: Some(length),
+            ..self
+        }
+    }
+
+    pub fn thumbnail(self, thumbnail: InputFile) -> SendVideoNote<'a> {
+        SendVideoNote {
+            thumbnail: Some(thumbnail),
+            ..self
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> SendVideoNote<'a> {
+        SendVideoNote {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply(self, reply_to_message_id: MessageId) -> SendVideoNote<'a> {
+        SendVideoNote {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: ReplyMarkup) -> Self {
+        Self {
+            reply_markup: Some(markup),
+            ..self
+        }
+    }
+}
+
+/// Use this method to forward messages of any kind.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ForwardMessage<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub from_chat_id: ChatTarget<'a>,
+    pub message_id: MessageId,
+}
+
+/// Use this method to copy messages of any kind. Service messages and invoice messages can't be
+/// copied. The method is analogous to the method `forwardMessage`, but the copied message doesn't
+/// have a link to the original message.
+///
+/// Returns the `MessageIdResult` of the sent message on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CopyMessage<'a> {
+    /// Unique identifier for the target chat or username of the target channel
+    pub chat_id: ChatTarget<'a>,
+
+    /// Unique identifier for the chat where the original message was sent
+    pub from_chat_id: ChatTarget<'a>,
+
+    pub message_id: types::MessageId,
+
+    /// New caption for media, 0-1024 characters after entities parsing. If not specified, the
+    /// original caption is kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<types::ParseMode>,
+
+    /// List of special entities that appear in the new caption, which can be specified instead
+    /// of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<types::MessageEntity>>,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<types::MessageId>,
+
+    /// Pass True, if the message should be sent even if the specified replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'a> CopyMessage<'a> {
+    pub fn new(
+        chat_id: ChatTarget<'a>,
+        from_chat_id: ChatTarget<'a>,
+        message_id: types::MessageId,
+    ) -> Self {
+        Self {
+            chat_id,
+            from_chat_id,
+            message_id,
+            caption: None,
+            parse_mode: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            allow_sending_without_reply: None,
+            reply_markup: None,
+            caption_entities: None,
+        }
+    }
+
+    pub fn caption(self, caption: String) -> Self {
+        Self {
+            caption: Some(caption),
             ..self
         }
     }
@@ -414,92 +1426,646 @@ impl<'a> CopyMessage<'a> {
         }
     }
 
-    pub fn disable_notification(self, disable_notification: bool) -> Self {
-        Self {
-            disable_notification: Some(disable_notification),
-            ..self
+    pub fn disable_notification(self, disable_notification: bool) -> Self {
+        Self {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply_to_message_id(self, reply_to_message_id: types::MessageId) -> Self {
+        Self {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn allow_sending_without_reply(self, allow_sending_without_reply: bool) -> Self {
+        Self {
+            allow_sending_without_reply: Some(allow_sending_without_reply),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, reply_markup: ReplyMarkup) -> Self {
+        Self {
+            reply_markup: Some(reply_markup),
+            ..self
+        }
+    }
+}
+
+/// To get a list of profile pictures for a user. Returns a [`UserProfilePhotos`](types::UserProfilePhotos) object.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetUserProfilePhotos {
+    pub user_id: UserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl GetUserProfilePhotos {
+    pub fn new(user_id: UserId) -> GetUserProfilePhotos {
+        GetUserProfilePhotos {
+            user_id,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Skip the `offset` newest pictures.
+    pub fn offset(self, offset: i64) -> GetUserProfilePhotos {
+        GetUserProfilePhotos {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Return at most `limit` pictures, 1-100.
+    pub fn limit(self, limit: u32) -> GetUserProfilePhotos {
+        GetUserProfilePhotos {
+            limit: Some(limit),
+            ..self
+        }
+    }
+}
+
+/// Use this method to get up to date information about the chat (current name of the user
+/// for one-on-one conversations, current username of a user, group or channel, etc.).
+///
+/// Returns a [`Chat`](types::Chat) object on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetChat<'a> {
+    pub chat_id: ChatTarget<'a>,
+}
+
+/// Use this method to get the number of members in a chat. Returns `Int` on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetChatMembersCount<'a> {
+    pub chat_id: ChatTarget<'a>,
+}
+
+/// Use this method to get a list of administrators in a chat. On success, returns an Array
+/// of `ChatMember` objects that contains information about all chat administrators except
+/// other bots. If the chat is a group or a supergroup and no administrators were appointed,
+/// only the creator will be returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetChatAdministrators<'a> {
+    pub chat_id: ChatTarget<'a>,
+}
+
+/// Use this method to get information about a member of a chat. Returns a `ChatMember`
+/// object on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetChatMember<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub user_id: UserId,
+}
+
+/// Use this method to ban a user from a group, a supergroup or a channel. In supergroups and
+/// channels, the user can't rejoin by themselves until they are unbanned.
+///
+/// The bot must be an administrator in the chat with the `can_restrict_members` right.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BanChatMember<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub user_id: UserId,
+    /// Unix time when the user will be unbanned. Less than 30 seconds or more than 366 days from
+    /// now bans the user forever. Always forever in groups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+    /// Delete all messages of the user in the chat. Always true in supergroups and channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_messages: Option<bool>,
+}
+
+impl<'a> BanChatMember<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, user_id: UserId) -> BanChatMember<'a> {
+        BanChatMember {
+            chat_id,
+            user_id,
+            until_date: None,
+            revoke_messages: None,
+        }
+    }
+
+    /// Unban the user at `until_date`, in Unix time.
+    pub fn until(self, until_date: i64) -> BanChatMember<'a> {
+        BanChatMember {
+            until_date: Some(until_date),
+            ..self
+        }
+    }
+
+    pub fn revoke_messages(self, revoke_messages: bool) -> BanChatMember<'a> {
+        BanChatMember {
+            revoke_messages: Some(revoke_messages),
+            ..self
+        }
+    }
+}
+
+/// Use this method to unban a previously banned user in a supergroup or channel. The user can
+/// then join again, but isn't added back.
+///
+/// By default, this removes a user who is a member of the chat too. Set `only_if_banned` to
+/// avoid that. Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct UnbanChatMember<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub user_id: UserId,
+    /// Do nothing if the user is not banned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_if_banned: Option<bool>,
+}
+
+impl<'a> UnbanChatMember<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, user_id: UserId) -> UnbanChatMember<'a> {
+        UnbanChatMember {
+            chat_id,
+            user_id,
+            only_if_banned: None,
+        }
+    }
+
+    pub fn only_if_banned(self, only_if_banned: bool) -> UnbanChatMember<'a> {
+        UnbanChatMember {
+            only_if_banned: Some(only_if_banned),
+            ..self
+        }
+    }
+}
+
+/// Use this method to ban a channel chat in a supergroup or a channel. Until it is unbanned, the
+/// owner of the banned chat can't send messages on behalf of any of their channels.
+///
+/// The bot must be an administrator in the chat with the `can_restrict_members` right.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BanChatSenderChat<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub sender_chat_id: ChatId,
+}
+
+impl<'a> BanChatSenderChat<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, sender_chat_id: ChatId) -> BanChatSenderChat<'a> {
+        BanChatSenderChat {
+            chat_id,
+            sender_chat_id,
+        }
+    }
+}
+
+/// Use this method to unban a previously banned channel chat in a supergroup or channel.
+///
+/// The bot must be an administrator in the chat with the `can_restrict_members` right.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct UnbanChatSenderChat<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub sender_chat_id: ChatId,
+}
+
+impl<'a> UnbanChatSenderChat<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, sender_chat_id: ChatId) -> UnbanChatSenderChat<'a> {
+        UnbanChatSenderChat {
+            chat_id,
+            sender_chat_id,
         }
     }
+}
 
-    pub fn reply_to_message_id(self, reply_to_message_id: types::MessageId) -> Self {
-        Self {
-            reply_to_message_id: Some(reply_to_message_id),
-            ..self
+/// Use this method to restrict a user in a supergroup, such as to mute them. Pass
+/// [`ChatPermissions::all`] to lift the restrictions.
+///
+/// The bot must be an administrator in the supergroup with the `can_restrict_members` right.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RestrictChatMember<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub user_id: UserId,
+    /// New permissions of the user
+    pub permissions: ChatPermissions,
+    /// If false or left out, `can_send_audios`, `can_send_documents`, `can_send_photos`,
+    /// `can_send_videos`, `can_send_video_notes` and `can_send_voice_notes` imply
+    /// `can_send_messages`, and `can_send_polls` and `can_send_other_messages` imply
+    /// `can_send_audios`, ... as well.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
+    /// Unix time when the restrictions will be lifted. Less than 30 seconds or more than 366
+    /// days from now restricts the user forever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+}
+
+impl<'a> RestrictChatMember<'a> {
+    pub fn new(
+        chat_id: ChatTarget<'a>,
+        user_id: UserId,
+        permissions: ChatPermissions,
+    ) -> RestrictChatMember<'a> {
+        RestrictChatMember {
+            chat_id,
+            user_id,
+            permissions,
+            use_independent_chat_permissions: None,
+            until_date: None,
         }
     }
 
-    pub fn allow_sending_without_reply(self, allow_sending_without_reply: bool) -> Self {
-        Self {
-            allow_sending_without_reply: Some(allow_sending_without_reply),
+    /// Forbid the user to send anything.
+    pub fn mute(chat_id: ChatTarget<'a>, user_id: UserId) -> RestrictChatMember<'a> {
+        RestrictChatMember::new(chat_id, user_id, ChatPermissions::default())
+    }
+
+    pub fn independent_permissions(self, independent: bool) -> RestrictChatMember<'a> {
+        RestrictChatMember {
+            use_independent_chat_permissions: Some(independent),
             ..self
         }
     }
 
-    pub fn reply_markup(self, reply_markup: ReplyMarkup) -> Self {
-        Self {
-            reply_markup: Some(reply_markup),
+    /// Lift the restrictions at `until_date`, in Unix time.
+    pub fn until(self, until_date: i64) -> RestrictChatMember<'a> {
+        RestrictChatMember {
+            until_date: Some(until_date),
             ..self
         }
     }
 }
 
-/// To get a list of profile pictures for a user. Returns a [`UserProfilePhotos`](types::UserProfilePhotos) object.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct GetUserProfilePhotos {
+/// Use this method to promote or demote a user in a supergroup or a channel. Pass the default
+/// [`ChatAdministratorRights`] to demote them.
+///
+/// The bot must be an administrator in the chat with the `can_promote_members` right, and can
+/// only grant rights it has. Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct PromoteChatMember<'a> {
+    pub chat_id: ChatTarget<'a>,
     pub user_id: UserId,
+    #[serde(flatten)]
+    pub rights: ChatAdministratorRights,
+}
+
+impl<'a> PromoteChatMember<'a> {
+    pub fn new(
+        chat_id: ChatTarget<'a>,
+        user_id: UserId,
+        rights: ChatAdministratorRights,
+    ) -> PromoteChatMember<'a> {
+        PromoteChatMember {
+            chat_id,
+            user_id,
+            rights,
+        }
+    }
+
+    /// Take all administrator rights from the user.
+    pub fn demote(chat_id: ChatTarget<'a>, user_id: UserId) -> PromoteChatMember<'a> {
+        PromoteChatMember::new(chat_id, user_id, ChatAdministratorRights::default())
+    }
+}
+
+/// Use this method to change the rights the bot asks for when it's added as an administrator to
+/// groups or channels. Users may still change them.
+///
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SetMyDefaultAdministratorRights {
+    /// The new rights, none if left out
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub offset: Option<i32>,
+    pub rights: Option<ChatAdministratorRights>,
+    /// Change the rights for channels instead of groups and supergroups
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i32>,
+    pub for_channels: Option<bool>,
 }
 
-impl GetUserProfilePhotos {
-    pub fn new(user_id: UserId) -> GetUserProfilePhotos {
-        GetUserProfilePhotos {
-            user_id,
-            offset: None,
-            limit: None,
+impl SetMyDefaultAdministratorRights {
+    pub fn new(rights: ChatAdministratorRights) -> SetMyDefaultAdministratorRights {
+        SetMyDefaultAdministratorRights {
+            rights: Some(rights),
+            for_channels: None,
+        }
+    }
+
+    pub fn for_channels(self, for_channels: bool) -> SetMyDefaultAdministratorRights {
+        SetMyDefaultAdministratorRights {
+            for_channels: Some(for_channels),
+            ..self
         }
     }
 }
 
-/// Use this method to get up to date information about the chat (current name of the user
-/// for one-on-one conversations, current username of a user, group or channel, etc.).
+/// Use this method to get the rights the bot asks for when it's added as an administrator.
 ///
-/// Returns a [`Chat`](types::Chat) object on success.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct GetChat<'a> {
+/// Returns [`ChatAdministratorRights`] on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetMyDefaultAdministratorRights {
+    /// Get the rights for channels instead of groups and supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub for_channels: Option<bool>,
+}
+
+impl GetMyDefaultAdministratorRights {
+    pub fn new() -> GetMyDefaultAdministratorRights {
+        GetMyDefaultAdministratorRights::default()
+    }
+
+    pub fn for_channels(self, for_channels: bool) -> GetMyDefaultAdministratorRights {
+        GetMyDefaultAdministratorRights {
+            for_channels: Some(for_channels),
+        }
+    }
+}
+
+/// Use this method to set the default permissions of all members of a group or supergroup.
+///
+/// The bot must be an administrator in the chat with the `can_restrict_members` right.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SetChatPermissions<'a> {
     pub chat_id: ChatTarget<'a>,
+    pub permissions: ChatPermissions,
+    /// See [`RestrictChatMember::use_independent_chat_permissions`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
 }
 
-/// Use this method to get the number of members in a chat. Returns `Int` on success.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct GetChatMembersCount<'a> {
+impl<'a> SetChatPermissions<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, permissions: ChatPermissions) -> SetChatPermissions<'a> {
+        SetChatPermissions {
+            chat_id,
+            permissions,
+            use_independent_chat_permissions: None,
+        }
+    }
+
+    pub fn independent_permissions(self, independent: bool) -> SetChatPermissions<'a> {
+        SetChatPermissions {
+            use_independent_chat_permissions: Some(independent),
+            ..self
+        }
+    }
+}
+
+/// Use this method to change the title of a chat. Titles can't be changed for private chats.
+///
+/// The bot must be an administrator in the chat with the `can_change_info` right.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SetChatTitle<'a> {
     pub chat_id: ChatTarget<'a>,
+    /// New chat title, 1-128 characters
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
+    pub title: Cow<'a, str>,
 }
 
-/// Use this method to get a list of administrators in a chat. On success, returns an Array
-/// of `ChatMember` objects that contains information about all chat administrators except
-/// other bots. If the chat is a group or a supergroup and no administrators were appointed,
-/// only the creator will be returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct GetChatAdministrators<'a> {
+impl<'a> SetChatTitle<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(chat_id: ChatTarget<'a>, title: T) -> SetChatTitle<'a> {
+        SetChatTitle {
+            chat_id,
+            title: title.into(),
+        }
+    }
+}
+
+/// Use this method to change the description of a group, a supergroup or a channel.
+///
+/// The bot must be an administrator in the chat with the `can_change_info` right.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SetChatDescription<'a> {
     pub chat_id: ChatTarget<'a>,
+    /// New chat description, 0-255 characters. Left out, the description is removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub description: Option<Cow<'a, str>>,
 }
 
-/// Use this method to get information about a member of a chat. Returns a `ChatMember`
-/// object on success.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct GetChatMember<'a> {
+impl<'a> SetChatDescription<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(
+        chat_id: ChatTarget<'a>,
+        description: T,
+    ) -> SetChatDescription<'a> {
+        SetChatDescription {
+            chat_id,
+            description: Some(description.into()),
+        }
+    }
+
+    /// Remove the description.
+    pub fn clear(chat_id: ChatTarget<'a>) -> SetChatDescription<'a> {
+        SetChatDescription {
+            chat_id,
+            description: None,
+        }
+    }
+}
+
+/// Use this method to set a new profile photo for a chat. Photos can't be changed for private
+/// chats.
+///
+/// The photo must be uploaded, send the method with [`Attachment`]. The bot must be an
+/// administrator in the chat with the `can_change_info` right. Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SetChatPhoto<'a> {
     pub chat_id: ChatTarget<'a>,
-    pub user_id: UserId,
+    pub photo: InputFile,
+}
+
+impl<'a> SetChatPhoto<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, photo: InputFile) -> SetChatPhoto<'a> {
+        SetChatPhoto { chat_id, photo }
+    }
+
+    /// The method uploading `data` as `file_name`, and the file to send with it.
+    pub fn upload<N: Into<String>>(
+        chat_id: ChatTarget<'a>,
+        file_name: N,
+        data: Vec<u8>,
+    ) -> (SetChatPhoto<'a>, Attachment) {
+        let file = Attachment::new("photo", file_name, data);
+        (SetChatPhoto::new(chat_id, file.input_file()), file)
+    }
+}
+
+/// Use this method to delete a chat photo. Photos can't be changed for private chats.
+///
+/// The bot must be an administrator in the chat with the `can_change_info` right.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeleteChatPhoto<'a> {
+    pub chat_id: ChatTarget<'a>,
+}
+
+impl<'a> DeleteChatPhoto<'a> {
+    pub fn new(chat_id: ChatTarget<'a>) -> DeleteChatPhoto<'a> {
+        DeleteChatPhoto { chat_id }
+    }
+}
+
+/// Use this method for your bot to leave a group, supergroup or channel.
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LeaveChat<'a> {
+    pub chat_id: ChatTarget<'a>,
+}
+
+impl<'a> LeaveChat<'a> {
+    pub fn new(chat_id: ChatTarget<'a>) -> LeaveChat<'a> {
+        LeaveChat { chat_id }
+    }
+}
+
+/// Use this method to pin a message in a chat.
+///
+/// Outside private chats, the bot must be an administrator with the `can_pin_messages` right in
+/// groups or `can_edit_messages` in channels. Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct PinChatMessage<'a> {
+    pub chat_id: ChatTarget<'a>,
+    pub message_id: MessageId,
+    /// Pass True to not notify the members about the new pinned message. Notifications are
+    /// always disabled in channels and private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+}
+
+impl<'a> PinChatMessage<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, message_id: MessageId) -> PinChatMessage<'a> {
+        PinChatMessage {
+            chat_id,
+            message_id,
+            disable_notification: None,
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> PinChatMessage<'a> {
+        PinChatMessage {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+}
+
+/// Use this method to unpin a message in a chat, the most recently pinned one if `message_id`
+/// is left out.
+///
+/// Needs the same rights as [`PinChatMessage`]. Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct UnpinChatMessage<'a> {
+    pub chat_id: ChatTarget<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageId>,
+}
+
+impl<'a> UnpinChatMessage<'a> {
+    /// Unpin the most recently pinned message.
+    pub fn new(chat_id: ChatTarget<'a>) -> UnpinChatMessage<'a> {
+        UnpinChatMessage {
+            chat_id,
+            message_id: None,
+        }
+    }
+
+    pub fn message(self, message_id: MessageId) -> UnpinChatMessage<'a> {
+        UnpinChatMessage {
+            message_id: Some(message_id),
+            ..self
+        }
+    }
+}
+
+/// Use this method to clear the list of pinned messages in a chat.
+///
+/// Needs the same rights as [`PinChatMessage`]. Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct UnpinAllChatMessages<'a> {
+    pub chat_id: ChatTarget<'a>,
+}
+
+impl<'a> UnpinAllChatMessages<'a> {
+    pub fn new(chat_id: ChatTarget<'a>) -> UnpinAllChatMessages<'a> {
+        UnpinAllChatMessages { chat_id }
+    }
 }
 
 /// Use this method to send answers to callback queries sent from inline keyboards. The answer will
 /// be displayed to the user as a notification at the top of the chat screen or as an alert.
 ///
 /// On success, True is returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AnswerCallbackQuery {
     /// Unique identifier for the query to be answered
     pub callback_query_id: String,
@@ -527,7 +2093,7 @@ pub struct AnswerCallbackQuery {
     /// cached client-side. Telegram apps will support caching starting in version 3.14.
     /// Defaults to 0.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_time: Option<u64>,
+    pub cache_time: Option<i64>,
 }
 
 impl AnswerCallbackQuery {
@@ -562,7 +2128,7 @@ impl AnswerCallbackQuery {
         }
     }
 
-    pub fn cache_time(self, cache_time: u64) -> Self {
+    pub fn cache_time(self, cache_time: i64) -> Self {
         Self {
             cache_time: Some(cache_time),
             ..self
@@ -574,62 +2140,282 @@ impl AnswerCallbackQuery {
 /// and audio files can be only grouped in an album with messages of the same type.
 ///
 /// On success, an array of `Messages` that were sent is returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SendMediaGroup<'a> {
     pub chat_id: ChatTarget<'a>,
 
-    /// must include 2-10 items
-    pub media: Vec<types::InputMedia>,
+    /// must include 2-10 items
+    pub media: Vec<types::InputMedia>,
+
+    /// Sends messages [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the messages are a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<types::MessageId>,
+
+    /// Pass True, if the message should be sent even if the specified replied-to message
+    /// is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+}
+
+impl<'a> SendMediaGroup<'a> {
+    /// Minimum number of items in a media group.
+    pub const MIN_MEDIA: usize = 2;
+    /// Maximum number of items in a media group.
+    pub const MAX_MEDIA: usize = 10;
+
+    /// Start building a media group, checked by [`SendMediaGroupBuilder::build`].
+    pub fn builder(chat_id: ChatTarget<'a>) -> SendMediaGroupBuilder<'a> {
+        SendMediaGroupBuilder {
+            inner: SendMediaGroup {
+                chat_id,
+                media: Vec::new(),
+                disable_notification: None,
+                reply_to_message_id: None,
+                allow_sending_without_reply: None,
+            },
+        }
+    }
+
+    /// Start building a media group with files to upload, see [`MediaGroupUpload`].
+    pub fn upload(chat_id: ChatTarget<'a>) -> MediaGroupUpload<'a> {
+        MediaGroupUpload {
+            builder: SendMediaGroup::builder(chat_id),
+            files: Vec::new(),
+        }
+    }
+}
+
+/// Builder of [`SendMediaGroup`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SendMediaGroupBuilder<'a> {
+    inner: SendMediaGroup<'a>,
+}
+
+impl<'a> SendMediaGroupBuilder<'a> {
+    pub fn media(mut self, media: InputMedia) -> Self {
+        self.inner.media.push(media);
+        self
+    }
+
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.inner.disable_notification = Some(disable_notification);
+        self
+    }
+
+    pub fn reply_to_message_id(mut self, reply_to_message_id: MessageId) -> Self {
+        self.inner.reply_to_message_id = Some(reply_to_message_id);
+        self
+    }
+
+    pub fn allow_sending_without_reply(mut self, allow_sending_without_reply: bool) -> Self {
+        self.inner.allow_sending_without_reply = Some(allow_sending_without_reply);
+        self
+    }
+
+    /// Check the album against Telegram's rules: 2-10 items, photos and videos may be
+    /// mixed, documents and audio files can only be grouped with their own kind.
+    pub fn build(self) -> Result<SendMediaGroup<'a>, MediaGroupError> {
+        let media = &self.inner.media;
+        if media.len() < SendMediaGroup::MIN_MEDIA || media.len() > SendMediaGroup::MAX_MEDIA {
+            return Err(MediaGroupError::InvalidSize(media.len()));
+        }
+        let group = |media: &InputMedia| match media {
+            InputMedia::Photo { .. } | InputMedia::Video { .. } => Ok(0),
+            InputMedia::Document { .. } => Ok(1),
+            InputMedia::Audio { .. } => Ok(2),
+            InputMedia::Animation { .. } | InputMedia::Unknown => Err(MediaGroupError::Unsupported),
+        };
+        let first = group(&media[0])?;
+        for item in &media[1..] {
+            if group(item)? != first {
+                return Err(MediaGroupError::MixedTypes);
+            }
+        }
+        Ok(self.inner)
+    }
+}
+
+/// Why a [`SendMediaGroup`] could not be built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaGroupError {
+    /// A media group must include 2-10 items.
+    InvalidSize(usize),
+    /// Documents and audio files can't be mixed with other kinds of media.
+    MixedTypes,
+    /// Animations can't be sent in a media group.
+    Unsupported,
+}
+
+impl fmt::Display for MediaGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MediaGroupError::InvalidSize(n) => write!(
+                f,
+                "a media group must include {}-{} items, got {}",
+                SendMediaGroup::MIN_MEDIA,
+                SendMediaGroup::MAX_MEDIA,
+                n
+            ),
+            MediaGroupError::MixedTypes => write!(
+                f,
+                "documents and audio files can only be grouped with their own kind"
+            ),
+            MediaGroupError::Unsupported => {
+                write!(
+                    f,
+                    "only photos, videos, documents and audio files can be grouped"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for MediaGroupError {}
+
+/// Builder of a [`SendMediaGroup`] whose items may be files to upload.
+///
+/// Each uploaded file gets its own `attach://` name, and [`build`](MediaGroupUpload::build)
+/// returns the method with the [`Attachment`]s to send with it as a multipart form. With the
+/// `client` feature, `Bot::send_media_group` does both in one call.
+///
+/// ```
+/// use telegram_types::bot::methods::{ChatTarget, SendMediaGroup};
+/// use telegram_types::bot::types::{FileId, FileToSend, InputMedia};
+///
+/// let (method, files) = SendMediaGroup::upload(ChatTarget::id(42))
+///     .file(|file| InputMedia::photo(file).caption("Madoka"), "madoka.jpg", vec![0xff, 0xd8])
+///     .photo("homura.jpg", vec![0xff, 0xd8])
+///     .media(InputMedia::photo(FileToSend::FileId(FileId("AgAD".to_string()))))
+///     .build()
+///     .unwrap();
+/// assert_eq!(files[1].name, "file1");
+/// let homura = FileToSend::InputFile(files[1].input_file());
+/// assert_eq!(method.media[1], InputMedia::photo(homura));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaGroupUpload<'a> {
+    builder: SendMediaGroupBuilder<'a>,
+    files: Vec<Attachment>,
+}
+
+impl<'a> MediaGroupUpload<'a> {
+    /// Add an item uploading `data` as `file_name`. `media` makes the item from the file
+    /// reference, such as `|file| InputMedia::video(file).caption("…")`.
+    pub fn file<F, N>(mut self, media: F, file_name: N, data: Vec<u8>) -> Self
+    where
+        F: FnOnce(FileToSend) -> InputMedia,
+        N: Into<String>,
+    {
+        let file = Attachment::new(format!("file{}", self.files.len()), file_name, data);
+        self.builder = self
+            .builder
+            .media(media(FileToSend::InputFile(file.input_file())));
+        self.files.push(file);
+        self
+    }
+
+    /// Add an item uploading the file at `path`, under its file name.
+    #[cfg(feature = "std")]
+    pub fn path<F, P>(self, media: F, path: P) -> std::io::Result<Self>
+    where
+        F: FnOnce(FileToSend) -> InputMedia,
+        P: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref();
+        let data = std::fs::read(path)?;
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(self.file(media, file_name, data))
+    }
+
+    /// Add a photo uploading `data` as `file_name`.
+    pub fn photo<N: Into<String>>(self, file_name: N, data: Vec<u8>) -> Self {
+        self.file(InputMedia::photo, file_name, data)
+    }
+
+    /// Add a video uploading `data` as `file_name`.
+    pub fn video<N: Into<String>>(self, file_name: N, data: Vec<u8>) -> Self {
+        self.file(InputMedia::video, file_name, data)
+    }
+
+    /// Add a document uploading `data` as `file_name`.
+    pub fn document<N: Into<String>>(self, file_name: N, data: Vec<u8>) -> Self {
+        self.file(InputMedia::document, file_name, data)
+    }
+
+    /// Add an audio file uploading `data` as `file_name`.
+    pub fn audio<N: Into<String>>(self, file_name: N, data: Vec<u8>) -> Self {
+        self.file(InputMedia::audio, file_name, data)
+    }
 
-    /// Sends messages [silently](https://telegram.org/blog/channels-2-0#silent-messages).
-    /// Users will receive a notification with no sound.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_notification: Option<bool>,
+    /// Add an item that is already on Telegram or at a URL, as it is.
+    pub fn media(mut self, media: InputMedia) -> Self {
+        self.builder = self.builder.media(media);
+        self
+    }
 
-    /// If the messages are a reply, ID of the original message
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_to_message_id: Option<types::MessageId>,
+    pub fn disable_notification(mut self, disable_notification: bool) -> Self {
+        self.builder = self.builder.disable_notification(disable_notification);
+        self
+    }
 
-    /// Pass True, if the message should be sent even if the specified replied-to message
-    /// is not found
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allow_sending_without_reply: Option<bool>,
+    pub fn reply_to_message_id(mut self, reply_to_message_id: MessageId) -> Self {
+        self.builder = self.builder.reply_to_message_id(reply_to_message_id);
+        self
+    }
+
+    pub fn allow_sending_without_reply(mut self, allow_sending_without_reply: bool) -> Self {
+        self.builder = self
+            .builder
+            .allow_sending_without_reply(allow_sending_without_reply);
+        self
+    }
+
+    /// The method and the files it refers to, checked as by [`SendMediaGroupBuilder::build`].
+    pub fn build(self) -> Result<(SendMediaGroup<'a>, Vec<Attachment>), MediaGroupError> {
+        Ok((self.builder.build()?, self.files))
+    }
 }
 
 /// Use this method to edit text and game messages sent by the bot or via the bot (for inline bots).
 /// On success, if edited message is sent by the bot, the edited [`Message`](types::Message) is
 /// returned, otherwise True is returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EditMessageText<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatTarget<'a>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<MessageId>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<Cow<'a, str>>,
+    #[serde(flatten)]
+    pub target: EditTarget<'a>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_cow))]
     pub text: Cow<'a, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_web_page_preview: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_keep")]
+    pub reply_markup: FieldUpdate<InlineKeyboardMarkup>,
 }
 
 impl<'a> EditMessageText<'a> {
-    pub fn new<T: Into<Cow<'a, str>>>(
-        chat_id: ChatTarget<'a>,
-        message_id: MessageId,
-        text: T,
-    ) -> EditMessageText<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(target: EditTarget<'a>, text: T) -> EditMessageText<'a> {
         EditMessageText {
-            chat_id: Some(chat_id),
-            message_id: Some(message_id),
-            inline_message_id: None,
+            target,
             text: text.into(),
             parse_mode: None,
             disable_web_page_preview: None,
-            reply_markup: None,
+            reply_markup: FieldUpdate::Keep,
         }
     }
 
@@ -649,7 +2435,15 @@ impl<'a> EditMessageText<'a> {
 
     pub fn reply_markup(self, markup: InlineKeyboardMarkup) -> Self {
         Self {
-            reply_markup: Some(markup),
+            reply_markup: FieldUpdate::Set(markup),
+            ..self
+        }
+    }
+
+    /// Remove the inline keyboard.
+    pub fn clear_reply_markup(self) -> Self {
+        Self {
+            reply_markup: FieldUpdate::Clear,
             ..self
         }
     }
@@ -658,37 +2452,41 @@ impl<'a> EditMessageText<'a> {
 /// Use this method to edit captions of messages sent by the bot or via the bot (for inline bots).
 /// On success, if edited message is sent by the bot, the edited [`Message`](types::Message) is
 /// returned, otherwise True is returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EditMessageCaption<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatTarget<'a>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<MessageId>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
+    #[serde(flatten)]
+    pub target: EditTarget<'a>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_keep")]
+    pub caption: FieldUpdate<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_keep")]
+    pub reply_markup: FieldUpdate<InlineKeyboardMarkup>,
 }
 
 impl<'a> EditMessageCaption<'a> {
-    pub fn new(chat_id: ChatTarget<'a>, message_id: MessageId) -> EditMessageCaption<'a> {
+    pub fn new(target: EditTarget<'a>) -> EditMessageCaption<'a> {
         EditMessageCaption {
-            chat_id: Some(chat_id),
-            message_id: Some(message_id),
-            inline_message_id: None,
-            caption: None,
+            target,
+            caption: FieldUpdate::Keep,
             parse_mode: None,
-            reply_markup: None,
+            reply_markup: FieldUpdate::Keep,
         }
     }
 
     pub fn caption(self, caption: String) -> EditMessageCaption<'a> {
         EditMessageCaption {
-            caption: Some(caption),
+            caption: FieldUpdate::Set(caption),
+            ..self
+        }
+    }
+
+    pub fn clear_caption(self) -> EditMessageCaption<'a> {
+        EditMessageCaption {
+            caption: FieldUpdate::Clear,
             ..self
         }
     }
@@ -702,7 +2500,15 @@ impl<'a> EditMessageCaption<'a> {
 
     pub fn reply_markup(self, markup: InlineKeyboardMarkup) -> Self {
         Self {
-            reply_markup: Some(markup),
+            reply_markup: FieldUpdate::Set(markup),
+            ..self
+        }
+    }
+
+    /// Remove the inline keyboard.
+    pub fn clear_reply_markup(self) -> Self {
+        Self {
+            reply_markup: FieldUpdate::Clear,
             ..self
         }
     }
@@ -712,31 +2518,24 @@ impl<'a> EditMessageCaption<'a> {
 /// type can be changed arbitrarily. When inline message is edited, new file can't be uploaded. Use
 /// previously uploaded file via its file_id or specify a URL. On success, if the edited message was
 /// sent by the bot, the edited [`Message`](types::Message) is returned, otherwise True is returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EditMessageMedia<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatTarget<'a>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<MessageId>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
+    #[serde(flatten)]
+    pub target: EditTarget<'a>,
     pub media: InputMedia,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_keep")]
+    pub reply_markup: FieldUpdate<InlineKeyboardMarkup>,
 }
 
 impl<'a> EditMessageMedia<'a> {
-    pub fn new(
-        chat_id: ChatTarget<'a>,
-        message_id: MessageId,
-        media: InputMedia,
-    ) -> EditMessageMedia<'a> {
+    pub fn new(target: EditTarget<'a>, media: InputMedia) -> EditMessageMedia<'a> {
         EditMessageMedia {
-            chat_id: Some(chat_id),
-            message_id: Some(message_id),
-            inline_message_id: None,
+            target,
             media,
-            reply_markup: None,
+            reply_markup: FieldUpdate::Keep,
         }
     }
 }
@@ -744,16 +2543,168 @@ impl<'a> EditMessageMedia<'a> {
 /// Use this method to edit only the reply markup of messages sent by the bot or via the bot (for
 /// inline bots). On success, if edited message is sent by the bot, the edited [`Message`](types::Message)
 /// is returned, otherwise True is returned.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EditMessageReplyMarkup<'a> {
+    #[serde(flatten)]
+    pub target: EditTarget<'a>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_keep")]
+    pub reply_markup: FieldUpdate<InlineKeyboardMarkup>,
+}
+
+impl<'a> EditMessageReplyMarkup<'a> {
+    pub fn new(target: EditTarget<'a>) -> EditMessageReplyMarkup<'a> {
+        EditMessageReplyMarkup {
+            target,
+            reply_markup: FieldUpdate::Keep,
+        }
+    }
+
+    pub fn reply_markup(self, markup: InlineKeyboardMarkup) -> Self {
+        Self {
+            reply_markup: FieldUpdate::Set(markup),
+            ..self
+        }
+    }
+
+    /// Remove the inline keyboard.
+    pub fn clear_reply_markup(self) -> Self {
+        Self {
+            reply_markup: FieldUpdate::Clear,
+            ..self
+        }
+    }
+}
+
+/// Use this method to move a live location, until its `live_period` expires or
+/// [`StopMessageLiveLocation`] stops it.
+///
+/// On success, if the edited message is not an inline message, the edited
+/// [`Message`](types::Message) is returned, otherwise True is returned.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct EditMessageLiveLocation<'a> {
+    #[serde(flatten)]
+    pub target: EditTarget<'a>,
+    pub latitude: f32,
+    pub longitude: f32,
+    /// New period in seconds during which the location can be updated, from now on. Can't be
+    /// longer than 0x7FFFFFFF, or than 86400 seconds past the original message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatTarget<'a>>,
+    pub live_period: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<MessageId>,
+    pub horizontal_accuracy: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<Cow<'a, str>>,
+    pub heading: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
+    pub proximity_alert_radius: Option<i64>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_keep")]
+    pub reply_markup: FieldUpdate<InlineKeyboardMarkup>,
+}
+
+impl<'a> EditMessageLiveLocation<'a> {
+    pub fn new(
+        target: EditTarget<'a>,
+        latitude: f32,
+        longitude: f32,
+    ) -> EditMessageLiveLocation<'a> {
+        EditMessageLiveLocation {
+            target,
+            latitude,
+            longitude,
+            live_period: None,
+            horizontal_accuracy: None,
+            heading: None,
+            proximity_alert_radius: None,
+            reply_markup: FieldUpdate::Keep,
+        }
+    }
+
+    pub fn live_period(self, live_period: i64) -> EditMessageLiveLocation<'a> {
+        EditMessageLiveLocation {
+            live_period: Some(live_period),
+            ..self
+        }
+    }
+
+    pub fn horizontal_accuracy(self, horizontal_accuracy: f32) -> EditMessageLiveLocation<'a> {
+        EditMessageLiveLocation {
+            horizontal_accuracy: Some(horizontal_accuracy),
+            ..self
+        }
+    }
+
+    pub fn heading(self, heading: i64) -> EditMessageLiveLocation<'a> {
+        EditMessageLiveLocation {
+            heading: Some(heading),
+            ..self
+        }
+    }
+
+    pub fn proximity_alert_radius(self, radius: i64) -> EditMessageLiveLocation<'a> {
+        EditMessageLiveLocation {
+            proximity_alert_radius: Some(radius),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: InlineKeyboardMarkup) -> Self {
+        Self {
+            reply_markup: FieldUpdate::Set(markup),
+            ..self
+        }
+    }
+
+    /// Remove the inline keyboard.
+    pub fn clear_reply_markup(self) -> Self {
+        Self {
+            reply_markup: FieldUpdate::Clear,
+            ..self
+        }
+    }
+}
+
+/// Use this method to stop updating a live location before its `live_period` expires.
+///
+/// On success, if the message is not an inline message, the edited [`Message`](types::Message)
+/// is returned, otherwise True is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StopMessageLiveLocation<'a> {
+    #[serde(flatten)]
+    pub target: EditTarget<'a>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_keep")]
+    pub reply_markup: FieldUpdate<InlineKeyboardMarkup>,
+}
+
+impl<'a> StopMessageLiveLocation<'a> {
+    pub fn new(target: EditTarget<'a>) -> StopMessageLiveLocation<'a> {
+        StopMessageLiveLocation {
+            target,
+            reply_markup: FieldUpdate::Keep,
+        }
+    }
+
+    pub fn reply_markup(self, markup: InlineKeyboardMarkup) -> Self {
+        Self {
+            reply_markup: FieldUpdate::Set(markup),
+            ..self
+        }
+    }
+
+    /// Remove the inline keyboard.
+    pub fn clear_reply_markup(self) -> Self {
+        Self {
+            reply_markup: FieldUpdate::Clear,
+            ..self
+        }
+    }
 }
 
 /// Use this method to delete a message, including service messages, with the following limitations:
@@ -765,93 +2716,475 @@ pub struct EditMessageReplyMarkup<'a> {
 /// - If the bot has can_delete_messages permission in a supergroup or a channel, it can delete any message there.
 ///
 /// Returns True on success.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteMessage<'a> {
     pub chat_id: ChatTarget<'a>,
     pub message_id: MessageId,
 }
 /// Use this method to approve a chat join request.
-/// 
+///
 /// The bot must be an administrator in the chat for this to work and must have the `can_invite_users` administrator right.
 /// Returns True on success.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ApproveJoinRequest<'a> {
     pub chat_id: ChatTarget<'a>,
     pub user_id: UserId,
 }
 
+impl<'a> ApproveJoinRequest<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, user_id: UserId) -> ApproveJoinRequest<'a> {
+        ApproveJoinRequest { chat_id, user_id }
+    }
+}
+
+impl From<&types::ChatJoinRequest> for ApproveJoinRequest<'_> {
+    fn from(request: &types::ChatJoinRequest) -> Self {
+        ApproveJoinRequest::new(ChatTarget::Id(request.chat.id), request.from.id)
+    }
+}
+
 /// Use this method to decline a chat join request.
-/// 
+///
 /// The bot must be an administrator in the chat for this to work and must have the `can_invite_users` administrator right.
 /// Returns True on success.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeclineJoinRequest<'a> {
     pub chat_id: ChatTarget<'a>,
     pub user_id: UserId,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+impl<'a> DeclineJoinRequest<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, user_id: UserId) -> DeclineJoinRequest<'a> {
+        DeclineJoinRequest { chat_id, user_id }
+    }
+}
+
+impl From<&types::ChatJoinRequest> for DeclineJoinRequest<'_> {
+    fn from(request: &types::ChatJoinRequest) -> Self {
+        DeclineJoinRequest::new(ChatTarget::Id(request.chat.id), request.from.id)
+    }
+}
+
+/// Use this method to change the list of the bot's commands. Returns True on success.
+///
+/// [More info](https://core.telegram.org/bots/features#commands)
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SetMyCommands<'a> {
+    /// At most 100 commands.
+    pub commands: Vec<types::BotCommand>,
+
+    /// The users the commands are for, [`BotCommandScope::Default`] if left out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope<'a>>,
+
+    /// A two-letter ISO 639-1 language code. If empty, the commands are for all users of the
+    /// scope who don't have commands in their language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> SetMyCommands<'a> {
+    pub fn new(commands: Vec<types::BotCommand>) -> SetMyCommands<'a> {
+        SetMyCommands {
+            commands,
+            scope: None,
+            language_code: None,
+        }
+    }
+
+    pub fn scope(self, scope: BotCommandScope<'a>) -> SetMyCommands<'a> {
+        SetMyCommands {
+            scope: Some(scope),
+            ..self
+        }
+    }
+
+    pub fn language_code<T: Into<Cow<'a, str>>>(self, language_code: T) -> SetMyCommands<'a> {
+        SetMyCommands {
+            language_code: Some(language_code.into()),
+            ..self
+        }
+    }
+}
+
+/// Use this method to get the bot's commands for a scope and language, exactly as set: no
+/// narrower or wider scope applies. Returns an empty list if there are none.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetMyCommands<'a> {
+    /// [`BotCommandScope::Default`] if left out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope<'a>>,
+
+    /// A two-letter ISO 639-1 language code, or empty for the commands of users in the scope
+    /// without commands in their language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> GetMyCommands<'a> {
+    pub fn new() -> GetMyCommands<'a> {
+        Default::default()
+    }
+
+    pub fn scope(self, scope: BotCommandScope<'a>) -> GetMyCommands<'a> {
+        GetMyCommands {
+            scope: Some(scope),
+            ..self
+        }
+    }
+
+    pub fn language_code<T: Into<Cow<'a, str>>>(self, language_code: T) -> GetMyCommands<'a> {
+        GetMyCommands {
+            language_code: Some(language_code.into()),
+            ..self
+        }
+    }
+}
+
+/// Use this method to delete the bot's commands for a scope and language. Users then see the
+/// commands of the next wider scope. Returns True on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeleteMyCommands<'a> {
+    /// [`BotCommandScope::Default`] if left out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope<'a>>,
+
+    /// A two-letter ISO 639-1 language code, or empty for the commands of users in the scope
+    /// without commands in their language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::bot::utils::arbitrary_optional_cow))]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> DeleteMyCommands<'a> {
+    pub fn new() -> DeleteMyCommands<'a> {
+        Default::default()
+    }
+
+    pub fn scope(self, scope: BotCommandScope<'a>) -> DeleteMyCommands<'a> {
+        DeleteMyCommands {
+            scope: Some(scope),
+            ..self
+        }
+    }
+
+    pub fn language_code<T: Into<Cow<'a, str>>>(self, language_code: T) -> DeleteMyCommands<'a> {
+        DeleteMyCommands {
+            language_code: Some(language_code.into()),
+            ..self
+        }
+    }
+}
+
+/// The users bot commands are shown to. The narrowest scope that applies wins.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BotCommandScope<'a> {
+    Default,
+    AllPrivateChats,
+    AllGroupChats,
+    AllChatAdministrators,
+    Chat {
+        chat_id: ChatTarget<'a>,
+    },
+    ChatAdministrators {
+        chat_id: ChatTarget<'a>,
+    },
+    ChatMember {
+        chat_id: ChatTarget<'a>,
+        user_id: UserId,
+    },
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMe;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteWebhook;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetWebhookInfo;
 
+/// Use this method to log out from the cloud Bot API server before launching the bot locally.
+/// After a successful call the bot can log in on a local server at once, but not back in the
+/// cloud for 10 minutes.
+///
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LogOut;
+
+/// Use this method to close the bot instance before moving it from one local server to
+/// another. Delete the webhook first, or the bot is launched again when the server restarts.
+/// Telegram answers 429 in the first 10 minutes after the bot is launched.
+///
+/// Returns True on success.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Close;
+
+/// Use this method to get basic info about a file and prepare it for downloading. On the cloud
+/// server files of up to 20 MB can be downloaded.
+///
+/// Returns a [`File`](types::File) object on success, its `file_path` valid for at least an hour.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetFile<'a> {
+    pub file_id: Cow<'a, str>,
+}
+
+impl<'a> GetFile<'a> {
+    pub fn new<S: Into<Cow<'a, str>>>(file_id: S) -> GetFile<'a> {
+        GetFile {
+            file_id: file_id.into(),
+        }
+    }
+}
+
+impl<'a> From<&'a types::FileId> for GetFile<'a> {
+    fn from(file_id: &'a types::FileId) -> GetFile<'a> {
+        GetFile::new(file_id.0.as_str())
+    }
+}
+
 /// Telegram methods.
 pub trait Method: Serialize {
     /// Method name in the Telegram Bot API url.
     const NAME: &'static str;
+    /// Bot API version that introduced the method, such as `"5.4"`.
+    ///
+    /// Methods older than the numbered changelog report `"1.0"`.
+    const SINCE: &'static str;
     /// Method return type.
     type Item: DeserializeOwned + fmt::Debug + 'static;
 
+    /// The chat this method sends a message to, the one Telegram's flood limits count it against.
+    /// `None` for methods that don't send messages.
+    fn message_chat(&self) -> Option<&ChatTarget<'_>> {
+        None
+    }
+
+    /// How long Telegram may hold the call open before answering, the
+    /// [`timeout`](GetUpdates::timeout) of a long poll. Clients allow that much more time for the
+    /// call.
+    fn long_poll(&self) -> Option<core::time::Duration> {
+        None
+    }
+
     /// Get method url.
     fn url(token: &str) -> String {
-        format!("https://api.telegram.org/bot{}/{}", token, Self::NAME)
+        Self::url_in(crate::bot::API_URL, token)
+    }
+
+    /// The method's URL at the Bot API server at `api_url`, such as a local one.
+    fn url_in(api_url: &str, token: &str) -> String {
+        let api_url = api_url.trim_end_matches('/');
+        format!("{}/bot{}/{}", api_url, token, Self::NAME)
+    }
+
+    /// Read the response to this method with any serde deserializer.
+    ///
+    /// The outer error is a malformed response, the inner one an error reported by Telegram.
+    fn parse_response<'de, D>(deserializer: D) -> Result<Result<Self::Item, ApiError>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        TelegramResult::deserialize(deserializer).map(TelegramResult::into_result)
+    }
+
+    /// Read the response to this method from JSON, see [`parse_response`](Method::parse_response).
+    #[cfg(feature = "json")]
+    fn response_from_slice(
+        bytes: &[u8],
+    ) -> Result<Result<Self::Item, ApiError>, serde_json::Error> {
+        Self::parse_response(&mut serde_json::Deserializer::from_slice(bytes))
+    }
+
+    /// Read the response to this method from JSON with simd-json, which parses `bytes` in place.
+    #[cfg(feature = "simd-json")]
+    fn response_from_simd_slice(
+        bytes: &mut [u8],
+    ) -> Result<Result<Self::Item, ApiError>, simd_json::Error> {
+        Self::parse_response(&mut simd_json::Deserializer::from_slice(bytes)?)
     }
 }
 
 #[rustfmt::skip]
 impl_method_table!(
-//  [                MethodType,       method_url_segment,          ApiReturnType],
-    [                     GetMe,                  "getMe",            types::User],
-    [             DeleteWebhook,          "deleteWebhook",                   bool],
-    [            GetWebhookInfo,         "getWebhookInfo",     types::WebhookInfo],
-    [            GetUpdates<'_>,             "getUpdates",     Vec<types::Update>],
-    [            SetWebhook<'_>,             "setWebhook",                   bool],
-    [           SendMessage<'_>,            "sendMessage",         types::Message],
-    [        ForwardMessage<'_>,         "forwardMessage",         types::Message],
-    [           CopyMessage<'_>,            "copyMessage", types::MessageIdResult],
-    [        SendMediaGroup<'_>,         "sendMediaGroup",    Vec<types::Message>],
-    [       EditMessageText<'_>,        "editMessageText",         types::Message],
-    [      EditMessageMedia<'_>,       "editMessageMedia",         types::Message],
-    [EditMessageReplyMarkup<'_>, "editMessageReplyMarkup",         types::Message],
-    [         DeleteMessage<'_>,          "deleteMessage",                   bool],
-    [    EditMessageCaption<'_>,     "editMessageCaption",                   bool],
-    [           SendSticker<'_>,            "sendSticker",         types::Message],
-    [             SendPhoto<'_>,              "sendPhoto",         types::Message],
-    [          SendDocument<'_>,           "sendDocument",         types::Message],
-    [               GetChat<'_>,                "getChat",            types::Chat],
-    [ GetChatAdministrators<'_>,  "getChatAdministrators", Vec<types::ChatMember>],
-    [   GetChatMembersCount<'_>,    "getChatMembersCount",                    i32],
-    [         GetChatMember<'_>,          "getChatMember",      types::ChatMember],
-    [       AnswerCallbackQuery,    "answerCallbackQuery",                   bool],
-    [    ApproveJoinRequest<'_>, "approveChatJoinRequest",                   bool],
-    [    DeclineJoinRequest<'_>, "declineChatJoinRequest",                   bool]
+//  [                MethodType,       method_url_segment,          ApiReturnType,   since, message_chat],
+    [                     GetMe,                  "getMe",            types::User,   "1.0"],
+    [             DeleteWebhook,          "deleteWebhook",                   bool, "2.3.1"],
+    [            GetWebhookInfo,         "getWebhookInfo",     types::WebhookInfo,   "2.2"],
+    [                    LogOut,                 "logOut",                   bool,   "5.0"],
+    [                     Close,                  "close",                   bool,   "5.0"],
+    [               GetFile<'_>,                "getFile",            types::File,   "1.0"],
+    [      GetUserProfilePhotos,   "getUserProfilePhotos", types::UserProfilePhotos,   "1.0"],
+    [            SetWebhook<'_>,             "setWebhook",                   bool,   "1.0"],
+    [           SendMessage<'_>,            "sendMessage",         types::Message,   "1.0", chat_id],
+    [        SendChatAction<'_>,         "sendChatAction",                   bool,   "1.0"],
+    [        ForwardMessage<'_>,         "forwardMessage",         types::Message,   "1.0", chat_id],
+    [           CopyMessage<'_>,            "copyMessage", types::MessageIdResult,   "5.0", chat_id],
+    [        SendMediaGroup<'_>,         "sendMediaGroup",    Vec<types::Message>,   "3.5", chat_id],
+    [       EditMessageText<'_>,        "editMessageText",      types::EditResult,   "2.0"],
+    [      EditMessageMedia<'_>,       "editMessageMedia",      types::EditResult,   "4.0"],
+    [EditMessageReplyMarkup<'_>, "editMessageReplyMarkup",      types::EditResult,   "2.0"],
+    [EditMessageLiveLocation<'_>, "editMessageLiveLocation",   types::EditResult,   "3.4"],
+    [StopMessageLiveLocation<'_>, "stopMessageLiveLocation",   types::EditResult,   "3.4"],
+    [         DeleteMessage<'_>,          "deleteMessage",                   bool,   "3.0"],
+    [    EditMessageCaption<'_>,     "editMessageCaption",      types::EditResult,   "2.0"],
+    [             SendPhoto<'_>,              "sendPhoto",         types::Message,   "1.0", chat_id],
+    [          SendDocument<'_>,           "sendDocument",         types::Message,   "1.0", chat_id],
+    [             SendAudio<'_>,              "sendAudio",         types::Message,   "1.0", chat_id],
+    [             SendVideo<'_>,              "sendVideo",         types::Message,   "1.0", chat_id],
+    [         SendAnimation<'_>,          "sendAnimation",         types::Message,   "4.0", chat_id],
+    [             SendVoice<'_>,              "sendVoice",         types::Message,   "1.0", chat_id],
+    [         SendVideoNote<'_>,          "sendVideoNote",         types::Message,   "3.0", chat_id],
+    [          SendLocation<'_>,           "sendLocation",         types::Message,   "1.0", chat_id],
+    [              SendDice<'_>,               "sendDice",         types::Message,   "4.7", chat_id],
+    [               GetChat<'_>,                "getChat",            types::Chat,   "2.1"],
+    [ GetChatAdministrators<'_>,  "getChatAdministrators", Vec<types::ChatMember>,   "2.1"],
+    [   GetChatMembersCount<'_>,    "getChatMembersCount",                    i64,   "2.1"],
+    [         GetChatMember<'_>,          "getChatMember",      types::ChatMember,   "2.1"],
+    [         BanChatMember<'_>,          "banChatMember",                   bool,   "5.3"],
+    [       UnbanChatMember<'_>,        "unbanChatMember",                   bool,   "2.0"],
+    [     BanChatSenderChat<'_>,      "banChatSenderChat",                   bool,   "5.5"],
+    [   UnbanChatSenderChat<'_>,    "unbanChatSenderChat",                   bool,   "5.5"],
+    [    RestrictChatMember<'_>,     "restrictChatMember",                   bool,   "3.1"],
+    [    SetChatPermissions<'_>,     "setChatPermissions",                   bool,   "4.4"],
+    [     PromoteChatMember<'_>,      "promoteChatMember",                   bool,   "3.1"],
+    [          SetChatTitle<'_>,           "setChatTitle",                   bool,   "3.1"],
+    [    SetChatDescription<'_>,     "setChatDescription",                   bool,   "3.1"],
+    [          SetChatPhoto<'_>,           "setChatPhoto",                   bool,   "3.1"],
+    [       DeleteChatPhoto<'_>,        "deleteChatPhoto",                   bool,   "3.1"],
+    [             LeaveChat<'_>,              "leaveChat",                   bool,   "2.1"],
+    [        PinChatMessage<'_>,         "pinChatMessage",                   bool,   "3.1"],
+    [      UnpinChatMessage<'_>,       "unpinChatMessage",                   bool,   "3.1"],
+    [  UnpinAllChatMessages<'_>,   "unpinAllChatMessages",                   bool,   "5.0"],
+    [SetMyDefaultAdministratorRights, "setMyDefaultAdministratorRights",     bool,   "6.0"],
+    [GetMyDefaultAdministratorRights, "getMyDefaultAdministratorRights", types::ChatAdministratorRights, "6.0"],
+    [       AnswerCallbackQuery,    "answerCallbackQuery",                   bool,   "2.0"],
+    [    ApproveJoinRequest<'_>, "approveChatJoinRequest",                   bool,   "5.4"],
+    [    DeclineJoinRequest<'_>, "declineChatJoinRequest",                   bool,   "5.4"],
+    [         SetMyCommands<'_>,          "setMyCommands",                   bool,   "4.7"],
+    [         GetMyCommands<'_>,          "getMyCommands", Vec<types::BotCommand>,   "4.7"],
+    [      DeleteMyCommands<'_>,       "deleteMyCommands",                   bool,   "5.3"]
 );
 
+impl Method for GetUpdates<'_> {
+    const NAME: &'static str = "getUpdates";
+    const SINCE: &'static str = "1.0";
+    type Item = Vec<types::Update>;
+
+    fn long_poll(&self) -> Option<core::time::Duration> {
+        let seconds = self.timeout?.max(0) as u64;
+        Some(core::time::Duration::from_secs(seconds))
+    }
+}
+
+/// A method to answer a webhook request with, saving a request of its own.
+///
+/// Serialized as the method's parameters plus its name in a `method` field. Telegram doesn't
+/// report whether such a call succeeded.
+///
+/// [More info](https://core.telegram.org/bots/api#making-requests-when-getting-updates)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WebhookReply<M>(pub M);
+
+impl<M: Method> Serialize for WebhookReply<M> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Reply<'a, M> {
+            method: &'static str,
+            #[serde(flatten)]
+            parameters: &'a M,
+        }
+
+        Reply {
+            method: M::NAME,
+            parameters: &self.0,
+        }
+        .serialize(serializer)
+    }
+}
+
 // https://core.telegram.org/bots/api#making-requests
-#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TelegramResult<T> {
     pub ok: bool,
+    #[cfg_attr(feature = "full-serde", serde(skip_serializing_if = "Option::is_none"))]
     pub description: Option<String>,
+    #[cfg_attr(feature = "full-serde", serde(skip_serializing_if = "Option::is_none"))]
     pub error_code: Option<i32>,
+    #[cfg_attr(feature = "full-serde", serde(skip_serializing_if = "Option::is_none"))]
     pub result: Option<T>,
+    #[cfg_attr(feature = "full-serde", serde(skip_serializing_if = "Option::is_none"))]
     pub parameters: Option<types::ResponseParameters>,
 }
 
 impl<T> TelegramResult<T> {
+    /// A successful response with `result`.
+    pub fn ok(result: T) -> TelegramResult<T> {
+        TelegramResult {
+            ok: true,
+            description: None,
+            error_code: None,
+            result: Some(result),
+            parameters: None,
+        }
+    }
+
+    /// A failed response.
+    pub fn error<S: Into<String>>(error_code: i32, description: S) -> TelegramResult<T> {
+        TelegramResult {
+            ok: false,
+            description: Some(description.into()),
+            error_code: Some(error_code),
+            result: None,
+            parameters: None,
+        }
+    }
+
     /// Convert the `TelegramResult` into `std` `Result`.
     pub fn into_result(self) -> Result<T, ApiError> {
         if self.ok {
@@ -881,9 +3214,9 @@ impl<T> TelegramResult<T> {
     }
 }
 
-impl<T> Into<Result<T, ApiError>> for TelegramResult<T> {
-    fn into(self) -> Result<T, ApiError> {
-        self.into_result()
+impl<T> From<TelegramResult<T>> for Result<T, ApiError> {
+    fn from(result: TelegramResult<T>) -> Self {
+        result.into_result()
     }
 }
 
@@ -891,6 +3224,8 @@ pub type UpdateList = TelegramResult<Vec<types::Update>>;
 
 /// Types of updates.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
 pub enum UpdateTypes {
     /// New incoming message of any kind — text, photo, sticker, etc.
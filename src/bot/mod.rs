@@ -1,23 +1,40 @@
 //! Bot relative API low-level type define.
 
 macro_rules! impl_method {
-    ($MethodType: ty, $url_fragment: expr, $ReTurnType: ty) => {
+    ($MethodType: ty, $url_fragment: expr, $ReTurnType: ty, $since: expr $(, $chat: ident)?) => {
         impl $crate::bot::methods::Method for $MethodType {
             const NAME: &'static str = $url_fragment;
+            const SINCE: &'static str = $since;
             type Item = $ReTurnType;
+            $(
+                fn message_chat(&self) -> Option<&$crate::bot::methods::ChatTarget<'_>> {
+                    Some(&self.$chat)
+                }
+            )?
         }
     };
 }
 
 macro_rules! impl_method_table {
 
-    ($([$MethodType: ty, $url_fragment: expr, $ReTurnType: ty]),*) => {
-        $(impl_method!($MethodType, $url_fragment, $ReTurnType);)*
+    ($([$MethodType: ty, $url_fragment: expr, $ReTurnType: ty, $since: expr $(, $chat: ident)?]),*) => {
+        $(impl_method!($MethodType, $url_fragment, $ReTurnType, $since $(, $chat)?);)*
     };
 }
 
+/// The public Bot API server.
+pub(crate) const API_URL: &str = "https://api.telegram.org";
+
+pub mod borrowed;
+#[cfg(feature = "games")]
 pub mod games;
+#[cfg(feature = "inline-mode")]
 pub mod inline_mode;
 pub mod methods;
+#[cfg(feature = "passport")]
+pub mod passport;
+#[cfg(feature = "payments")]
+pub mod payments;
+pub mod text;
 pub mod types;
 mod utils;
@@ -0,0 +1,215 @@
+//! Telegram Passport: personal documents users share with the bot, and reporting errors in them.
+//!
+//! The data arrives encrypted. Decrypting it needs the bot's private key and is left to the
+//! bot, see [the guide](https://core.telegram.org/passport#receiving-information).
+use super::types::{FileId, Time, UserId};
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Telegram Passport data shared with the bot by the user.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportData {
+    /// Information about documents and other Telegram Passport elements that was shared
+    pub data: Vec<EncryptedPassportElement>,
+    /// Credentials required to decrypt the data
+    pub credentials: EncryptedCredentials,
+}
+
+/// A file uploaded to Telegram Passport, in JPEG format when decrypted and at most 10 MB.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PassportFile {
+    pub file_id: FileId,
+    /// Identifier that stays the same over time and across bots, but can't download the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_unique_id: Option<String>,
+    /// File size in bytes
+    pub file_size: i64,
+    /// When the file was uploaded
+    pub file_date: Time,
+}
+
+/// Kind of a Telegram Passport element.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptedPassportElementType {
+    PersonalDetails,
+    Passport,
+    DriverLicense,
+    IdentityCard,
+    InternalPassport,
+    Address,
+    UtilityBill,
+    BankStatement,
+    RentalAgreement,
+    PassportRegistration,
+    TemporaryRegistration,
+    PhoneNumber,
+    Email,
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+/// A document or other element shared with the bot, with which of its fields depending on
+/// [`kind`](EncryptedPassportElement::kind).
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EncryptedPassportElement {
+    #[serde(rename = "type")]
+    pub kind: EncryptedPassportElementType,
+    /// Base64-encoded encrypted data provided by the user, for personal details, identity
+    /// documents and addresses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// User's verified phone number, for `phone_number` elements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// User's verified email address, for `email` elements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Encrypted files of utility bills, bank statements, rental agreements and registrations
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<PassportFile>,
+    /// Encrypted file with the front side of an identity document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_side: Option<PassportFile>,
+    /// Encrypted file with the reverse side of a driver license or identity card
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_side: Option<PassportFile>,
+    /// Encrypted file with the selfie of the user holding an identity document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<PassportFile>,
+    /// Encrypted files with translated versions of documents
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub translation: Vec<PassportFile>,
+    /// Base64-encoded element hash, for [`PassportElementError`]
+    pub hash: String,
+}
+
+/// The secret to decrypt the [`EncryptedPassportElement`]s with, itself encrypted with the
+/// bot's public key.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EncryptedCredentials {
+    /// Base64-encoded encrypted JSON-serialized data with unique user's payload, data hashes
+    /// and secrets
+    pub data: String,
+    /// Base64-encoded data hash for data authentication
+    pub hash: String,
+    /// Base64-encoded secret, encrypted with the bot's public RSA key
+    pub secret: String,
+}
+
+/// An error in a Telegram Passport element, which the user must fix before resubmitting it.
+///
+/// The hashes are the base64-encoded ones of the field, file or element that has the error.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum PassportElementError {
+    /// An error in a field of the element's `data`.
+    Data {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        field_name: String,
+        data_hash: String,
+        message: String,
+    },
+    /// An issue with the front side of a document.
+    FrontSide {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the reverse side of a document.
+    ReverseSide {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the selfie with a document.
+    Selfie {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with a document scan.
+    File {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the list of scans.
+    Files {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    /// An issue with one of the files of a translation.
+    TranslationFile {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the translation of a document.
+    TranslationFiles {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    /// An issue in an unspecified place.
+    Unspecified {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        element_hash: String,
+        message: String,
+    },
+}
+
+/// Tell the user that some of the Telegram Passport elements they provided contain errors.
+/// They won't be able to resubmit the elements until the errors are fixed.
+///
+/// On success, True is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SetPassportDataErrors {
+    pub user_id: UserId,
+    pub errors: Vec<PassportElementError>,
+}
+
+impl_method!(SetPassportDataErrors, "setPassportDataErrors", bool, "4.0");
+
+impl SetPassportDataErrors {
+    pub fn new(user_id: UserId, errors: Vec<PassportElementError>) -> SetPassportDataErrors {
+        SetPassportDataErrors { user_id, errors }
+    }
+}
@@ -0,0 +1,703 @@
+//! Payments: invoices, shipping and the payments they lead to.
+//!
+//! Amounts are integers in the smallest units of the currency, such as cents for `USD`: `145`
+//! is US$ 1.45. See `exp` in [currencies.json](https://core.telegram.org/bots/payments/currencies.json)
+//! for the number of digits past the decimal point of each currency.
+use super::methods::ChatTarget;
+use super::types::{Chat, InlineKeyboardMarkup, MessageId, Time, User, UserId};
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A portion of the price of goods or services.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LabeledPrice {
+    pub label: String,
+    /// Price in the smallest units of the currency
+    pub amount: i64,
+}
+
+impl LabeledPrice {
+    pub fn new<S: Into<String>>(label: S, amount: i64) -> LabeledPrice {
+        LabeledPrice {
+            label: label.into(),
+            amount,
+        }
+    }
+}
+
+/// Basic information about an invoice.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Invoice {
+    /// Product name
+    pub title: String,
+    /// Product description
+    pub description: String,
+    /// Unique bot deep-linking parameter that can be used to generate this invoice
+    pub start_parameter: String,
+    /// Three-letter ISO 4217 currency code
+    pub currency: String,
+    /// Total price in the smallest units of the currency
+    pub total_amount: i64,
+}
+
+/// A shipping address.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ShippingAddress {
+    /// Two-letter ISO 3166-1 alpha-2 country code
+    pub country_code: String,
+    /// State, if applicable
+    pub state: String,
+    pub city: String,
+    /// First line for the address
+    pub street_line1: String,
+    /// Second line for the address
+    pub street_line2: String,
+    pub post_code: String,
+}
+
+/// Information about an order, as much of it as the invoice asked for.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OrderInfo {
+    /// User name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<ShippingAddress>,
+}
+
+/// One shipping option, to offer in answer to a shipping query.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ShippingOption {
+    /// Shipping option identifier
+    pub id: String,
+    /// Option title
+    pub title: String,
+    /// Price portions
+    pub prices: Vec<LabeledPrice>,
+}
+
+impl ShippingOption {
+    pub fn new<I, T>(id: I, title: T, prices: Vec<LabeledPrice>) -> ShippingOption
+    where
+        I: Into<String>,
+        T: Into<String>,
+    {
+        ShippingOption {
+            id: id.into(),
+            title: title.into(),
+            prices,
+        }
+    }
+}
+
+/// Basic information about a successful payment.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SuccessfulPayment {
+    /// Three-letter ISO 4217 currency code
+    pub currency: String,
+    /// Total price in the smallest units of the currency
+    pub total_amount: i64,
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+    /// Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_option_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_info: Option<OrderInfo>,
+    pub telegram_payment_charge_id: String,
+    pub provider_payment_charge_id: String,
+}
+
+/// Reply to a shipping query, sent for invoices asking for a shipping address whose price
+/// depends on it.
+///
+/// On success, True is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AnswerShippingQuery {
+    /// Unique identifier for the query to be answered
+    pub shipping_query_id: String,
+    /// Whether delivery to the address is possible
+    pub ok: bool,
+    /// Available shipping options, required if `ok` is true
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shipping_options: Vec<ShippingOption>,
+    /// Why the order can't be completed, shown to the user. Required if `ok` is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl_method!(AnswerShippingQuery, "answerShippingQuery", bool, "3.0");
+
+impl AnswerShippingQuery {
+    /// Offer `shipping_options` for the address.
+    pub fn ok(shipping_query_id: String, shipping_options: Vec<ShippingOption>) -> Self {
+        Self {
+            shipping_query_id,
+            ok: true,
+            shipping_options,
+            error_message: None,
+        }
+    }
+
+    /// Refuse to deliver to the address, telling the user why.
+    pub fn error(shipping_query_id: String, error_message: String) -> Self {
+        Self {
+            shipping_query_id,
+            ok: false,
+            shipping_options: Vec::new(),
+            error_message: Some(error_message),
+        }
+    }
+}
+
+/// Confirm or refuse an order before the payment goes through. Must be answered within
+/// 10 seconds of the pre-checkout query.
+///
+/// On success, True is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AnswerPreCheckoutQuery {
+    /// Unique identifier for the query to be answered
+    pub pre_checkout_query_id: String,
+    /// Whether the goods are available and the bot is ready to proceed with the order
+    pub ok: bool,
+    /// Why the order can't be completed, shown to the user. Required if `ok` is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl_method!(
+    AnswerPreCheckoutQuery,
+    "answerPreCheckoutQuery",
+    bool,
+    "3.0"
+);
+
+impl AnswerPreCheckoutQuery {
+    pub fn ok(pre_checkout_query_id: String) -> Self {
+        Self {
+            pre_checkout_query_id,
+            ok: true,
+            error_message: None,
+        }
+    }
+
+    pub fn error(pre_checkout_query_id: String, error_message: String) -> Self {
+        Self {
+            pre_checkout_query_id,
+            ok: false,
+            error_message: Some(error_message),
+        }
+    }
+}
+
+/// Basic information about a refunded payment.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RefundedPayment {
+    /// Three-letter ISO 4217 currency code, or `XTR` for Telegram Stars. Currently always `XTR`
+    pub currency: String,
+    /// Total refunded price in the smallest units of the currency
+    pub total_amount: i64,
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+    pub telegram_payment_charge_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_payment_charge_id: Option<String>,
+}
+
+/// A transfer of Telegram Stars to or from the bot.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. For payments by users, the same as
+    /// [`SuccessfulPayment::telegram_payment_charge_id`].
+    pub id: String,
+    /// Number of Telegram Stars transferred
+    pub amount: i64,
+    /// Number of 1/1000000000 shares of Telegram Stars transferred, 0-999999999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nanostar_amount: Option<i64>,
+    pub date: Time,
+    /// Where the Stars came from, for incoming transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<TransactionPartner>,
+    /// Where the Stars went, for outgoing transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<TransactionPartner>,
+}
+
+impl StarTransaction {
+    /// Whether the Stars went to the bot.
+    pub fn is_incoming(&self) -> bool {
+        self.source.is_some()
+    }
+}
+
+/// A page of the bot's Star transactions, returned by [`GetStarTransactions`].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StarTransactions {
+    pub transactions: Vec<StarTransaction>,
+}
+
+/// The other side of a [`StarTransaction`].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionPartner {
+    /// A user, paying an invoice or receiving a refund.
+    User {
+        user: User,
+        /// Bot specified invoice payload
+        #[serde(skip_serializing_if = "Option::is_none")]
+        invoice_payload: Option<String>,
+        /// Duration of the paid subscription, in seconds
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subscription_period: Option<i64>,
+    },
+    /// A chat, such as a channel the bot boosted with Stars.
+    Chat { chat: Box<Chat> },
+    /// A withdrawal of the bot's earnings through Fragment.
+    Fragment {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        withdrawal_state: Option<RevenueWithdrawalState>,
+    },
+    /// Payment for advertising on Telegram.
+    TelegramAds,
+    /// Payment for paid broadcasts through the Bot API.
+    TelegramApi {
+        /// Number of successful requests that exceeded the free limits and were paid for
+        request_count: i64,
+    },
+    /// Commission from an affiliate program.
+    AffiliateProgram {
+        /// The bot that sponsored the affiliate program
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sponsor_user: Option<User>,
+        /// Stars received per 1000 Stars of referred users' purchases
+        commission_per_mille: i64,
+    },
+    /// A transaction with an unknown source or recipient.
+    Other,
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+/// State of a revenue withdrawal through Fragment.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum RevenueWithdrawalState {
+    /// The withdrawal is in progress.
+    Pending,
+    Succeeded {
+        /// When the withdrawal completed
+        date: Time,
+        /// An HTTPS URL to see transaction details
+        url: String,
+    },
+    /// The withdrawal failed and the transaction was refunded.
+    Failed,
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+/// What an invoice is for and what it asks from the user, as sent with [`SendInvoice`] and
+/// [`CreateInvoiceLink`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct InvoiceParams {
+    /// Product name, 1-32 characters
+    pub title: String,
+    /// Product description, 1-255 characters
+    pub description: String,
+    /// Bot-defined invoice payload, 1-128 bytes. Not shown to the user, for the bot's own use.
+    pub payload: String,
+    /// Payment provider token, from [@BotFather](https://t.me/botfather). Empty or absent for
+    /// payments in Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, or `XTR` for Telegram Stars
+    pub currency: String,
+    /// Price breakdown: product price, tax, discount, delivery cost, ...
+    pub prices: Vec<LabeledPrice>,
+    /// Maximum tip the user may add, in the smallest units of the currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+    /// Up to 4 suggested tips, positive, increasing and at most `max_tip_amount`
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggested_tip_amounts: Vec<i64>,
+    /// JSON data about the invoice, shared with the payment provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    /// URL of a product photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    /// Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+    /// Whether the final price depends on the shipping method, which makes Telegram send a
+    /// shipping query to answer with [`AnswerShippingQuery`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+}
+
+impl InvoiceParams {
+    pub fn new<T, D, P, C>(
+        title: T,
+        description: D,
+        payload: P,
+        currency: C,
+        prices: Vec<LabeledPrice>,
+    ) -> InvoiceParams
+    where
+        T: Into<String>,
+        D: Into<String>,
+        P: Into<String>,
+        C: Into<String>,
+    {
+        InvoiceParams {
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: None,
+            currency: currency.into(),
+            prices,
+            max_tip_amount: None,
+            suggested_tip_amounts: Vec::new(),
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: None,
+            need_phone_number: None,
+            need_email: None,
+            need_shipping_address: None,
+            send_phone_number_to_provider: None,
+            send_email_to_provider: None,
+            is_flexible: None,
+        }
+    }
+
+    pub fn provider_token<S: Into<String>>(self, provider_token: S) -> Self {
+        Self {
+            provider_token: Some(provider_token.into()),
+            ..self
+        }
+    }
+
+    /// Let the user add a tip of up to `max_tip_amount`, offering `suggested_tip_amounts`.
+    pub fn tips(self, max_tip_amount: i64, suggested_tip_amounts: Vec<i64>) -> Self {
+        Self {
+            max_tip_amount: Some(max_tip_amount),
+            suggested_tip_amounts,
+            ..self
+        }
+    }
+
+    pub fn provider_data<S: Into<String>>(self, provider_data: S) -> Self {
+        Self {
+            provider_data: Some(provider_data.into()),
+            ..self
+        }
+    }
+
+    pub fn photo<S: Into<String>>(self, photo_url: S) -> Self {
+        Self {
+            photo_url: Some(photo_url.into()),
+            ..self
+        }
+    }
+
+    pub fn photo_size(self, photo_size: i64, width: i64, height: i64) -> Self {
+        Self {
+            photo_size: Some(photo_size),
+            photo_width: Some(width),
+            photo_height: Some(height),
+            ..self
+        }
+    }
+
+    pub fn need_name(self, need_name: bool) -> Self {
+        Self {
+            need_name: Some(need_name),
+            ..self
+        }
+    }
+
+    pub fn need_phone_number(self, need_phone_number: bool) -> Self {
+        Self {
+            need_phone_number: Some(need_phone_number),
+            ..self
+        }
+    }
+
+    pub fn need_email(self, need_email: bool) -> Self {
+        Self {
+            need_email: Some(need_email),
+            ..self
+        }
+    }
+
+    pub fn need_shipping_address(self, need_shipping_address: bool) -> Self {
+        Self {
+            need_shipping_address: Some(need_shipping_address),
+            ..self
+        }
+    }
+
+    pub fn send_phone_number_to_provider(self, send: bool) -> Self {
+        Self {
+            send_phone_number_to_provider: Some(send),
+            ..self
+        }
+    }
+
+    pub fn send_email_to_provider(self, send: bool) -> Self {
+        Self {
+            send_email_to_provider: Some(send),
+            ..self
+        }
+    }
+
+    pub fn flexible(self, is_flexible: bool) -> Self {
+        Self {
+            is_flexible: Some(is_flexible),
+            ..self
+        }
+    }
+}
+
+/// Use this method to send invoices.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SendInvoice<'a> {
+    pub chat_id: ChatTarget<'a>,
+    #[serde(flatten)]
+    pub invoice: InvoiceParams,
+    /// Deep-linking parameter. If absent, forwarded copies of the message have an active Pay
+    /// button; if given, they have a URL button to the bot with it as the start parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_parameter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageId>,
+    /// If not empty, the first button must be a Pay button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl_method!(
+    SendInvoice<'_>,
+    "sendInvoice",
+    super::types::Message,
+    "3.0",
+    chat_id
+);
+
+impl<'a> SendInvoice<'a> {
+    pub fn new(chat_id: ChatTarget<'a>, invoice: InvoiceParams) -> SendInvoice<'a> {
+        SendInvoice {
+            chat_id,
+            invoice,
+            start_parameter: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn start_parameter<S: Into<String>>(self, start_parameter: S) -> SendInvoice<'a> {
+        SendInvoice {
+            start_parameter: Some(start_parameter.into()),
+            ..self
+        }
+    }
+
+    pub fn disable_notification(self, disable_notification: bool) -> SendInvoice<'a> {
+        SendInvoice {
+            disable_notification: Some(disable_notification),
+            ..self
+        }
+    }
+
+    pub fn reply(self, reply_to_message_id: MessageId) -> SendInvoice<'a> {
+        SendInvoice {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..self
+        }
+    }
+
+    pub fn reply_markup(self, markup: InlineKeyboardMarkup) -> Self {
+        Self {
+            reply_markup: Some(markup),
+            ..self
+        }
+    }
+}
+
+/// Use this method to create a link for an invoice, which can be shared anywhere.
+///
+/// Returns the link on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CreateInvoiceLink {
+    #[serde(flatten)]
+    pub invoice: InvoiceParams,
+}
+
+impl_method!(CreateInvoiceLink, "createInvoiceLink", String, "6.1");
+
+impl CreateInvoiceLink {
+    pub fn new(invoice: InvoiceParams) -> CreateInvoiceLink {
+        CreateInvoiceLink { invoice }
+    }
+}
+
+impl From<InvoiceParams> for CreateInvoiceLink {
+    fn from(invoice: InvoiceParams) -> CreateInvoiceLink {
+        CreateInvoiceLink::new(invoice)
+    }
+}
+
+/// Refund a successful payment in Telegram Stars.
+///
+/// On success, True is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RefundStarPayment {
+    /// The user whose payment will be refunded
+    pub user_id: UserId,
+    /// [`SuccessfulPayment::telegram_payment_charge_id`] of the payment
+    pub telegram_payment_charge_id: String,
+}
+
+impl_method!(RefundStarPayment, "refundStarPayment", bool, "7.4");
+
+impl RefundStarPayment {
+    pub fn new(user_id: UserId, telegram_payment_charge_id: String) -> RefundStarPayment {
+        RefundStarPayment {
+            user_id,
+            telegram_payment_charge_id,
+        }
+    }
+}
+
+/// Get the bot's Telegram Stars transactions, newest first.
+///
+/// Returns [`StarTransactions`] on success.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GetStarTransactions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl_method!(
+    GetStarTransactions,
+    "getStarTransactions",
+    StarTransactions,
+    "7.5"
+);
+
+impl GetStarTransactions {
+    pub fn new() -> GetStarTransactions {
+        GetStarTransactions::default()
+    }
+
+    /// Skip the `offset` newest transactions.
+    pub fn offset(self, offset: u32) -> GetStarTransactions {
+        GetStarTransactions {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Return at most `limit` transactions, 1-100.
+    pub fn limit(self, limit: u32) -> GetStarTransactions {
+        GetStarTransactions {
+            limit: Some(limit),
+            ..self
+        }
+    }
+}
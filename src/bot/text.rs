@@ -0,0 +1,175 @@
+//! Helpers for working with message text.
+//!
+//! Telegram measures text and [entity](MessageEntity) positions in UTF-16 code units,
+//! while Rust strings are indexed by UTF-8 bytes. The functions here do the conversion
+//! so callers don't have to.
+use super::types::{MessageEntity, User, Utf16Len, Utf16Offset};
+use crate::prelude::*;
+use core::ops::Range;
+
+/// Maximum length of a text message, in UTF-16 code units.
+pub const MAX_MESSAGE_LENGTH: usize = 4096;
+
+/// Maximum length of a media caption, in UTF-16 code units.
+pub const MAX_CAPTION_LENGTH: usize = 1024;
+
+/// Length of `text` in UTF-16 code units.
+pub fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+/// The byte range of `text` between `offset` and `offset + length` UTF-16 code units.
+///
+/// Returns `None` if the range is out of bounds or cuts a character in half.
+pub fn utf16_range(text: &str, offset: Utf16Offset, length: Utf16Len) -> Option<Range<usize>> {
+    let offset = offset.0 as usize;
+    let end = offset.checked_add(length.0 as usize)?;
+    let mut start_byte = None;
+    let mut units = 0;
+    for (byte, c) in text.char_indices() {
+        if units == offset {
+            start_byte = Some(byte);
+        }
+        if units == end {
+            return start_byte.map(|start| start..byte);
+        }
+        if units > end {
+            return None;
+        }
+        units += c.len_utf16();
+    }
+    if units == offset {
+        start_byte = Some(text.len());
+    }
+    if units == end {
+        start_byte.map(|start| start..text.len())
+    } else {
+        None
+    }
+}
+
+/// The part of `text` between `offset` and `offset + length` UTF-16 code units.
+///
+/// Returns `None` if the range is out of bounds or cuts a character in half.
+pub fn utf16_slice(text: &str, offset: Utf16Offset, length: Utf16Len) -> Option<&str> {
+    utf16_range(text, offset, length).map(|range| &text[range])
+}
+
+/// The part of `text` covered by `entity`.
+pub fn entity_text<'a>(text: &'a str, entity: &MessageEntity) -> Option<&'a str> {
+    entity.byte_range(text).map(|range| &text[range])
+}
+
+/// A bot command like `/start` or `/start@my_bot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Command<'a> {
+    /// Command name without the leading slash.
+    pub name: &'a str,
+    /// Username of the bot the command is addressed to, if any.
+    pub username: Option<&'a str>,
+}
+
+impl<'a> Command<'a> {
+    /// Parse the text of a [`BotCommand`](super::types::MessageEntityKind::BotCommand) entity.
+    pub fn parse(text: &'a str) -> Option<Command<'a>> {
+        let command = text.strip_prefix('/')?;
+        let (name, username) = match command.find('@') {
+            Some(at) => (&command[..at], Some(&command[at + 1..])),
+            None => (command, None),
+        };
+        if name.is_empty() {
+            return None;
+        }
+        Some(Command { name, username })
+    }
+}
+
+/// A mentioned user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mention<'a> {
+    /// `@username`, without the `@`.
+    Username(&'a str),
+    /// A user without a username, mentioned by a text mention.
+    User(&'a User),
+}
+
+/// One piece of a text split by [`split_text`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextChunk {
+    /// Text of the piece, at most `max_len` UTF-16 code units.
+    pub text: String,
+    /// Entities overlapping this piece, with offsets relative to the start of the piece.
+    pub entities: Vec<MessageEntity>,
+}
+
+/// Split `text` into pieces of at most `max_len` UTF-16 code units.
+///
+/// Pieces are cut at the last line break that fits, then at the last whitespace, and only
+/// in the middle of a word if there is no other choice. The separator a piece was cut at
+/// is dropped. Entities spanning a cut are clipped to each piece they overlap.
+///
+/// Use [`MAX_MESSAGE_LENGTH`] for messages and [`MAX_CAPTION_LENGTH`] for captions.
+///
+/// # Panics
+/// If `max_len` is zero.
+pub fn split_text(text: &str, entities: &[MessageEntity], max_len: usize) -> Vec<TextChunk> {
+    assert!(max_len > 0, "max_len must be positive");
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    // UTF-16 offset of every char boundary, including the end of the text.
+    let mut offsets = Vec::with_capacity(chars.len() + 1);
+    let mut acc = 0;
+    offsets.push(acc);
+    for &(_, c) in &chars {
+        acc += c.len_utf16();
+        offsets.push(acc);
+    }
+    let byte_at = |i: usize| chars.get(i).map_or(text.len(), |&(b, _)| b);
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let mut end = start;
+        while end < chars.len() && offsets[end + 1] - offsets[start] <= max_len {
+            end += 1;
+        }
+        let (cut, next) = if end == chars.len() {
+            (end, end)
+        } else {
+            let last_of = |f: fn(char) -> bool| (start + 1..=end).rev().find(|&i| f(chars[i].1));
+            match last_of(|c| c == '\n').or_else(|| last_of(char::is_whitespace)) {
+                Some(i) => (i, i + 1),
+                // A single character wider than `max_len` still has to go somewhere.
+                None => (end.max(start + 1), end.max(start + 1)),
+            }
+        };
+        if cut > start {
+            chunks.push(TextChunk {
+                text: text[byte_at(start)..byte_at(cut)].to_string(),
+                entities: clip_entities(entities, offsets[start], offsets[cut]),
+            });
+        }
+        start = next;
+    }
+    chunks
+}
+
+/// Entities overlapping `[start, end)`, clipped and rebased to `start`.
+fn clip_entities(entities: &[MessageEntity], start: usize, end: usize) -> Vec<MessageEntity> {
+    entities
+        .iter()
+        .filter_map(|entity| {
+            let entity_start = entity.offset.0 as usize;
+            let entity_end = entity.end().0 as usize;
+            let clipped_start = entity_start.max(start);
+            let clipped_end = entity_end.min(end);
+            if clipped_start >= clipped_end {
+                return None;
+            }
+            Some(MessageEntity {
+                offset: Utf16Offset((clipped_start - start) as u32),
+                length: Utf16Len((clipped_end - clipped_start) as u32),
+                ..entity.clone()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,668 @@
+//! Users, chats and their members.
+#[cfg(feature = "extra-fields")]
+use super::ExtraFields;
+use super::{ChatId, FileId, Message, Time, UserId};
+use crate::bot::utils::{falsum, is_false};
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use core::convert::TryFrom;
+use serde::{Deserialize, Serialize};
+
+/// This object represents changes in the status of a chat member.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMemberUpdated {
+    pub chat: Chat,
+    pub from: User,
+    pub date: Time,
+    /// Previous information about the chat member
+    pub old_chat_member: ChatMember,
+    /// New information about the chat member
+    pub new_chat_member: ChatMember,
+    /// Chat invite link, which was used by the user to join the chat;
+    /// for joining by invite link events only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<ChatInviteLink>,
+    /// True, if the user joined the chat after sending a direct join request
+    /// without using an invite link and being approved by an administrator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via_join_request: Option<bool>,
+    /// True, if the user joined the chat via a chat folder invite link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via_chat_folder_invite_link: Option<bool>,
+}
+
+impl ChatMemberUpdated {
+    /// Whether the member wasn't in the chat and now is, such as the bot being added to a group
+    /// in a `my_chat_member` update.
+    pub fn joined(&self) -> bool {
+        !self.old_chat_member.is_present() && self.new_chat_member.is_present()
+    }
+
+    /// Whether the member was in the chat and no longer is. In a `my_chat_member` update for a
+    /// private chat, the user blocked the bot.
+    pub fn left(&self) -> bool {
+        self.old_chat_member.is_present() && !self.new_chat_member.is_present()
+    }
+}
+
+/// A request to join a chat, which the bot may approve or decline if it is an administrator
+/// with the `can_invite_users` right.
+///
+/// [`ApproveJoinRequest`](crate::bot::methods::ApproveJoinRequest) and
+/// [`DeclineJoinRequest`](crate::bot::methods::DeclineJoinRequest) convert from a reference to
+/// one.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatJoinRequest {
+    pub chat: Chat,
+    pub from: User,
+    /// Identifier of a private chat with the user, where the bot may message them for 5 minutes
+    /// until the request is processed
+    pub user_chat_id: UserId,
+    pub date: Time,
+    /// Bio of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    /// Chat invite link that was used by the user to send the join request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<ChatInviteLink>,
+}
+
+/// An invite link to a chat.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatInviteLink {
+    /// The invite link.
+    ///
+    /// If the link was created by another chat administrator, then the second part of the link
+    /// will be replaced with “…”.
+    pub invite_link: String,
+    /// Creator of the link
+    pub creator: User,
+    /// True, if users joining the chat via the link need to be approved by chat administrators
+    pub creates_join_request: bool,
+    /// True, if the link is the primary one of the chat
+    pub is_primary: bool,
+    pub is_revoked: bool,
+    /// Invite link name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Point in time when the link will expire or has been expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<Time>,
+    /// The maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+    /// Number of pending join requests created using this link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_join_request_count: Option<i64>,
+    /// Number of seconds the subscription will be active for before the next payment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<i64>,
+    /// Number of Telegram Stars a user pays for each subscription period to be a member
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_price: Option<i64>,
+}
+
+/// A Telegram user or bot.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct User {
+    /// Unique identifier for this user or bot
+    pub id: UserId,
+    /// True, if this user is a bot
+    pub is_bot: bool,
+    /// User‘s or bot’s first name
+    pub first_name: String,
+    /// User‘s or bot’s last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// User‘s or bot’s username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// [IETF language tag](https://en.wikipedia.org/wiki/IETF_language_tag) of the user's language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub is_premium: bool,
+    /// Optional. True, if this user added the bot to the attachment menu
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub added_to_attachment_menu: bool,
+    /// Optional. True, if the bot can be invited to groups. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_join_groups: Option<bool>,
+    /// Optional. True, if privacy mode is disabled for the bot. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_read_all_group_messages: Option<bool>,
+    /// Optional. True, if the bot supports inline queries. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_inline_queries: Option<bool>,
+    /// Optional. True, if the bot can be connected to a Telegram Business account to receive its messages.
+    /// Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_connect_to_business: Option<bool>,
+    /// Optional. True, if the bot has a main Web App. Returned only in getMe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_main_web_app: Option<bool>,
+    /// Fields this crate doesn't know about yet.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: ExtraFields,
+}
+
+/// Type of chat
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum ChatType {
+    Private {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        /// First name of the other party in a private chat
+        first_name: String,
+        /// Last name of the other party in a private chat
+        #[serde(skip_serializing_if = "Option::is_none")]
+        last_name: Option<String>,
+    },
+    Group {
+        title: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        /// True if a group has ‘All Members Are Admins’ enabled.
+        #[serde(default = "falsum")]
+        #[serde(skip_serializing_if = "is_false")]
+        all_members_are_administrators: bool,
+    },
+    Supergroup {
+        title: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        /// True if a group has ‘All Members Are Admins’ enabled.
+        #[serde(default = "falsum")]
+        #[serde(skip_serializing_if = "is_false")]
+        all_members_are_administrators: bool,
+        /// Pinned message. Returned only in `getChat`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pinned_message: Option<Box<Message>>,
+        /// Name of group sticker set. Returned only in `getChat.`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sticker_set_name: Option<String>,
+        /// True, if the bot can change the group sticker set. Returned only in `getChat`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        can_set_sticker_set: Option<bool>,
+        /// Chat invite link/ Returned only in `getChat`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        invite_link: Option<String>,
+        /// Description. Returned only in `getChat`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
+    Channel {
+        title: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        /// Pinned message. Returned only in `getChat`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pinned_message: Option<Box<Message>>,
+        /// Chat invite link. Returned only in `getChat`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        invite_link: Option<String>,
+        /// Description. Returned only in `getChat`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[serde(try_from = "ChatRepr")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Chat {
+    /// Unique identifier for this chat.
+    pub id: ChatId,
+    /// Chat photo. Returned only in `getChat`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<ChatPhoto>,
+    /// Type of chat, serialized inline with its `type` tag
+    #[serde(flatten)]
+    pub kind: ChatType,
+    /// Fields this crate doesn't know about yet.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: ExtraFields,
+}
+
+/// The flat wire shape of [`Chat`].
+///
+/// Deserializing `Chat` through `#[serde(flatten)]` and the internally tagged [`ChatType`]
+/// buffered every chat into a map twice, this reads the fields directly.
+#[derive(Deserialize)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Chat")
+)]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+struct ChatRepr {
+    id: ChatId,
+    photo: Option<ChatPhoto>,
+    #[serde(rename = "type")]
+    kind: ChatTypeTag,
+    username: Option<String>,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    title: Option<String>,
+    #[serde(default = "falsum")]
+    all_members_are_administrators: bool,
+    pinned_message: Option<Box<Message>>,
+    sticker_set_name: Option<String>,
+    can_set_sticker_set: Option<bool>,
+    invite_link: Option<String>,
+    description: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    extra: ExtraFields,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+enum ChatTypeTag {
+    Private,
+    Group,
+    Supergroup,
+    Channel,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TryFrom<ChatRepr> for Chat {
+    type Error = &'static str;
+
+    fn try_from(repr: ChatRepr) -> Result<Chat, &'static str> {
+        let title = repr.title.ok_or("missing field `title`");
+        let kind = match repr.kind {
+            ChatTypeTag::Private => ChatType::Private {
+                username: repr.username,
+                first_name: repr.first_name.ok_or("missing field `first_name`")?,
+                last_name: repr.last_name,
+            },
+            ChatTypeTag::Group => ChatType::Group {
+                title: title?,
+                username: repr.username,
+                all_members_are_administrators: repr.all_members_are_administrators,
+            },
+            ChatTypeTag::Supergroup => ChatType::Supergroup {
+                title: title?,
+                username: repr.username,
+                all_members_are_administrators: repr.all_members_are_administrators,
+                pinned_message: repr.pinned_message,
+                sticker_set_name: repr.sticker_set_name,
+                can_set_sticker_set: repr.can_set_sticker_set,
+                invite_link: repr.invite_link,
+                description: repr.description,
+            },
+            ChatTypeTag::Channel => ChatType::Channel {
+                title: title?,
+                username: repr.username,
+                pinned_message: repr.pinned_message,
+                invite_link: repr.invite_link,
+                description: repr.description,
+            },
+            ChatTypeTag::Unknown => ChatType::Unknown,
+        };
+        Ok(Chat {
+            id: repr.id,
+            photo: repr.photo,
+            kind,
+            #[cfg(feature = "extra-fields")]
+            extra: repr.extra,
+        })
+    }
+}
+
+impl Chat {
+    /// Title of a group or channel, or the full name of the other party in a private chat.
+    ///
+    /// `None` only for chats of unknown type.
+    pub fn title_or_name(&self) -> Option<Cow<'_, str>> {
+        match self.kind {
+            ChatType::Private {
+                ref first_name,
+                last_name: Some(ref last_name),
+                ..
+            } => Some(Cow::Owned(format!("{} {}", first_name, last_name))),
+            ChatType::Private { ref first_name, .. } => Some(Cow::Borrowed(first_name)),
+            ChatType::Group { ref title, .. }
+            | ChatType::Supergroup { ref title, .. }
+            | ChatType::Channel { ref title, .. } => Some(Cow::Borrowed(title)),
+            ChatType::Unknown => None,
+        }
+    }
+
+    /// Username of the chat, if it has one.
+    pub fn username(&self) -> Option<&str> {
+        match self.kind {
+            ChatType::Private { ref username, .. }
+            | ChatType::Group { ref username, .. }
+            | ChatType::Supergroup { ref username, .. }
+            | ChatType::Channel { ref username, .. } => username.as_deref(),
+            ChatType::Unknown => None,
+        }
+    }
+
+    /// True, if this is a private chat.
+    pub fn is_private(&self) -> bool {
+        matches!(self.kind, ChatType::Private { .. })
+    }
+
+    /// True, if this is a group or a supergroup.
+    pub fn is_group(&self) -> bool {
+        matches!(
+            self.kind,
+            ChatType::Group { .. } | ChatType::Supergroup { .. }
+        )
+    }
+
+    /// True, if this is a channel.
+    pub fn is_channel(&self) -> bool {
+        matches!(self.kind, ChatType::Channel { .. })
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatPhoto {
+    /// Unique file identifier of small (160x160) chat photo.
+    /// This file_id can be used only for photo download.
+    pub small_file_id: FileId,
+    /// Unique file identifier of big (640x640) chat photo.
+    /// This file_id can be used only for photo download.
+    pub big_file_id: FileId,
+}
+
+/// This object contains information about one member of a chat.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatMember {
+    /// Information about the user
+    pub user: Box<User>,
+    /// The member's status in the chat.
+    pub status: ChatMemberStatus,
+    /// Restricted and kicked only. Date when restrictions will be lifted for this user, unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<Time>,
+    /// Administrators only. True, if the bot is allowed to edit administrator privileges of
+    /// that user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_be_edited: Option<bool>,
+    /// Administrators only. True, if the administrator can change the chat title, photo and
+    /// other settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+    /// Administrators only. True, if the administrator can post in the channel, channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    /// Administrators only. True, if the administrator can edit messages of other users and can
+    /// pin messages, channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    /// Administrators only. True, if the administrator can delete messages of other users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_messages: Option<bool>,
+    /// Administrators only. True, if the administrator can invite new users to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+    /// Administrators only. True, if the administrator can restrict, ban or unban chat members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_restrict_members: Option<bool>,
+    /// Administrators only. True, if the administrator can pin messages, supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// Administrators only. True, if the administrator can add new administrators with a subset
+    /// of his own privileges or demote administrators that he has promoted, directly or
+    /// indirectly (promoted by administrators that were appointed by the user)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_promote_members: Option<bool>,
+    /// Restricted only. True, if the user is a member of the chat at the moment of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_member: Option<bool>,
+    /// Restricted only. True, if the user can send text messages, contacts, locations and venues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_messages: Option<bool>,
+    /// Restricted only. True, if the user can send audios, documents, photos, videos, video notes
+    /// and voice notes, implies can_send_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_media_messages: Option<bool>,
+    /// Restricted only. True, if the user can send animations, games, stickers and use inline
+    /// bots, implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_other_messages: Option<bool>,
+    /// Restricted only. True, if user may add web page previews to his messages, implies
+    /// can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_add_web_page_previews: Option<bool>,
+}
+
+impl ChatMember {
+    /// Whether the user is in the chat, restricted or not.
+    pub fn is_present(&self) -> bool {
+        match self.status {
+            ChatMemberStatus::Creator
+            | ChatMemberStatus::Administrator
+            | ChatMemberStatus::Member => true,
+            ChatMemberStatus::Restricted => self.is_member.unwrap_or(false),
+            ChatMemberStatus::Left | ChatMemberStatus::Kicked | ChatMemberStatus::Unknown => false,
+        }
+    }
+}
+
+/// The member's status in the chat.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "lowercase")]
+pub enum ChatMemberStatus {
+    Creator,
+    Administrator,
+    Member,
+    Restricted,
+    Left,
+    Kicked,
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+/// What non-administrator members of a chat may do, by default or for a restricted member.
+///
+/// Left out permissions are not granted: the default value allows nothing and mutes members.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatPermissions {
+    /// Text messages, contacts, giveaways, invoices, locations and venues
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_messages: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_audios: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_documents: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_photos: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_videos: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_video_notes: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_voice_notes: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_polls: bool,
+    /// Animations, games, stickers and inline bots
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_send_other_messages: bool,
+    /// Web page previews on links in messages
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_add_web_page_previews: bool,
+    /// Changing the chat title, photo and other settings. Ignored in public supergroups
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_change_info: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_invite_users: bool,
+    /// Ignored in public supergroups
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_pin_messages: bool,
+    /// Creating forum topics. Telegram defaults it to `can_pin_messages` if left out, so it is
+    /// always sent
+    #[serde(default = "falsum")]
+    pub can_manage_topics: bool,
+}
+
+impl ChatPermissions {
+    /// Every permission, such as to lift the restrictions of a member.
+    pub fn all() -> ChatPermissions {
+        ChatPermissions {
+            can_send_messages: true,
+            can_send_audios: true,
+            can_send_documents: true,
+            can_send_photos: true,
+            can_send_videos: true,
+            can_send_video_notes: true,
+            can_send_voice_notes: true,
+            can_send_polls: true,
+            can_send_other_messages: true,
+            can_add_web_page_previews: true,
+            can_change_info: true,
+            can_invite_users: true,
+            can_pin_messages: true,
+            can_manage_topics: true,
+        }
+    }
+
+    /// Whether members may send anything at all.
+    pub fn can_send_anything(&self) -> bool {
+        self.can_send_messages
+            || self.can_send_audios
+            || self.can_send_documents
+            || self.can_send_photos
+            || self.can_send_videos
+            || self.can_send_video_notes
+            || self.can_send_voice_notes
+            || self.can_send_polls
+            || self.can_send_other_messages
+    }
+}
+
+/// The rights of an administrator in a chat.
+///
+/// Left out rights are not granted, the default value has none.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChatAdministratorRights {
+    /// Whether the administrator's presence in the chat is hidden
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub is_anonymous: bool,
+    /// Access to the event log, boost list, statistics, members and hidden administrators, implied by any other right
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_manage_chat: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_delete_messages: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_manage_video_chats: bool,
+    /// Restricting, banning and unbanning members, and seeing supergroup statistics
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_restrict_members: bool,
+    /// Adding administrators with a subset of the own rights, and demoting those they promoted
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_promote_members: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_change_info: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_invite_users: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_post_stories: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_edit_stories: bool,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_delete_stories: bool,
+    /// Channels only
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_post_messages: bool,
+    /// Channels only, also pinning messages
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_edit_messages: bool,
+    /// Groups and supergroups only
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_pin_messages: bool,
+    /// Supergroups only
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub can_manage_topics: bool,
+}
@@ -0,0 +1,530 @@
+//! Reply and inline keyboards, and callback queries from them.
+use super::{Message, User};
+#[cfg(feature = "games")]
+use crate::bot::games::CallbackGame;
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use core::fmt;
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// A [custom keyboard](https://core.telegram.org/bots#keyboards)
+/// with reply options (see [Introduction to bots](https://core.telegram.org/bots#keyboards)
+/// for details and examples).
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ReplyKeyboardMarkup {
+    /// Array of button rows, each represented by an Array of [`KeyboardButton`](KeyboardButton) objects
+    #[serde(default)]
+    pub keyboard: Vec<Vec<KeyboardButton>>,
+    /// Requests clients to resize the keyboard vertically for optimal fit
+    /// (e.g., make the keyboard smaller if there are just two rows of buttons).
+    /// Defaults to false, in which case the custom keyboard is always of the
+    /// same height as the app's standard keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resize_keyboard: Option<bool>,
+    /// Requests clients to hide the keyboard as soon as it's been used.
+    /// The keyboard will still be available, but clients will automatically display the usual
+    /// letter-keyboard in the chat – the user can press a special button in the input field
+    /// to see the custom keyboard again. Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_keyboard: Option<bool>,
+    /// Use this parameter if you want to show the keyboard to specific users only. Targets: 1)
+    /// users that are @mentioned in the text of the [`Message`] object; 2)
+    /// if the bot's message is a reply (has reply_to_message_id),
+    /// sender of the original message.
+    ///
+    /// Example: A user requests to change the bot‘s language,
+    /// bot replies to the request with a keyboard to select the new language.
+    /// Other users in the group don’t see the keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selective: Option<bool>,
+}
+
+impl ReplyKeyboardMarkup {
+    /// Lay `buttons` out in rows of `per_row` buttons.
+    ///
+    /// Fails if `per_row` or the number of buttons exceeds Telegram's
+    /// [limits](KeyboardLayoutError).
+    pub fn from_buttons<I>(
+        buttons: I,
+        per_row: usize,
+    ) -> Result<ReplyKeyboardMarkup, KeyboardLayoutError>
+    where
+        I: IntoIterator<Item = KeyboardButton>,
+    {
+        Ok(ReplyKeyboardMarkup {
+            keyboard: layout_buttons(buttons, per_row)?,
+            resize_keyboard: None,
+            one_time_keyboard: None,
+            selective: None,
+        })
+    }
+}
+
+/// One button of the reply keyboard.
+/// For simple text buttons *String* can be used instead of this object to specify
+/// text of the button. Optional fields are mutually exclusive.
+///
+/// ## Note
+/// Note: request_contact and request_location options will only work in
+/// Telegram versions released after 9 April, 2016. Older clients will ignore them.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct KeyboardButton {
+    /// Text of the button. If none of the optional fields are used,
+    /// it will be sent as a message when the button is pressed
+    pub text: String,
+    /// If True, the user's phone number will be sent as a contact when the button is pressed.
+    /// Available in private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_contact: Option<bool>,
+    /// If True, the user's current location will be sent when the button is pressed.
+    /// Available in private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_location: Option<bool>,
+}
+
+/// Upon receiving a message with this object, Telegram clients will remove the current
+/// custom keyboard and display the default letter-keyboard.
+///
+/// By default, custom keyboards are displayed until a new keyboard is sent by a bot.
+/// An exception is made for one-time keyboards that are hidden immediately after the user
+/// presses a button (see [`ReplyKeyboardMarkup`]).
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ReplyKeyboardRemove {
+    /// Requests clients to remove the custom keyboard (user will not be able to summon this
+    /// keyboard; if you want to hide the keyboard from sight but keep it accessible,
+    /// use *one_time_keyboard* in [`ReplyKeyboardMarkup`])
+    pub remove_keyboard: bool,
+    /// *Optional*. Use this parameter if you want to remove the keyboard for specific users only.
+    /// Targets:
+    ///
+    /// 1. users that are @mentioned in the text of the Message object;
+    /// 2. if the bot's message is a reply (has reply_to_message_id),
+    ///    sender of the original message.
+    ///
+    /// *Example*: A user votes in a poll, bot returns confirmation message in reply to the
+    /// vote and removes the keyboard for that user, while still showing the keyboard with poll
+    /// options to users who haven't voted yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selective: Option<bool>,
+}
+
+/// An inline keyboard that appears right next to the message it belongs to.
+///
+/// ## Note
+/// This will only work in Telegram versions released after 9 April, 2016.
+/// Older clients will display unsupported message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct InlineKeyboardMarkup {
+    /// Array of button rows, each represented by an Array of [`InlineKeyboardButton`] objects
+    #[serde(default)]
+    pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
+}
+
+impl InlineKeyboardMarkup {
+    /// Lay `buttons` out in rows of `per_row` buttons.
+    ///
+    /// Fails if `per_row` or the number of buttons exceeds Telegram's
+    /// [limits](KeyboardLayoutError).
+    pub fn from_buttons<I>(
+        buttons: I,
+        per_row: usize,
+    ) -> Result<InlineKeyboardMarkup, KeyboardLayoutError>
+    where
+        I: IntoIterator<Item = InlineKeyboardButton>,
+    {
+        Ok(InlineKeyboardMarkup {
+            inline_keyboard: layout_buttons(buttons, per_row)?,
+        })
+    }
+}
+
+/// Maximum number of buttons in one keyboard row.
+pub const MAX_BUTTONS_PER_ROW: usize = 8;
+
+/// Maximum number of buttons in one keyboard.
+pub const MAX_BUTTONS: usize = 100;
+
+/// Why a list of buttons can't be laid out as a keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyboardLayoutError {
+    /// Rows must hold between 1 and [`MAX_BUTTONS_PER_ROW`] buttons.
+    InvalidRowSize(usize),
+    /// A keyboard can't hold more than [`MAX_BUTTONS`] buttons.
+    TooManyButtons(usize),
+}
+
+impl fmt::Display for KeyboardLayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            KeyboardLayoutError::InvalidRowSize(n) => write!(
+                f,
+                "a keyboard row must hold 1-{} buttons, got {}",
+                MAX_BUTTONS_PER_ROW, n
+            ),
+            KeyboardLayoutError::TooManyButtons(n) => write!(
+                f,
+                "a keyboard can hold at most {} buttons, got {}",
+                MAX_BUTTONS, n
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KeyboardLayoutError {}
+
+fn layout_buttons<T, I>(buttons: I, per_row: usize) -> Result<Vec<Vec<T>>, KeyboardLayoutError>
+where
+    I: IntoIterator<Item = T>,
+{
+    if per_row == 0 || per_row > MAX_BUTTONS_PER_ROW {
+        return Err(KeyboardLayoutError::InvalidRowSize(per_row));
+    }
+    let buttons: Vec<T> = buttons.into_iter().collect();
+    if buttons.len() > MAX_BUTTONS {
+        return Err(KeyboardLayoutError::TooManyButtons(buttons.len()));
+    }
+    let mut rows = Vec::with_capacity(buttons.len().div_ceil(per_row));
+    let mut buttons = buttons.into_iter().peekable();
+    while buttons.peek().is_some() {
+        rows.push(buttons.by_ref().take(per_row).collect());
+    }
+    Ok(rows)
+}
+
+/// One button of an inline keyboard.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct InlineKeyboardButton {
+    /// Label text on the button
+    pub text: String,
+    #[serde(flatten)]
+    pub pressed: InlineKeyboardButtonPressed,
+}
+
+/// What happens when an [`InlineKeyboardButton`] is pressed.
+///
+/// On the wire this is a single optional field of the button, e.g. `"pay": true` or
+/// `"callback_game": {}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum InlineKeyboardButtonPressed {
+    /// HTTP url to be opened when button is pressed
+    Url(String),
+    /// Data to be sent in a [callback query](CallbackQuery) to the bot when button is pressed,
+    /// 1-64 bytes
+    CallbackData(String),
+    /// If set, pressing the button will prompt the user to select one of their chats, open that
+    /// chat and insert the bot‘s username and the specified inline query in the input field.
+    /// Can be empty, in which case just the bot’s username will be inserted.
+    ///
+    /// ## Note
+    /// This offers an easy way for users to start using your bot in
+    /// [inline mode](https://core.telegram.org/bots/inline) when they are currently
+    /// in a private chat with it. Especially useful when combined with
+    /// *[switch_pm…](https://core.telegram.org/bots/api#answerinlinequery)* actions – in this
+    /// case the user will be automatically returned to the chat they switched from, skipping
+    /// the chat selection screen.
+    SwitchInlineQuery(String),
+    /// If set, pressing the button will insert the bot‘s username and the specified inline
+    /// query in the current chat's input field. Can be empty, in which case only
+    /// the bot’s username will be inserted.
+    ///
+    /// This offers a quick way for the user to open your bot in inline mode in the same chat –
+    /// good for selecting something from multiple options.
+    SwitchInlineQueryCurrentChat(String),
+    /// Description of the game that will be launched when the user presses the button.
+    ///
+    /// # NOTE
+    /// This type of button **must** always be the first button in the first row.
+    Pay(bool),
+    /// Description of the game that will be launched when the user presses the button.
+    ///
+    /// ## NOTE
+    /// This type of button **must** always be the first button in the first row.
+    #[cfg(feature = "games")]
+    CallbackGame(CallbackGame),
+    /// An HTTP URL used to automatically authorize the user.
+    LoginUrl(LoginUrl),
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+/// `{}`, the wire form of [`CallbackGame`].
+#[cfg(feature = "games")]
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct EmptyObject {}
+
+/// The wire shape of [`InlineKeyboardButtonPressed`], whose serde impls are written by hand.
+#[cfg(feature = "schemars")]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "InlineKeyboardButtonPressed")]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+enum InlineKeyboardButtonPressedSchema {
+    Url(String),
+    CallbackData(String),
+    SwitchInlineQuery(String),
+    SwitchInlineQueryCurrentChat(String),
+    Pay(bool),
+    #[cfg(feature = "games")]
+    CallbackGame(EmptyObject),
+    LoginUrl(LoginUrl),
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for InlineKeyboardButtonPressed {
+    fn schema_name() -> Cow<'static, str> {
+        InlineKeyboardButtonPressedSchema::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        InlineKeyboardButtonPressedSchema::json_schema(generator)
+    }
+}
+
+impl Serialize for InlineKeyboardButtonPressed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use self::InlineKeyboardButtonPressed::*;
+        let mut map = serializer.serialize_map(None)?;
+        match *self {
+            Url(ref x) => map.serialize_entry("url", x)?,
+            CallbackData(ref x) => map.serialize_entry("callback_data", x)?,
+            SwitchInlineQuery(ref x) => map.serialize_entry("switch_inline_query", x)?,
+            SwitchInlineQueryCurrentChat(ref x) => {
+                map.serialize_entry("switch_inline_query_current_chat", x)?
+            }
+            Pay(x) => map.serialize_entry("pay", &x)?,
+            #[cfg(feature = "games")]
+            CallbackGame(_) => map.serialize_entry("callback_game", &EmptyObject {})?,
+            LoginUrl(ref x) => map.serialize_entry("login_url", x)?,
+            Unknown => (),
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for InlineKeyboardButtonPressed {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<InlineKeyboardButtonPressed, D::Error> {
+        struct PressedVisitor;
+
+        impl<'de> Visitor<'de> for PressedVisitor {
+            type Value = InlineKeyboardButtonPressed;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an inline keyboard button")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<InlineKeyboardButtonPressed, A::Error> {
+                use self::InlineKeyboardButtonPressed::*;
+                let mut pressed = None;
+                // The first known field decides, a valid button has exactly one of them.
+                while let Some(key) = map.next_key::<Cow<str>>()? {
+                    pressed = match &*key {
+                        _ if pressed.is_some() => {
+                            map.next_value::<IgnoredAny>()?;
+                            continue;
+                        }
+                        "url" => Some(Url(map.next_value()?)),
+                        "callback_data" => Some(CallbackData(map.next_value()?)),
+                        "switch_inline_query" => Some(SwitchInlineQuery(map.next_value()?)),
+                        "switch_inline_query_current_chat" => {
+                            Some(SwitchInlineQueryCurrentChat(map.next_value()?))
+                        }
+                        "pay" => Some(Pay(map.next_value()?)),
+                        #[cfg(feature = "games")]
+                        "callback_game" => {
+                            map.next_value::<EmptyObject>()?;
+                            Some(CallbackGame(crate::bot::games::CallbackGame))
+                        }
+                        "login_url" => Some(LoginUrl(map.next_value()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                            None
+                        }
+                    };
+                }
+                Ok(pressed.unwrap_or(Unknown))
+            }
+        }
+
+        deserializer.deserialize_map(PressedVisitor)
+    }
+}
+
+/// This object represents an incoming callback query from a callback button in an inline keyboard.
+/// If the button that originated the query was attached to a message sent by the bot, the field
+/// message will be present. If the button was attached to a message sent via the bot (in inline
+/// mode), the field inline_message_id will be present. Exactly one of the fields data
+/// or game_short_name will be present.
+///
+/// ## Note
+/// After the user presses a callback button, Telegram clients will display a progress bar until
+/// you call `answerCallbackQuery`. It is, therefore, necessary to react by calling
+/// `answerCallbackQuery` even if no notification to the user is needed (e.g., without
+/// specifying any of the optional parameters).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[serde(from = "CallbackQueryRepr")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CallbackQuery {
+    /// Unique identifier for this query
+    pub id: String,
+    /// Sender
+    pub from: Box<User>,
+    /// Message with the callback button that originated the query. Note that message content and
+    /// message date will not be available if the message is too old
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Box<Message>>,
+    /// Identifier of the message sent via the bot in inline mode, that originated the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    /// Global identifier, uniquely corresponding to the chat to which the message with the
+    /// callback button was sent. Useful for high scores in games.
+    pub chat_instance: String,
+    /// Data associated with the callback button. Be aware that a bad client can send arbitrary data in this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// Short name of a Game to be returned, serves as the unique identifier for the game
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_short_name: Option<String>,
+    /// Whichever of [`data`](CallbackQuery::data) and
+    /// [`game_short_name`](CallbackQuery::game_short_name) is present.
+    #[serde(skip)]
+    pub payload: CallbackPayload,
+}
+
+/// What a [`CallbackQuery`] carries, filled in from its `data` or `game_short_name` field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum CallbackPayload {
+    /// Data associated with the callback button.
+    Data(String),
+    /// Short name of the game the button launches.
+    GameShortName(String),
+    /// Neither field is present.
+    #[default]
+    Unknown,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(rename = "CallbackQuery")
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+struct CallbackQueryRepr {
+    id: String,
+    from: Box<User>,
+    message: Option<Box<Message>>,
+    inline_message_id: Option<String>,
+    chat_instance: String,
+    data: Option<String>,
+    game_short_name: Option<String>,
+}
+
+impl From<CallbackQueryRepr> for CallbackQuery {
+    fn from(repr: CallbackQueryRepr) -> CallbackQuery {
+        let payload = match (&repr.data, &repr.game_short_name) {
+            (Some(data), _) => CallbackPayload::Data(data.clone()),
+            (None, Some(name)) => CallbackPayload::GameShortName(name.clone()),
+            (None, None) => CallbackPayload::Unknown,
+        };
+        CallbackQuery {
+            id: repr.id,
+            from: repr.from,
+            message: repr.message,
+            inline_message_id: repr.inline_message_id,
+            chat_instance: repr.chat_instance,
+            data: repr.data,
+            game_short_name: repr.game_short_name,
+            payload,
+        }
+    }
+}
+
+/// Generated through the wire shape, so that the payload matches the raw fields.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CallbackQuery {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<CallbackQuery> {
+        CallbackQueryRepr::arbitrary(u).map(CallbackQuery::from)
+    }
+}
+
+/// Upon receiving a message with this object, Telegram clients will display a reply interface
+/// to the user (act as if the user has selected the bot‘s message and tapped ’Reply'). This can
+/// be extremely useful if you want to create user-friendly step-by-step interfaces without having
+/// to sacrifice [privacy mode](https://core.telegram.org/bots#privacy-mode).
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ForceReply {
+    /// Shows reply interface to the user, as if they manually selected the bot‘s message and
+    /// tapped ’Reply'
+    pub force_reply: bool,
+    /// *Optional*. Use this parameter if you want to force reply from specific users only.
+    /// Targets:
+    ///
+    /// 1. users that are @mentioned in the text of the [`Message`] object;
+    /// 2. if the bot's message is a reply (has reply_to_message_id), sender of the original message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selective: Option<bool>,
+}
+
+/// a parameter of the inline keyboard button used to automatically authorize a user.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LoginUrl {
+    /// An HTTP URL to be opened with user authorization data added to the query string when
+    /// the button is pressed.
+    ///
+    /// If the user refuses to provide authorization data, the original URL without information
+    /// about the user will be opened. The data added is the same as described in
+    /// [Receiving authorization data](https://core.telegram.org/widgets/login#receiving-authorization-data).
+    ///
+    /// ## Note
+    ///
+    /// You **must** always check the hash of the received data to verify the authentication and
+    /// the integrity of the data as described in [Checking authorization](https://core.telegram.org/widgets/login#checking-authorization).
+    pub url: String,
+    /// New text of the button in forwarded messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_text: Option<String>,
+    /// Username of a bot, which will be used for user authorization.
+    ///
+    /// See Setting up a bot for more details.
+    /// If not specified, the current bot's username will be assumed.
+    /// The *url*'s domain must be the same as the domain linked with the bot.
+    /// See [Linking your domain to the bot](https://core.telegram.org/widgets/login#linking-your-domain-to-the-bot)
+    /// for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_username: Option<String>,
+    /// Pass True to request the permission for your bot to send messages to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_write_access: Option<bool>,
+}
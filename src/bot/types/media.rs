@@ -0,0 +1,650 @@
+//! Media attachments and files to send.
+use super::{FileId, ParseMode, UserId};
+use crate::prelude::*;
+#[cfg(feature = "full-serde")]
+use serde::Deserializer;
+use serde::{Deserialize, Serialize};
+
+/// A general file (as opposed to [photos](PhotoSize), [voice messages](Voice) and
+/// [audio files](Audio)).
+#[derive(Deserialize, Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Document {
+    /// Unique file identifier
+    pub file_id: FileId,
+    /// Document thumbnail as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+    /// Original filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
+
+/// A video file.
+#[derive(Deserialize, Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Video {
+    /// Unique identifier for this file
+    pub file_id: FileId,
+    pub width: i64,
+    pub height: i64,
+    /// Duration of the video in seconds as defined by sender
+    pub duration: i64,
+    /// Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+    /// Mime type of a file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
+
+/// An animation file (GIF or H.264/MPEG-4 AVC video without sound).
+#[derive(Deserialize, Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Animation {
+    /// Unique identifier for this file
+    pub file_id: FileId,
+    pub width: i64,
+    pub height: i64,
+    /// Duration of the video in seconds as defined by sender
+    pub duration: i64,
+    /// Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+    /// Original animation filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// Mime type of a file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
+
+/// An audio file to be treated as music by the Telegram clients.
+#[derive(Deserialize, Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Audio {
+    /// Unique identifier for this file
+    pub file_id: FileId,
+    /// Duration of the audio in seconds as defined by sender
+    pub duration: i64,
+    /// Performer of the audio as defined by sender or by audio tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    /// Title of the audio as defined by sender or by audio tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Thumbnail of the album cover to which the music file belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+}
+
+/// A voice note.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Voice {
+    /// Unique identifier for this file
+    pub file_id: FileId,
+    /// Duration of the audio in seconds as defined by sender
+    pub duration: i64,
+    /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
+
+/// A video message (available in Telegram apps as of v.4.0).
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VideoNote {
+    /// Unique identifier for this file
+    pub file_id: FileId,
+    /// Video width and height as defined by sender
+    pub length: i64,
+    /// Duration of the audio in seconds as defined by sender
+    pub duration: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
+
+/// A phone contact.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Contact {
+    pub phone_number: String,
+    pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    /// Additional data about the contact in the form of a vCard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+}
+
+/// A file ready to be downloaded.
+/// The file can be downloaded via the link `https://api.telegram.org/file/bot<token>/<file_path>`.
+/// It is guaranteed that the link will be valid for at least 1 hour. When the link expires,
+/// a new one can be requested by calling `getFile`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct File {
+    /// Unique identifier for this file
+    pub file_id: FileId,
+    /// File size, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+    /// Optional. File path. Use `https://api.telegram.org/file/bot<token>/<file_path>` to get the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+}
+
+impl File {
+    /// The URL to download the file from the cloud Bot API server, `None` if it has no
+    /// `file_path`. The URL has the bot's token in it, don't show it to users.
+    pub fn download_url(&self, token: &str) -> Option<String> {
+        self.download_url_at(crate::bot::API_URL, token)
+    }
+
+    /// The URL to download the file from the Bot API server at `api_url`, such as
+    /// `https://api.telegram.org`. With the `client` feature, `Server::file_location` also
+    /// handles the test environment and local servers.
+    pub fn download_url_at(&self, api_url: &str, token: &str) -> Option<String> {
+        self.download_url_in(api_url, token, "")
+    }
+
+    /// The download URL in `environment`, `test/` for the test environment and empty otherwise.
+    pub(crate) fn download_url_in(
+        &self,
+        api_url: &str,
+        token: &str,
+        environment: &str,
+    ) -> Option<String> {
+        let path = self.file_path.as_ref()?;
+        let api_url = api_url.trim_end_matches('/');
+        Some(format!(
+            "{}/file/bot{}/{}{}",
+            api_url, token, environment, path
+        ))
+    }
+}
+
+/// An animated emoji that shows a random value.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Dice {
+    /// Emoji on which the dice throw animation is based
+    pub emoji: String,
+    /// Value of the dice, 1-6 for 🎲, 🎯 and 🎳, 1-5 for 🏀 and ⚽, 1-64 for 🎰
+    pub value: i64,
+}
+
+/// A point on the map.
+#[derive(Deserialize, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Location {
+    /// Longitude as defined by sender
+    pub longitude: f32,
+    /// Latitude as defined by sender
+    pub latitude: f32,
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f32>,
+    /// Time relative to the message sending date, during which the location can be updated;
+    /// in seconds. For active live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+    /// The direction in which user is moving, in degrees; 1-360. For active live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+    /// The maximum distance for proximity alerts about approaching another chat member, in
+    /// meters. For sent live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+}
+
+impl Location {
+    /// Whether the location is live and may still be updated.
+    pub fn is_live(&self) -> bool {
+        self.live_period.is_some()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Venue {
+    /// Venue location
+    pub location: Location,
+    /// Name of the venue
+    pub title: String,
+    /// Address of the venue
+    pub address: String,
+    /// Foursquare identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    /// Foursquare type of the venue. (For example, “arts_entertainment/default”,
+    /// “arts_entertainment/aquarium” or “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+}
+
+/// One size of a photo or a [file](Document) / [sticker](super::Sticker) thumbnail.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PhotoSize {
+    /// Unique identifier for this file
+    pub file_id: FileId,
+    pub width: i64,
+    pub height: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
+
+/// A user's profile pictures.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UserProfilePhotos {
+    /// Total number of profile pictures the target user has
+    pub total_count: i64,
+    /// Requested profile pictures, newest first, each in up to 4 sizes
+    #[serde(default)]
+    pub photos: Vec<Vec<PhotoSize>>,
+}
+
+impl UserProfilePhotos {
+    /// The size of the newest picture to show at `size` pixels: the smallest at least that wide
+    /// and high, or the largest if none is. `None` if the user has no picture.
+    pub fn newest(&self, size: i64) -> Option<&PhotoSize> {
+        let sizes = self.photos.first()?;
+        let fits = |photo: &&PhotoSize| photo.width >= size && photo.height >= size;
+        let area = |photo: &&PhotoSize| photo.width * photo.height;
+        match sizes.iter().filter(fits).min_by_key(area) {
+            Some(photo) => Some(photo),
+            None => sizes.iter().max_by_key(area),
+        }
+    }
+}
+
+/// The contents of a file to be uploaded.
+///
+/// Must be posted using `multipart/form-data` in the usual way that
+/// files are uploaded via the browser.
+///
+/// [More info on Sending Files](https://core.telegram.org/bots/api#sending-files)
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct InputFile(pub String);
+
+impl InputFile {
+    /// using `multipart/form-data` under <file_attach_name> name.
+    pub fn new<S: AsRef<str>>(file_attach_name: S) -> InputFile {
+        let attach = format!("attach://{}", file_attach_name.as_ref());
+        InputFile(attach)
+    }
+}
+
+/// A file to upload with a method, under the name its [`InputFile`] refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    /// The name in `attach://<name>`.
+    pub name: String,
+    /// The file name Telegram shows, such as `report.pdf`.
+    pub file_name: String,
+    pub data: Vec<u8>,
+}
+
+impl Attachment {
+    pub fn new<N: Into<String>, F: Into<String>>(
+        name: N,
+        file_name: F,
+        data: Vec<u8>,
+    ) -> Attachment {
+        Attachment {
+            name: name.into(),
+            file_name: file_name.into(),
+            data,
+        }
+    }
+
+    /// The `attach://` reference to put in the method.
+    pub fn input_file(&self) -> InputFile {
+        InputFile::new(&self.name)
+    }
+}
+
+/// There are three ways to send files
+///
+/// 1. If the file is already stored somewhere on the Telegram servers, you don't need to reupload it: each file object has a **file_id** field, simply pass this **file_id** as a parameter instead of uploading. There are **no limits** for files sent this way.
+/// 2. Provide Telegram with an HTTP URL for the file to be sent. Telegram will download and send the file. 5 MB max size for photos and 20 MB max for other types of content.
+/// 3. Post the file using multipart/form-data in the usual way that files are uploaded via the browser. 10 MB max size for photos, 50 MB for other files.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum FileToSend {
+    FileId(FileId),
+    Url(String),
+    InputFile(InputFile),
+}
+
+#[cfg(feature = "full-serde")]
+impl<'de> Deserialize<'de> for FileToSend {
+    /// All three kinds are strings, tell them apart by prefix.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<FileToSend, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(if s.starts_with("attach://") {
+            FileToSend::InputFile(InputFile(s))
+        } else if s.starts_with("http://") || s.starts_with("https://") {
+            FileToSend::Url(s)
+        } else {
+            FileToSend::FileId(FileId(s))
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputFile {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<InputFile> {
+        Ok(InputFile::new(String::arbitrary(u)?))
+    }
+}
+
+/// Only values that deserialize back to the same kind, see the `Deserialize` impl.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FileToSend {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<FileToSend> {
+        let s = String::arbitrary(u)?;
+        Ok(match u.int_in_range(0..=2)? {
+            0 if !s.starts_with("attach://") && !s.starts_with("http") => {
+                FileToSend::FileId(FileId(s))
+            }
+            1 => FileToSend::Url(format!("https://{}", s)),
+            _ => FileToSend::InputFile(InputFile::new(s)),
+        })
+    }
+}
+
+/// The content of a media message to be sent.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+pub enum InputMedia {
+    #[serde(rename = "video")]
+    Video {
+        /// File to send.
+        ///
+        /// Pass a file_id to send a file that exists on the Telegram servers (recommended),
+        /// pass an HTTP URL for Telegram to get a file from the Internet, or pass
+        /// "attach://<file_attach_name>" to upload a new one using multipart/form-data
+        /// under <file_attach_name> name.
+        ///
+        /// [More info on Sending Files](https://core.telegram.org/bots/api#sending-files)
+        media: FileToSend,
+        /// *Optional*. Caption of the photo to be sent, 0-200 characters
+        #[serde(skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+        /// *Optional*. Send Markdown or HTML, if you want Telegram apps to show
+        /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options)
+        /// in the media caption.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parse_mode: Option<ParseMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        width: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        height: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        duration: Option<i64>,
+        /// Pass True, if the uploaded video is suitable for streaming
+        #[serde(skip_serializing_if = "Option::is_none")]
+        supports_streaming: Option<bool>,
+    },
+    #[serde(rename = "photo")]
+    Photo {
+        /// File to send.
+        ///
+        /// Pass a file_id to send a file that exists on the Telegram servers (recommended),
+        /// pass an HTTP URL for Telegram to get a file from the Internet, or pass
+        /// "attach://<file_attach_name>" to upload a new one using multipart/form-data
+        /// under <file_attach_name> name.
+        ///
+        /// [More info on Sending Files](https://core.telegram.org/bots/api#sending-files)
+        media: FileToSend,
+        /// *Optional*. Caption of the photo to be sent, 0-200 characters
+        #[serde(skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+        /// *Optional*. Send Markdown or HTML, if you want Telegram apps to show
+        /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options)
+        /// in the media caption.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parse_mode: Option<ParseMode>,
+    },
+    #[serde(rename = "animation")]
+    Animation {
+        /// File to send.
+        ///
+        /// Pass a file_id to send a file that exists on the Telegram servers (recommended),
+        /// pass an HTTP URL for Telegram to get a file from the Internet, or pass
+        /// "attach://<file_attach_name>" to upload a new one using multipart/form-data
+        /// under <file_attach_name> name.
+        ///
+        /// [More info on Sending Files](https://core.telegram.org/bots/api#sending-files)
+        media: FileToSend,
+        /// Thumbnail of the file sent.
+        ///
+        /// The thumbnail should be in JPEG format and less than 200 kB in size.
+        ///
+        /// A thumbnail‘s width and height should not exceed 90.
+        ///
+        /// Ignored if the file is not uploaded using multipart/form-data.
+        ///
+        /// Thumbnails can’t be reused and can be only uploaded as a new file,
+        /// so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded
+        /// using multipart/form-data under <file_attach_name>.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thumb: Option<InputFile>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parse_mode: Option<ParseMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        width: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        height: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        duration: Option<i64>,
+    },
+    #[serde(rename = "audio")]
+    Audio {
+        media: FileToSend,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thumb: Option<InputFile>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parse_mode: Option<ParseMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        duration: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        performer: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
+    #[serde(rename = "document")]
+    Document {
+        media: FileToSend,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thumb: Option<InputFile>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parse_mode: Option<ParseMode>,
+    },
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+impl InputMedia {
+    /// A photo to be sent.
+    pub fn photo(media: FileToSend) -> InputMedia {
+        InputMedia::Photo {
+            media,
+            caption: None,
+            parse_mode: None,
+        }
+    }
+
+    /// A video to be sent.
+    pub fn video(media: FileToSend) -> InputMedia {
+        InputMedia::Video {
+            media,
+            caption: None,
+            parse_mode: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: None,
+        }
+    }
+
+    /// An animation to be sent.
+    pub fn animation(media: FileToSend) -> InputMedia {
+        InputMedia::Animation {
+            media,
+            thumb: None,
+            caption: None,
+            parse_mode: None,
+            width: None,
+            height: None,
+            duration: None,
+        }
+    }
+
+    /// An audio file to be treated as music to be sent.
+    pub fn audio(media: FileToSend) -> InputMedia {
+        InputMedia::Audio {
+            media,
+            thumb: None,
+            caption: None,
+            parse_mode: None,
+            duration: None,
+            performer: None,
+            title: None,
+        }
+    }
+
+    /// A general file to be sent.
+    pub fn document(media: FileToSend) -> InputMedia {
+        InputMedia::Document {
+            media,
+            thumb: None,
+            caption: None,
+            parse_mode: None,
+        }
+    }
+
+    /// Set the caption. Does nothing to [`InputMedia::Unknown`].
+    pub fn caption<T: Into<String>>(mut self, text: T) -> InputMedia {
+        match self {
+            InputMedia::Photo {
+                ref mut caption, ..
+            }
+            | InputMedia::Video {
+                ref mut caption, ..
+            }
+            | InputMedia::Animation {
+                ref mut caption, ..
+            }
+            | InputMedia::Audio {
+                ref mut caption, ..
+            }
+            | InputMedia::Document {
+                ref mut caption, ..
+            } => *caption = Some(text.into()),
+            InputMedia::Unknown => (),
+        }
+        self
+    }
+
+    /// Set the parse mode of the caption. Does nothing to [`InputMedia::Unknown`].
+    pub fn parse_mode(mut self, mode: ParseMode) -> InputMedia {
+        match self {
+            InputMedia::Photo {
+                ref mut parse_mode, ..
+            }
+            | InputMedia::Video {
+                ref mut parse_mode, ..
+            }
+            | InputMedia::Animation {
+                ref mut parse_mode, ..
+            }
+            | InputMedia::Audio {
+                ref mut parse_mode, ..
+            }
+            | InputMedia::Document {
+                ref mut parse_mode, ..
+            } => *parse_mode = Some(mode),
+            InputMedia::Unknown => (),
+        }
+        self
+    }
+}
@@ -0,0 +1,667 @@
+//! Messages and their entities.
+#[cfg(feature = "extra-fields")]
+use super::ExtraFields;
+#[cfg(feature = "stickers")]
+use super::Sticker;
+use super::{
+    Animation, Audio, Chat, ChatId, Contact, Dice, Document, InlineKeyboardMarkup, Location,
+    MessageId, PhotoSize, Time, User, Venue, Video, VideoNote, Voice,
+};
+#[cfg(feature = "passport")]
+use crate::bot::passport::PassportData;
+#[cfg(feature = "payments")]
+use crate::bot::payments::{Invoice, RefundedPayment, SuccessfulPayment};
+use crate::bot::text::{self, Command, Mention};
+use crate::bot::utils::falsum;
+#[cfg(any(feature = "full-serde", feature = "schemars"))]
+use crate::bot::utils::is_false;
+use crate::prelude::*;
+use core::ops::{Add, Range};
+use serde::{Deserialize, Serialize};
+
+// TODO: game
+/// A message.
+///
+/// Rarely present groups of fields live in [`forward`](Message::forward) and
+/// [`service`](Message::service), use the accessor methods to read them.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[serde(from = "MessageRepr")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Message {
+    /// Unique message identifier inside this chat
+    pub message_id: MessageId,
+    /// Sender, empty for messages sent to channels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<Box<User>>,
+    /// Sender of the message, sent on behalf of a chat.
+    /// The channel itself for channel messages.
+    /// The supergroup itself for messages from anonymous group administrators.
+    /// The linked channel for messages automatically forwarded to the discussion group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: Time,
+    /// Conversation the message belongs to
+    pub chat: Box<Chat>,
+    /// For replies, the original message.
+    /// Note that the Message object in this field will not contain
+    /// further `reply_to_message` fields even if it itself is a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message: Option<Box<Message>>,
+    /// Date the message was last edited in Unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edit_date: Option<Time>,
+    /// The unique identifier of a media message group this message belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_group_id: Option<String>,
+    /// Signature of the post author for messages in channels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_signature: Option<String>,
+    /// For text messages, the actual UTF-8 text of the message, 0-4096 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Message is a sticker, information about the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "stickers")]
+    pub sticker: Option<Box<Sticker>>,
+    /// Message is an audio file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<Audio>,
+    /// Message is a general file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<Document>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub photo: Vec<PhotoSize>,
+    /// For text messages, special entities like usernames, URLs, bot commands, etc.
+    /// that appear in the text
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entities: Vec<MessageEntity>,
+    /// Message is a voice message, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Box<Voice>>,
+    /// Message is a video, information about the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<Video>,
+    /// Message is a video note, information about the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_note: Option<Box<VideoNote>>,
+    /// Message is an animation, information about the animation.
+    ///
+    /// For backward compatibility, when this field is set, the document field will also be set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Box<Animation>>,
+    /// For messages with a caption, special entities like usernames, URLs, bot commands, etc.
+    /// that appear in the caption
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub caption_entities: Vec<MessageEntity>,
+    /// Caption for the audio, document, photo, video or voice, 0-200 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Message is a shared contact, information about the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Box<Contact>>,
+    /// Message is a shared location, information about the location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Box<Location>>,
+    /// Message is a venue, information about the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue: Option<Box<Venue>>,
+    /// Message is a dice with a random value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dice: Option<Box<Dice>>,
+    /// Message is an invoice for a payment, information about the invoice
+    #[cfg(feature = "payments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Box<Invoice>>,
+    /// Telegram Passport data the user shared with the bot
+    #[cfg(feature = "passport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport_data: Option<Box<PassportData>>,
+    /// Inline keyboard attached to the message.
+    ///
+    /// `login_url` buttons are represented as ordinary `url` buttons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Origin of a forwarded message, `None` if the message was not forwarded.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub forward: Option<Box<ForwardExtras>>,
+    /// Service message fields, `None` for ordinary messages.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub service: Option<Box<ServiceExtras>>,
+    /// Fields this crate doesn't know about yet.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: ExtraFields,
+}
+
+/// Fields of a forwarded [`Message`].
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ForwardExtras {
+    /// For forwarded messages, sender of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_from: Option<Box<User>>,
+    /// For messages forwarded from channels, information about the original channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_from_chat: Option<Box<Chat>>,
+    /// For messages forwarded from channels, identifier of the original message in the channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_from_message_id: Option<MessageId>,
+    /// For messages forwarded from channels, signature of the post author if present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_signature: Option<String>,
+    /// Sender's name for messages forwarded from users who disallow adding a link to their account
+    /// in forwarded messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_sender_name: Option<String>,
+    /// For forwarded messages, date the original message was sent in Unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_date: Option<Time>,
+}
+
+/// Fields of a service [`Message`], sent when something about the chat changes.
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ServiceExtras {
+    /// New members that were added to the group or supergroup and information about them
+    /// (the bot itself may be one of these members)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub new_chat_members: Vec<User>,
+    /// A member was removed from the group, information about them
+    /// (this member may be the bot itself)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left_chat_member: Option<Box<User>>,
+    /// A chat title was changed to this value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_chat_title: Option<String>,
+    /// A chat photo was change to this value
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub new_chat_photo: Vec<PhotoSize>,
+    /// Service message: the chat photo was deleted
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub delete_chat_photo: bool,
+    /// Service message: the group has been created
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub group_chat_created: bool,
+    /// Service message: the supergroup has been created.
+    /// This field can‘t be received in a message coming through updates, because bot can’t
+    /// be a member of a supergroup when it is created. It can only be found in reply_to_message
+    /// if someone replies to a very first message in a directly created supergroup.
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub supergroup_chat_created: bool,
+    /// Service message: the channel has been created.
+    ///
+    /// This field can‘t be received in a message coming through updates, because bot can’t be
+    /// a member of a channel when it is created. It can only be found in reply_to_message
+    /// if someone replies to a very first message in a channel.
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub channel_chat_created: bool,
+    /// The group has been migrated to a supergroup with the specified identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_to_chat_id: Option<ChatId>,
+    /// The supergroup has been migrated from a group with the specified identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_from_chat_id: Option<ChatId>,
+    /// Specified message was pinned. Note that the Message object in this field
+    /// will not contain further reply_to_message fields even if it is itself a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_message: Option<Box<Message>>,
+    /// The domain name of the website on which the user has logged in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connected_website: Option<String>,
+    /// A successful payment, information about the payment
+    #[cfg(feature = "payments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub successful_payment: Option<Box<SuccessfulPayment>>,
+    /// A refunded payment, information about the payment
+    #[cfg(feature = "payments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refunded_payment: Option<Box<RefundedPayment>>,
+}
+
+/// The flat wire shape of [`Message`].
+#[derive(Deserialize)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Message")
+)]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+struct MessageRepr {
+    message_id: MessageId,
+    from: Option<Box<User>>,
+    sender_chat: Option<Chat>,
+    date: Time,
+    chat: Box<Chat>,
+    forward_from: Option<Box<User>>,
+    forward_from_chat: Option<Box<Chat>>,
+    forward_from_message_id: Option<MessageId>,
+    forward_signature: Option<String>,
+    forward_sender_name: Option<String>,
+    forward_date: Option<Time>,
+    reply_to_message: Option<Box<Message>>,
+    edit_date: Option<Time>,
+    media_group_id: Option<String>,
+    author_signature: Option<String>,
+    text: Option<String>,
+    #[cfg(feature = "stickers")]
+    sticker: Option<Box<Sticker>>,
+    audio: Option<Audio>,
+    document: Option<Box<Document>>,
+    #[serde(default)]
+    photo: Vec<PhotoSize>,
+    #[serde(default)]
+    entities: Vec<MessageEntity>,
+    voice: Option<Box<Voice>>,
+    video: Option<Video>,
+    video_note: Option<Box<VideoNote>>,
+    animation: Option<Box<Animation>>,
+    #[serde(default)]
+    caption_entities: Vec<MessageEntity>,
+    caption: Option<String>,
+    contact: Option<Box<Contact>>,
+    location: Option<Box<Location>>,
+    venue: Option<Box<Venue>>,
+    dice: Option<Box<Dice>>,
+    #[cfg(feature = "payments")]
+    invoice: Option<Box<Invoice>>,
+    #[cfg(feature = "passport")]
+    passport_data: Option<Box<PassportData>>,
+    #[serde(default)]
+    new_chat_members: Vec<User>,
+    left_chat_member: Option<Box<User>>,
+    new_chat_title: Option<String>,
+    #[serde(default)]
+    new_chat_photo: Vec<PhotoSize>,
+    #[serde(default = "falsum")]
+    delete_chat_photo: bool,
+    #[serde(default = "falsum")]
+    group_chat_created: bool,
+    #[serde(default = "falsum")]
+    supergroup_chat_created: bool,
+    #[serde(default = "falsum")]
+    channel_chat_created: bool,
+    migrate_to_chat_id: Option<ChatId>,
+    migrate_from_chat_id: Option<ChatId>,
+    pinned_message: Option<Box<Message>>,
+    connected_website: Option<String>,
+    #[cfg(feature = "payments")]
+    successful_payment: Option<Box<SuccessfulPayment>>,
+    #[cfg(feature = "payments")]
+    refunded_payment: Option<Box<RefundedPayment>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    extra: ExtraFields,
+}
+
+impl From<MessageRepr> for Message {
+    fn from(repr: MessageRepr) -> Message {
+        let forward = ForwardExtras {
+            forward_from: repr.forward_from,
+            forward_from_chat: repr.forward_from_chat,
+            forward_from_message_id: repr.forward_from_message_id,
+            forward_signature: repr.forward_signature,
+            forward_sender_name: repr.forward_sender_name,
+            forward_date: repr.forward_date,
+        };
+        let service = ServiceExtras {
+            new_chat_members: repr.new_chat_members,
+            left_chat_member: repr.left_chat_member,
+            new_chat_title: repr.new_chat_title,
+            new_chat_photo: repr.new_chat_photo,
+            delete_chat_photo: repr.delete_chat_photo,
+            group_chat_created: repr.group_chat_created,
+            supergroup_chat_created: repr.supergroup_chat_created,
+            channel_chat_created: repr.channel_chat_created,
+            migrate_to_chat_id: repr.migrate_to_chat_id,
+            migrate_from_chat_id: repr.migrate_from_chat_id,
+            pinned_message: repr.pinned_message,
+            connected_website: repr.connected_website,
+            #[cfg(feature = "payments")]
+            successful_payment: repr.successful_payment,
+            #[cfg(feature = "payments")]
+            refunded_payment: repr.refunded_payment,
+        };
+        Message {
+            message_id: repr.message_id,
+            from: repr.from,
+            sender_chat: repr.sender_chat,
+            date: repr.date,
+            chat: repr.chat,
+            reply_to_message: repr.reply_to_message,
+            edit_date: repr.edit_date,
+            media_group_id: repr.media_group_id,
+            author_signature: repr.author_signature,
+            text: repr.text,
+            #[cfg(feature = "stickers")]
+            sticker: repr.sticker,
+            audio: repr.audio,
+            document: repr.document,
+            photo: repr.photo,
+            entities: repr.entities,
+            voice: repr.voice,
+            video: repr.video,
+            video_note: repr.video_note,
+            animation: repr.animation,
+            caption_entities: repr.caption_entities,
+            caption: repr.caption,
+            contact: repr.contact,
+            location: repr.location,
+            venue: repr.venue,
+            dice: repr.dice,
+            #[cfg(feature = "payments")]
+            invoice: repr.invoice,
+            #[cfg(feature = "passport")]
+            passport_data: repr.passport_data,
+            reply_markup: repr.reply_markup,
+            forward: Some(forward)
+                .filter(|x| *x != ForwardExtras::default())
+                .map(Box::new),
+            service: Some(service)
+                .filter(|x| *x != ServiceExtras::default())
+                .map(Box::new),
+            #[cfg(feature = "extra-fields")]
+            extra: repr.extra,
+        }
+    }
+}
+
+/// Accessors for the fields in [`ForwardExtras`] and [`ServiceExtras`].
+impl Message {
+    /// For forwarded messages, sender of the original message.
+    pub fn forward_from(&self) -> Option<&User> {
+        self.forward.as_ref()?.forward_from.as_deref()
+    }
+
+    /// For messages forwarded from channels, the original channel.
+    pub fn forward_from_chat(&self) -> Option<&Chat> {
+        self.forward.as_ref()?.forward_from_chat.as_deref()
+    }
+
+    /// For messages forwarded from channels, identifier of the original message.
+    pub fn forward_from_message_id(&self) -> Option<MessageId> {
+        self.forward.as_ref()?.forward_from_message_id
+    }
+
+    /// For messages forwarded from channels, signature of the post author.
+    pub fn forward_signature(&self) -> Option<&str> {
+        self.forward.as_ref()?.forward_signature.as_deref()
+    }
+
+    /// Sender's name for messages forwarded from users who hide their account.
+    pub fn forward_sender_name(&self) -> Option<&str> {
+        self.forward.as_ref()?.forward_sender_name.as_deref()
+    }
+
+    /// For forwarded messages, date the original message was sent.
+    pub fn forward_date(&self) -> Option<&Time> {
+        self.forward.as_ref()?.forward_date.as_ref()
+    }
+
+    /// New members that were added to the group or supergroup.
+    pub fn new_chat_members(&self) -> &[User] {
+        self.service.as_ref().map_or(&[], |x| &x.new_chat_members)
+    }
+
+    /// A member was removed from the group.
+    pub fn left_chat_member(&self) -> Option<&User> {
+        self.service.as_ref()?.left_chat_member.as_deref()
+    }
+
+    /// A chat title was changed to this value.
+    pub fn new_chat_title(&self) -> Option<&str> {
+        self.service.as_ref()?.new_chat_title.as_deref()
+    }
+
+    /// A chat photo was changed to this value.
+    pub fn new_chat_photo(&self) -> &[PhotoSize] {
+        self.service.as_ref().map_or(&[], |x| &x.new_chat_photo)
+    }
+
+    /// The chat photo was deleted.
+    pub fn delete_chat_photo(&self) -> bool {
+        self.service.as_ref().is_some_and(|x| x.delete_chat_photo)
+    }
+
+    /// The group has been created.
+    pub fn group_chat_created(&self) -> bool {
+        self.service.as_ref().is_some_and(|x| x.group_chat_created)
+    }
+
+    /// The supergroup has been created.
+    pub fn supergroup_chat_created(&self) -> bool {
+        self.service
+            .as_ref()
+            .is_some_and(|x| x.supergroup_chat_created)
+    }
+
+    /// The channel has been created.
+    pub fn channel_chat_created(&self) -> bool {
+        self.service
+            .as_ref()
+            .is_some_and(|x| x.channel_chat_created)
+    }
+
+    /// The group has been migrated to a supergroup with this identifier.
+    pub fn migrate_to_chat_id(&self) -> Option<ChatId> {
+        self.service.as_ref()?.migrate_to_chat_id
+    }
+
+    /// The supergroup has been migrated from a group with this identifier.
+    pub fn migrate_from_chat_id(&self) -> Option<ChatId> {
+        self.service.as_ref()?.migrate_from_chat_id
+    }
+
+    /// Specified message was pinned.
+    pub fn pinned_message(&self) -> Option<&Message> {
+        self.service.as_ref()?.pinned_message.as_deref()
+    }
+
+    /// The domain name of the website on which the user has logged in.
+    pub fn connected_website(&self) -> Option<&str> {
+        self.service.as_ref()?.connected_website.as_deref()
+    }
+
+    /// A successful payment.
+    #[cfg(feature = "payments")]
+    pub fn successful_payment(&self) -> Option<&SuccessfulPayment> {
+        self.service.as_ref()?.successful_payment.as_deref()
+    }
+
+    /// A refunded payment.
+    #[cfg(feature = "payments")]
+    pub fn refunded_payment(&self) -> Option<&RefundedPayment> {
+        self.service.as_ref()?.refunded_payment.as_deref()
+    }
+}
+
+impl Message {
+    /// The text of a text message, or the caption of a media message.
+    pub fn text_or_caption(&self) -> Option<&str> {
+        self.text.as_deref().or(self.caption.as_deref())
+    }
+
+    /// The entities of [`text_or_caption`](Message::text_or_caption).
+    pub fn entities_or_caption_entities(&self) -> &[MessageEntity] {
+        if self.text.is_some() {
+            &self.entities
+        } else {
+            &self.caption_entities
+        }
+    }
+
+    /// Entities of [`text_or_caption`](Message::text_or_caption) along with the text they cover.
+    pub fn entities_with_text(&self) -> impl Iterator<Item = (&MessageEntity, &str)> {
+        let text = self.text_or_caption().unwrap_or_default();
+        self.entities_or_caption_entities()
+            .iter()
+            .filter_map(move |entity| Some((entity, text::entity_text(text, entity)?)))
+    }
+
+    /// URLs in the message, including the targets of text links.
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        self.entities_with_text()
+            .filter_map(|(entity, text)| match entity.kind {
+                MessageEntityKind::Url => Some(text),
+                MessageEntityKind::TextLink => entity.url.as_deref(),
+                _ => None,
+            })
+    }
+
+    /// Users mentioned in the message.
+    pub fn mentions(&self) -> impl Iterator<Item = Mention<'_>> {
+        self.entities_with_text()
+            .filter_map(|(entity, text)| match entity.kind {
+                MessageEntityKind::Mention => text.strip_prefix('@').map(Mention::Username),
+                MessageEntityKind::TextMention => entity.user.as_deref().map(Mention::User),
+                _ => None,
+            })
+    }
+
+    /// Hashtags in the message, without the `#`.
+    pub fn hashtags(&self) -> impl Iterator<Item = &str> {
+        self.entities_with_text()
+            .filter(|(entity, _)| entity.kind == MessageEntityKind::Hashtag)
+            .filter_map(|(_, text)| text.strip_prefix('#'))
+    }
+
+    /// Bot commands in the message.
+    pub fn commands(&self) -> impl Iterator<Item = Command<'_>> {
+        self.entities_with_text()
+            .filter(|(entity, _)| entity.kind == MessageEntityKind::BotCommand)
+            .filter_map(|(_, text)| Command::parse(text))
+    }
+}
+
+/// One special entity in a text message.
+/// For example, hashtags, usernames, URLs, etc.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageEntity {
+    /// Type of the entity.
+    #[serde(rename = "type")]
+    pub kind: MessageEntityKind,
+    /// Offset in UTF-16 code units to the start of the entity
+    pub offset: Utf16Offset,
+    /// Length of the entity in UTF-16 code units
+    pub length: Utf16Len,
+    /// For “text_link” only, url that will be opened after user taps on the text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// For “text_mention” only, the mentioned user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+impl MessageEntity {
+    /// Where the entity ends, in UTF-16 code units.
+    pub fn end(&self) -> Utf16Offset {
+        self.offset + self.length
+    }
+
+    /// The byte range of `text` covered by the entity, for indexing the Rust string.
+    pub fn byte_range(&self, text: &str) -> Option<Range<usize>> {
+        text::utf16_range(text, self.offset, self.length)
+    }
+}
+
+/// A position in a text, counted in UTF-16 code units as Telegram does.
+///
+/// Not a byte index, convert it with [`to_byte`](Utf16Offset::to_byte) before slicing a `str`.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct Utf16Offset(pub u32);
+
+impl Utf16Offset {
+    /// The offset of byte index `byte` of `text`, `None` if it isn't on a character boundary.
+    pub fn from_byte(text: &str, byte: usize) -> Option<Utf16Offset> {
+        let prefix = text.get(..byte)?;
+        Some(Utf16Offset(Utf16Len::of(prefix).0))
+    }
+
+    /// The byte index in `text`, `None` if it is out of bounds or inside a character.
+    pub fn to_byte(self, text: &str) -> Option<usize> {
+        text::utf16_range(text, self, Utf16Len(0)).map(|range| range.start)
+    }
+}
+
+impl Add<Utf16Len> for Utf16Offset {
+    type Output = Utf16Offset;
+
+    fn add(self, length: Utf16Len) -> Utf16Offset {
+        Utf16Offset(self.0.saturating_add(length.0))
+    }
+}
+
+/// A length of text, counted in UTF-16 code units as Telegram does.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct Utf16Len(pub u32);
+
+impl Utf16Len {
+    /// The length of `text`.
+    pub fn of(text: &str) -> Utf16Len {
+        Utf16Len(text::utf16_len(text) as u32)
+    }
+}
+
+/// Type of the `MessageEntity`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum MessageEntityKind {
+    /// `@username`
+    Mention,
+    Hashtag,
+    Cashtag,
+    BotCommand,
+    Url,
+    Email,
+    PhoneNumber,
+    /// bold text
+    Bold,
+    /// italic text
+    Italic,
+    /// monowidth string
+    Code,
+    /// monowidth block
+    Pre,
+    /// for clickable text URLs
+    TextLink,
+    /// for users without usernames
+    TextMention,
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
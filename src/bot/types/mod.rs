@@ -0,0 +1,338 @@
+//! Telegram bot object types.
+//!
+//! The types are split into submodules by topic and re-exported here, so
+//! `types::Message` and `types::message::Message` name the same type.
+pub mod chat;
+pub mod keyboard;
+pub mod media;
+pub mod message;
+pub mod poll;
+#[cfg(feature = "stickers")]
+pub mod sticker;
+pub mod update;
+pub mod webhook;
+
+pub use self::chat::*;
+pub use self::keyboard::*;
+pub use self::media::*;
+pub use self::message::*;
+pub use self::poll::*;
+#[cfg(feature = "stickers")]
+pub use self::sticker::*;
+pub use self::update::*;
+pub use self::webhook::*;
+
+use crate::prelude::*;
+#[cfg(feature = "chrono")]
+use chrono::naive::NaiveDateTime;
+use core::fmt;
+use serde::de::{Error as DeError, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+macro_rules! impl_id {
+    ($Id: ident : $Ty: ty) => {
+        /// Deserialized from either a number or a string of digits, serialized as a number
+        /// or, with the `string-ids` feature, as a string. Formats that aren't human-readable,
+        /// such as bincode, only use the number.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+        #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+        pub struct $Id(
+            #[cfg_attr(
+                all(feature = "schemars", feature = "string-ids"),
+                schemars(with = "String")
+            )]
+            pub $Ty,
+        );
+
+        impl Serialize for $Id {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                // Compact formats such as bincode are for Rust, the number itself is expected.
+                if cfg!(feature = "string-ids") && serializer.is_human_readable() {
+                    serializer.collect_str(&self.0)
+                } else {
+                    self.0.serialize(serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $Id {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$Id, D::Error> {
+                struct IdVisitor;
+
+                impl<'de> Visitor<'de> for IdVisitor {
+                    type Value = $Id;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str("an integer or a string of digits")
+                    }
+
+                    fn visit_i64<E: DeError>(self, v: i64) -> Result<$Id, E> {
+                        <$Ty as ::core::convert::TryFrom<i64>>::try_from(v)
+                            .map($Id)
+                            .map_err(|_| E::custom(format!("{} is out of range", v)))
+                    }
+
+                    fn visit_u64<E: DeError>(self, v: u64) -> Result<$Id, E> {
+                        <$Ty as ::core::convert::TryFrom<u64>>::try_from(v)
+                            .map($Id)
+                            .map_err(|_| E::custom(format!("{} is out of range", v)))
+                    }
+
+                    fn visit_str<E: DeError>(self, v: &str) -> Result<$Id, E> {
+                        v.parse()
+                            .map($Id)
+                            .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+                    }
+                }
+
+                // Formats that aren't self-describing can't tell a string from a number.
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(IdVisitor)
+                } else {
+                    <$Ty>::deserialize(deserializer).map($Id)
+                }
+            }
+        }
+
+        impl ::core::ops::Add<$Ty> for $Id {
+            type Output = $Id;
+            #[inline]
+            fn add(self, other: $Ty) -> $Id {
+                $Id(self.0 + other)
+            }
+        }
+
+        impl<'a> ::core::ops::Add<&'a $Ty> for $Id {
+            type Output = $Id;
+            #[inline]
+            fn add(self, other: &$Ty) -> Self::Output {
+                $Id(self.0 + other)
+            }
+        }
+
+        impl ::core::ops::Sub<$Ty> for $Id {
+            type Output = $Id;
+            #[inline]
+            fn sub(self, other: $Ty) -> $Id {
+                $Id(self.0 - other)
+            }
+        }
+        impl ::core::ops::AddAssign<$Ty> for $Id {
+            fn add_assign(&mut self, rhs: $Ty) {
+                self.0 += rhs
+            }
+        }
+        impl ::core::ops::SubAssign<$Ty> for $Id {
+            fn sub_assign(&mut self, rhs: $Ty) {
+                self.0 -= rhs
+            }
+        }
+    };
+}
+
+impl_id! {UserId : i64}
+
+impl_id! {ChatId : i64}
+
+impl_id! {MessageId : i64}
+
+impl_id! {UpdateId : i64}
+
+/// Unique identifier for a file
+/// # Sending by file_id
+/// * It is not possible to change the file type when resending by **file_id**. I.e. a [video](Video) can't be sent as a photo, a [photo](PhotoSize) can't be sent as a document, etc.
+/// * It is not possible to resend thumbnails.
+/// * Resending a photo by **file_id** will send all of its [sizes](PhotoSize).
+/// * **file_id** is unique for each individual bot and can't be transferred from one bot to another.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct FileId(pub String);
+
+/// This object represents a unique message identifier.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MessageIdResult {
+    pub message_id: MessageId,
+}
+
+/// The result of an `editMessage*` method: the edited message, or True for an inline message.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum EditResult {
+    Message(Box<Message>),
+    Inline(bool),
+}
+
+impl EditResult {
+    /// The edited message, if it wasn't an inline one.
+    pub fn message(self) -> Option<Message> {
+        match self {
+            EditResult::Message(message) => Some(*message),
+            EditResult::Inline(_) => None,
+        }
+    }
+}
+
+/// A command of the bot, listed in the menu of its chats.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BotCommand {
+    /// Text of the command without the slash, 1-32 characters. Can contain only lowercase
+    /// English letters, digits and underscores.
+    pub command: String,
+    /// Description of the command, 1-256 characters.
+    pub description: String,
+}
+
+impl BotCommand {
+    pub fn new<C: Into<String>, D: Into<String>>(command: C, description: D) -> BotCommand {
+        BotCommand {
+            command: command.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// The UNIX timestamp
+#[cfg(not(feature = "chrono"))]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Time(pub u64);
+
+/// The Datetime.
+#[cfg(feature = "chrono")]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Time(
+    #[serde(with = "timestamp_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub NaiveDateTime,
+);
+
+#[cfg(feature = "chrono")]
+mod timestamp_format {
+    use crate::prelude::*;
+    use chrono::naive::NaiveDateTime;
+    use chrono::DateTime;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer};
+
+    #[cfg(feature = "full-serde")]
+    pub fn serialize<S>(date: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i64(date.and_utc().timestamp())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i64::deserialize(deserializer)?;
+        DateTime::from_timestamp(s, 0)
+            .map(|time| time.naive_utc())
+            .ok_or_else(|| D::Error::custom(format!("timestamp {} is out of range", s)))
+    }
+}
+
+impl Time {
+    /// Seconds since the UNIX epoch.
+    #[cfg(not(feature = "chrono"))]
+    pub fn unix(&self) -> u64 {
+        self.0
+    }
+
+    /// Seconds since the UNIX epoch.
+    #[cfg(feature = "chrono")]
+    pub fn unix(&self) -> u64 {
+        self.0.and_utc().timestamp().max(0) as u64
+    }
+
+    /// Time from seconds since the UNIX epoch.
+    #[cfg(not(feature = "chrono"))]
+    pub fn from_unix(secs: u64) -> Time {
+        Time(secs)
+    }
+
+    /// Time from seconds since the UNIX epoch.
+    ///
+    /// Saturates at the latest time `NaiveDateTime` can represent.
+    #[cfg(feature = "chrono")]
+    pub fn from_unix(secs: u64) -> Time {
+        let secs = secs.min(i64::MAX as u64) as i64;
+        Time(
+            chrono::DateTime::from_timestamp(secs, 0)
+                .map_or(NaiveDateTime::MAX, |time| time.naive_utc()),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<SystemTime> for Time {
+    /// Truncates to whole seconds; times before the epoch become the epoch.
+    fn from(time: SystemTime) -> Time {
+        Time::from_unix(time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Time> for SystemTime {
+    fn from(time: Time) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(time.unix())
+    }
+}
+
+/// Unrecognized fields of an object, kept with the `extra-fields` feature.
+#[cfg(feature = "extra-fields")]
+pub type ExtraFields = serde_json::Map<String, serde_json::Value>;
+
+/// Whether unknown fields are rejected, see the `strict` feature.
+const STRICT: bool = cfg!(all(feature = "strict", not(feature = "extra-fields")));
+
+/// Contains information about why a request was unsuccessful.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ResponseParameters {
+    /// *Optional*. The group has been migrated to a supergroup with the specified identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_to_chat_id: Option<ChatId>,
+    /// In case of exceeding flood control, the number of seconds left to wait before the request
+    /// can be repeated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<i64>,
+}
+
+/// Send *Markdown or HTML*, if you want Telegram apps to show
+/// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options)
+/// in the media caption.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ParseMode {
+    Markdown,
+    MarkdownV2,
+    HTML,
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
@@ -0,0 +1,105 @@
+//! Polls, quizzes and the answers to them.
+use super::{Chat, MessageEntity, Time, User};
+use crate::bot::utils::falsum;
+#[cfg(any(feature = "full-serde", feature = "schemars"))]
+use crate::bot::utils::is_false;
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A poll, in a message or as the new state of one in an update.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Poll {
+    /// Unique poll identifier
+    pub id: String,
+    /// Poll question, 1-300 characters
+    pub question: String,
+    /// Special entities that appear in the question, such as custom emoji
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub question_entities: Vec<MessageEntity>,
+    pub options: Vec<PollOption>,
+    /// Total number of users that voted in the poll
+    pub total_voter_count: i64,
+    pub is_closed: bool,
+    pub is_anonymous: bool,
+    #[serde(rename = "type")]
+    pub kind: PollType,
+    #[serde(default = "falsum")]
+    #[serde(skip_serializing_if = "is_false")]
+    pub allows_multiple_answers: bool,
+    /// 0-based index of the right option of a quiz. Only given for closed quizzes and to the bot
+    /// that sent the quiz.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct_option_id: Option<i64>,
+    /// Shown to users who choose a wrong option of a quiz or tap the lamp icon, 0-200 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub explanation_entities: Vec<MessageEntity>,
+    /// How long the poll is open after it was created, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<i64>,
+    /// When the poll closes by itself
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<Time>,
+}
+
+impl Poll {
+    pub fn is_quiz(&self) -> bool {
+        self.kind == PollType::Quiz
+    }
+}
+
+/// Kind of a [`Poll`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum PollType {
+    Regular,
+    /// A poll with one right option.
+    Quiz,
+    #[serde(other)]
+    /// Unknown upstream data type.
+    Unknown,
+}
+
+/// An answer option of a [`Poll`].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PollOption {
+    /// Option text, 1-100 characters
+    pub text: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub text_entities: Vec<MessageEntity>,
+    /// Number of users that voted for this option
+    pub voter_count: i64,
+}
+
+/// A vote in a non-anonymous [`Poll`] the bot sent.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PollAnswer {
+    pub poll_id: String,
+    /// The chat that voted, if the voter is anonymous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voter_chat: Option<Chat>,
+    /// The user that voted, if the voter isn't anonymous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// 0-based indexes of the chosen options, empty if the user retracted their vote
+    #[serde(default)]
+    pub option_ids: Vec<i64>,
+}
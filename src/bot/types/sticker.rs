@@ -0,0 +1,69 @@
+//! Stickers and sticker sets.
+use super::{FileId, PhotoSize};
+use crate::prelude::*;
+use serde::Deserialize;
+
+#[cfg(feature = "stickers")]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Sticker {
+    pub file_id: FileId,
+    pub width: i64,
+    pub height: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<PhotoSize>,
+    /// Emoji associated with the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    /// Name of the sticker set to which the sticker belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_name: Option<String>,
+    /// For mask stickers, the position where the mask should be placed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+    /// File size
+    pub file_size: i64,
+}
+
+/// A sticker set.
+#[cfg(feature = "stickers")]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StickerSet {
+    /// Sticker set name
+    pub name: String,
+    /// Sticker set title
+    pub title: String,
+    /// *True*, if the sticker set contains masks
+    pub contains_masks: bool,
+    /// List of all set stickers
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
+}
+
+/// The position on faces where a mask should be placed by default.
+#[cfg(feature = "stickers")]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MaskPosition {
+    /// The part of the face relative to which the mask should be placed. One of “forehead”, “eyes”,
+    /// “mouth”, or “chin”.
+    pub point: String,
+    /// Shift by X-axis measured in widths of the mask scaled to the face size, from left to right.
+    /// For example, choosing -1.0 will place mask just to the left of the default mask position.
+    pub x_shift: f32,
+    /// Shift by Y-axis measured in heights of the mask scaled to the face size, from top to bottom.
+    /// For example, 1.0 will place the mask just below the default mask position.
+    pub y_shift: f32,
+    /// Mask scaling coefficient. For example, 2.0 means double size.
+    pub scale: f32,
+}
@@ -0,0 +1,447 @@
+//! Updates received from Telegram.
+#[cfg(feature = "extra-fields")]
+use super::ExtraFields;
+#[cfg(feature = "payments")]
+use super::User;
+use super::STRICT;
+use super::{
+    CallbackQuery, ChatId, ChatJoinRequest, ChatMemberUpdated, Message, Poll, PollAnswer, UpdateId,
+};
+#[cfg(feature = "inline-mode")]
+use crate::bot::inline_mode::{ChosenInlineResult, InlineQuery};
+#[cfg(feature = "payments")]
+use crate::bot::payments::{OrderInfo, ShippingAddress};
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use core::fmt;
+#[cfg(feature = "json")]
+use serde::de::{DeserializeSeed, IntoDeserializer};
+use serde::de::{Error as DeError, IgnoredAny, MapAccess, Visitor};
+#[cfg(feature = "full-serde")]
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer};
+#[cfg(feature = "full-serde")]
+use serde::{Serialize, Serializer};
+#[cfg(feature = "json")]
+use serde_json::value::RawValue;
+
+/// An incoming update.
+///
+/// At most one of the optional parameters can be present in any given update.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Update {
+    /// The update‘s unique identifier.
+    pub update_id: UpdateId,
+    /// The content of the update, [`UpdateContent::Unknown`] if this crate doesn't know it.
+    pub content: UpdateContent,
+    /// Fields this crate doesn't know about yet, including unknown kinds of content.
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: ExtraFields,
+}
+
+// Written by hand instead of `#[serde(flatten)]`, which can't fall back to
+// `UpdateContent::Unknown` (https://github.com/serde-rs/serde/issues/1626).
+#[cfg(feature = "full-serde")]
+impl Serialize for Update {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use self::UpdateContent::*;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("update_id", &self.update_id)?;
+        match self.content {
+            Message(ref x) => map.serialize_entry("message", x)?,
+            EditedMessage(ref x) => map.serialize_entry("edited_message", x)?,
+            ChannelPost(ref x) => map.serialize_entry("channel_post", x)?,
+            EditedChannelPost(ref x) => map.serialize_entry("edited_channel_post", x)?,
+            #[cfg(feature = "inline-mode")]
+            InlineQuery(ref x) => map.serialize_entry("inline_query", x)?,
+            #[cfg(feature = "inline-mode")]
+            ChosenInlineResult(ref x) => map.serialize_entry("chosen_inline_result", x)?,
+            CallbackQuery(ref x) => map.serialize_entry("callback_query", x)?,
+            MyChatMember(ref x) => map.serialize_entry("my_chat_member", x)?,
+            ChatMember(ref x) => map.serialize_entry("chat_member", x)?,
+            ChatJoinRequest(ref x) => map.serialize_entry("chat_join_request", x)?,
+            #[cfg(feature = "payments")]
+            ShippingQuery(ref x) => map.serialize_entry("shipping_query", x)?,
+            #[cfg(feature = "payments")]
+            PreCheckoutQuery(ref x) => map.serialize_entry("pre_checkout_query", x)?,
+            Poll(ref x) => map.serialize_entry("poll", x)?,
+            PollAnswer(ref x) => map.serialize_entry("poll_answer", x)?,
+            Unknown => (),
+        }
+        #[cfg(feature = "extra-fields")]
+        for (key, value) in &self.extra {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+/// The wire shape of [`Update`], whose serde impls are written by hand.
+#[cfg(feature = "schemars")]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "Update")]
+#[allow(dead_code)]
+struct UpdateSchema {
+    update_id: UpdateId,
+    #[serde(flatten)]
+    content: UpdateContent,
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Update {
+    fn schema_name() -> Cow<'static, str> {
+        UpdateSchema::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        UpdateSchema::json_schema(generator)
+    }
+}
+
+struct UpdateVisitor;
+
+impl<'de> Visitor<'de> for UpdateVisitor {
+    type Value = Update;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an update object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Update, A::Error> {
+        use self::UpdateContent::*;
+        let mut update_id = None;
+        let mut content = None;
+        #[cfg(feature = "extra-fields")]
+        let mut extra = ExtraFields::new();
+        while let Some(key) = map.next_key::<Cow<'de, str>>()? {
+            if key == "update_id" {
+                update_id = Some(map.next_value()?);
+                continue;
+            }
+            if content.is_some() {
+                if STRICT {
+                    return Err(A::Error::custom(format!(
+                        "more than one content field, found `{}`",
+                        key
+                    )));
+                }
+                #[cfg(feature = "extra-fields")]
+                extra.insert(key.into_owned(), map.next_value()?);
+                #[cfg(not(feature = "extra-fields"))]
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            }
+            content = match &*key {
+                "message" => Some(Message(map.next_value()?)),
+                "edited_message" => Some(EditedMessage(map.next_value()?)),
+                "channel_post" => Some(ChannelPost(map.next_value()?)),
+                "edited_channel_post" => Some(EditedChannelPost(map.next_value()?)),
+                #[cfg(feature = "inline-mode")]
+                "inline_query" => Some(InlineQuery(map.next_value()?)),
+                #[cfg(feature = "inline-mode")]
+                "chosen_inline_result" => Some(ChosenInlineResult(map.next_value()?)),
+                "callback_query" => Some(CallbackQuery(map.next_value()?)),
+                "my_chat_member" => Some(MyChatMember(map.next_value()?)),
+                "chat_member" => Some(ChatMember(map.next_value()?)),
+                "chat_join_request" => Some(ChatJoinRequest(map.next_value()?)),
+                #[cfg(feature = "payments")]
+                "shipping_query" => Some(ShippingQuery(map.next_value()?)),
+                #[cfg(feature = "payments")]
+                "pre_checkout_query" => Some(PreCheckoutQuery(map.next_value()?)),
+                "poll" => Some(Poll(map.next_value()?)),
+                "poll_answer" => Some(PollAnswer(map.next_value()?)),
+                _ => {
+                    if STRICT {
+                        return Err(A::Error::unknown_field(&key, &["update_id"]));
+                    }
+                    #[cfg(feature = "extra-fields")]
+                    extra.insert(key.into_owned(), map.next_value()?);
+                    #[cfg(not(feature = "extra-fields"))]
+                    map.next_value::<IgnoredAny>()?;
+                    None
+                }
+            };
+        }
+        Ok(Update {
+            update_id: update_id.ok_or_else(|| A::Error::missing_field("update_id"))?,
+            content: content.unwrap_or_default(),
+            #[cfg(feature = "extra-fields")]
+            extra,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Update {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Update, D::Error> {
+        deserializer.deserialize_map(UpdateVisitor)
+    }
+}
+
+/// An [`Update`] with its content left unparsed.
+///
+/// Only `update_id` and the name of the content field are read, which is enough to route
+/// or acknowledge an update. Call [`parse`](RawUpdate::parse) for the full [`Update`].
+/// Fields after the content field are skipped and not kept by `parse`.
+///
+/// Deserialize from a `&str` or `&[u8]` that outlives the value.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone)]
+pub struct RawUpdate<'a> {
+    /// The update‘s unique identifier.
+    pub update_id: UpdateId,
+    /// The name of the content field is borrowed unless it has escapes.
+    content: Option<(Cow<'a, str>, &'a RawValue)>,
+}
+
+#[cfg(feature = "json")]
+impl<'a> RawUpdate<'a> {
+    /// Name of the content field, e.g. `"message"`, `None` if the update has none.
+    pub fn kind(&self) -> Option<&str> {
+        self.content.as_ref().map(|(kind, _)| &**kind)
+    }
+
+    /// The unparsed content.
+    pub fn content(&self) -> Option<&'a RawValue> {
+        self.content.as_ref().map(|&(_, content)| content)
+    }
+
+    /// Parse the content into a full [`Update`].
+    pub fn parse(&self) -> Result<Update, serde_json::Error> {
+        let content = self.content.as_ref();
+        UpdateVisitor.visit_map(RawUpdateAccess {
+            update_id: Some(self.update_id),
+            content: content.map(|(kind, content)| (&**kind, *content)),
+            value: None,
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'de: 'a, 'a> Deserialize<'de> for RawUpdate<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RawUpdate<'a>, D::Error> {
+        struct RawUpdateVisitor;
+
+        /// A key, borrowed from the input if it has no escapes.
+        #[derive(Deserialize)]
+        struct Key<'a>(#[serde(borrow)] Cow<'a, str>);
+
+        impl<'de> Visitor<'de> for RawUpdateVisitor {
+            type Value = RawUpdate<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an update object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<RawUpdate<'de>, A::Error> {
+                let mut update_id = None;
+                let mut content = None;
+                while let Some(Key(key)) = map.next_key()? {
+                    if key == "update_id" {
+                        update_id = Some(map.next_value()?);
+                    } else if content.is_none() {
+                        content = Some((key, map.next_value()?));
+                    } else {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+                Ok(RawUpdate {
+                    update_id: update_id.ok_or_else(|| A::Error::missing_field("update_id"))?,
+                    content,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(RawUpdateVisitor)
+    }
+}
+
+/// Feeds a [`RawUpdate`] back into [`UpdateVisitor`].
+#[cfg(feature = "json")]
+struct RawUpdateAccess<'k, 'a> {
+    update_id: Option<UpdateId>,
+    content: Option<(&'k str, &'a RawValue)>,
+    value: Option<&'a RawValue>,
+}
+
+#[cfg(feature = "json")]
+impl<'a> MapAccess<'a> for RawUpdateAccess<'_, 'a> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, serde_json::Error>
+    where
+        K: DeserializeSeed<'a>,
+    {
+        if self.update_id.is_some() {
+            return seed.deserialize("update_id".into_deserializer()).map(Some);
+        }
+        match self.content.take() {
+            Some((kind, content)) => {
+                self.value = Some(content);
+                seed.deserialize(kind.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, serde_json::Error>
+    where
+        V: DeserializeSeed<'a>,
+    {
+        match (self.update_id.take(), self.value.take()) {
+            (Some(UpdateId(id)), _) => seed.deserialize(id.into_deserializer()),
+            (None, Some(content)) => seed.deserialize(content),
+            (None, None) => Err(serde_json::Error::custom("value requested before key")),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateContent {
+    /// New incoming message of any kind — text, photo, sticker, etc.
+    Message(Message),
+    /// New version of a message that is known to the bot and was edited
+    EditedMessage(Message),
+    /// New incoming channel post of any kind — text, photo, sticker, etc.
+    ChannelPost(Message),
+    /// New version of a channel post that is known to the bot and was edited
+    EditedChannelPost(Message),
+    /// New incoming inline query
+    #[cfg(feature = "inline-mode")]
+    InlineQuery(InlineQuery),
+    /// The result of an [inline](https://core.telegram.org/bots/api#inline-mode) query that
+    /// was chosen by a user and sent to their chat partner.
+    ///
+    /// Please see our documentation on the
+    /// [feedback collecting](https://core.telegram.org/bots/inline#collecting-feedback) for
+    /// details on how to enable these updates for your bot.
+    #[cfg(feature = "inline-mode")]
+    ChosenInlineResult(ChosenInlineResult),
+    /// New incoming callback query
+    CallbackQuery(CallbackQuery),
+    /// The bot's chat member status was updated in a chat.
+    /// For private chats, this update is received only when the bot is blocked or unblocked by the user.
+    MyChatMember(ChatMemberUpdated),
+    /// A chat member's status was updated in a chat.
+    /// The bot must be an administrator in the chat and must explicitly
+    /// specify `"chat_member"` in the list of allowed_updates to receive these updates.
+    ChatMember(ChatMemberUpdated),
+    /// A request to join the chat has been sent.
+    /// The bot must have the `can_invite_users` administrator right in the chat to receive these updates.
+    ChatJoinRequest(ChatJoinRequest),
+    /// New incoming shipping query. Only for invoices with flexible price
+    #[cfg(feature = "payments")]
+    ShippingQuery(ShippingQuery),
+    /// New incoming pre-checkout query. Contains full information about checkout
+    #[cfg(feature = "payments")]
+    PreCheckoutQuery(PreCheckoutQuery),
+    /// New poll state. Bots receive only updates about stopped polls and polls they sent.
+    Poll(Poll),
+    /// A user changed their answer in a non-anonymous poll. Bots receive new votes only in
+    /// polls that were sent by the bot itself.
+    PollAnswer(PollAnswer),
+    /// Unknown update type
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    Unknown,
+}
+impl UpdateContent {
+    /// Name of the content field, e.g. `"message"`, `None` for [`Unknown`](UpdateContent::Unknown).
+    pub fn kind(&self) -> Option<&'static str> {
+        use self::UpdateContent::*;
+        Some(match self {
+            Message(_) => "message",
+            EditedMessage(_) => "edited_message",
+            ChannelPost(_) => "channel_post",
+            EditedChannelPost(_) => "edited_channel_post",
+            #[cfg(feature = "inline-mode")]
+            InlineQuery(_) => "inline_query",
+            #[cfg(feature = "inline-mode")]
+            ChosenInlineResult(_) => "chosen_inline_result",
+            CallbackQuery(_) => "callback_query",
+            MyChatMember(_) => "my_chat_member",
+            ChatMember(_) => "chat_member",
+            ChatJoinRequest(_) => "chat_join_request",
+            #[cfg(feature = "payments")]
+            ShippingQuery(_) => "shipping_query",
+            #[cfg(feature = "payments")]
+            PreCheckoutQuery(_) => "pre_checkout_query",
+            Poll(_) => "poll",
+            PollAnswer(_) => "poll_answer",
+            Unknown => return None,
+        })
+    }
+
+    /// The chat the update happened in: of the message, of the message with the pressed
+    /// button, or of the member or join request. `None` for updates outside a chat, such as
+    /// inline queries.
+    pub fn chat_id(&self) -> Option<ChatId> {
+        use self::UpdateContent::*;
+        match self {
+            Message(message)
+            | EditedMessage(message)
+            | ChannelPost(message)
+            | EditedChannelPost(message) => Some(message.chat.id),
+            CallbackQuery(query) => query.message.as_ref().map(|message| message.chat.id),
+            MyChatMember(updated) | ChatMember(updated) => Some(updated.chat.id),
+            ChatJoinRequest(request) => Some(request.chat.id),
+            _ => None,
+        }
+    }
+}
+
+impl Default for UpdateContent {
+    fn default() -> Self {
+        UpdateContent::Unknown {}
+    }
+}
+
+/// An incoming shipping query, for invoices asking for a shipping address whose price depends
+/// on it. Answer it with [`AnswerShippingQuery`](crate::bot::payments::AnswerShippingQuery).
+#[cfg(feature = "payments")]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ShippingQuery {
+    /// Unique query identifier
+    pub id: String,
+    /// User who sent the query
+    pub from: Box<User>,
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+    /// User specified shipping address
+    pub shipping_address: ShippingAddress,
+}
+
+/// An incoming pre-checkout query, with the full order. Answer it with
+/// [`AnswerPreCheckoutQuery`](crate::bot::payments::AnswerPreCheckoutQuery) within 10 seconds.
+#[cfg(feature = "payments")]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full-serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PreCheckoutQuery {
+    /// Unique query identifier
+    pub id: String,
+    /// User who sent the query
+    pub from: Box<User>,
+    /// Three-letter ISO 4217 currency code
+    pub currency: String,
+    /// Total price in the smallest units of the currency
+    pub total_amount: i64,
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+    /// Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_option_id: Option<String>,
+    /// Order information provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_info: Option<OrderInfo>,
+}
@@ -1,4 +1,39 @@
+#[cfg(feature = "arbitrary")]
+use alloc::borrow::Cow;
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+
 /// Simple serde helper function that always return false.
 pub(crate) fn falsum() -> bool {
     false
 }
+
+/// Simple serde helper function that skips serializing `false`.
+pub(crate) fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// Owned `Arbitrary` value for a borrowed field, which the derive can't produce.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_cow<'a, 'b, T>(u: &mut Unstructured<'a>) -> arbitrary::Result<Cow<'b, T>>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: Arbitrary<'a>,
+{
+    Ok(Cow::Owned(u.arbitrary()?))
+}
+
+/// [`arbitrary_cow`] for an optional field.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_optional_cow<'a, 'b, T>(
+    u: &mut Unstructured<'a>,
+) -> arbitrary::Result<Option<Cow<'b, T>>>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: Arbitrary<'a>,
+{
+    Ok(match u.arbitrary()? {
+        true => Some(arbitrary_cow(u)?),
+        false => None,
+    })
+}
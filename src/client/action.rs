@@ -0,0 +1,62 @@
+//! Keeping a chat action such as "typing…" shown while the bot works on an answer.
+use super::Execute;
+use crate::bot::methods::{ChatAction, ChatTarget, SendChatAction};
+use std::future::{poll_fn, Future};
+use std::pin::pin;
+use std::task::Poll;
+use std::time::Duration;
+
+/// How often [`keep_chat_action`] sends the action again. Telegram shows it for 5 seconds.
+pub const CHAT_ACTION_INTERVAL: Duration = Duration::from_secs(4);
+
+/// Run `work`, showing `action` in `chat` until it finishes.
+///
+/// The action is sent if `work` doesn't finish at once, then again every
+/// [`CHAT_ACTION_INTERVAL`], waiting with `sleep`, the runtime's timer such as
+/// `tokio::time::sleep`. Failing to send it doesn't affect `work`. Dropping the returned future
+/// drops `work` and stops the action, which Telegram clears a few seconds later or as soon as
+/// the bot sends a message.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn run(bot: telegram_types::client::Bot<reqwest::Client>) {
+/// use telegram_types::bot::methods::{ChatAction, ChatTarget};
+/// use telegram_types::client::keep_chat_action;
+///
+/// let chat = ChatTarget::id(42);
+/// let work = async { /* a slow answer */ };
+/// keep_chat_action(&bot, chat, ChatAction::Typing, tokio::time::sleep, work).await;
+/// # }
+/// ```
+pub async fn keep_chat_action<E, S, F, W>(
+    bot: &E,
+    chat: ChatTarget<'_>,
+    action: ChatAction,
+    sleep: S,
+    work: W,
+) -> W::Output
+where
+    E: Execute,
+    S: Fn(Duration) -> F,
+    F: Future<Output = ()>,
+    W: Future,
+{
+    let send = SendChatAction::new(chat, action);
+    let keep_alive = async {
+        loop {
+            let _ = bot.execute(&send).await;
+            sleep(CHAT_ACTION_INTERVAL).await;
+        }
+    };
+    let mut work = pin!(work);
+    let mut keep_alive = pin!(keep_alive);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = work.as_mut().poll(cx) {
+            return Poll::Ready(output);
+        }
+        // Never finishes, it only runs while `work` does.
+        let _ = keep_alive.as_mut().poll(cx);
+        Poll::Pending
+    })
+    .await
+}
@@ -0,0 +1,522 @@
+//! A synchronous [`Bot`], for scripts and command line tools that don't run an async runtime.
+//!
+//! It builds and decodes requests like the async [`client::Bot`](super::Bot); only the
+//! [`Transport`] blocks. With the `blocking` feature `ureq::Agent` is one, and [`Bot::new`]
+//! uses it.
+//!
+//! ```no_run
+//! use telegram_types::bot::methods::{ChatTarget, SendMessage};
+//! use telegram_types::client::blocking::Bot;
+//!
+//! let bot = Bot::new(std::env::var("BOT_TOKEN").unwrap());
+//! bot.call(&SendMessage::new(ChatTarget::id(42), "backup done")).unwrap();
+//! ```
+use super::offset::Confirm;
+use super::polling::{Dedup, Reconnect};
+use super::{
+    decode_response, Attachment, BotInfo, Error, FileLocation, MetricsSink, OffsetStore, Request,
+    Response, RetryPolicy, Server, StopHandle, Timeouts, DEFAULT_POLL_TIMEOUT,
+};
+use crate::bot::methods::{
+    ChatTarget, Close, DeleteWebhook, GetFile, GetMe, GetUpdates, GetUserProfilePhotos, LogOut,
+    MediaGroupUpload, Method, UpdateTypes,
+};
+use crate::bot::types::{File, Message, PhotoSize, Update, UpdateId, UserId};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::error::Error as StdError;
+use std::fmt;
+use std::io::{self, Read};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+/// An HTTP client that can send a [`Request`], blocking until the response is read.
+pub trait Transport {
+    type Error: StdError + Send + Sync + 'static;
+
+    /// POST [`body`](Request::body) to [`url`](Request::url) with its
+    /// [`content_type`](Request::content_type) and [`headers`](Request::headers), and return the response whatever its status.
+    /// A request without a body is a GET, see [`Request::get`].
+    fn send(&self, request: Request) -> Result<Response, Self::Error>;
+}
+
+impl Transport for ureq::Agent {
+    type Error = UreqError;
+
+    fn send(&self, request: Request) -> Result<Response, UreqError> {
+        let mut call = if request.is_get() {
+            self.get(&request.url)
+        } else {
+            self.post(&request.url)
+                .set("Content-Type", &request.content_type)
+        };
+        for (name, value) in &request.headers {
+            call = call.set(name, value);
+        }
+        if let Some(timeout) = request.timeout {
+            call = call.timeout(timeout);
+        }
+        let sent = if request.is_get() {
+            call.call()
+        } else {
+            call.send_bytes(&request.body)
+        };
+        let response = match sent {
+            Ok(response) => response,
+            // Telegram's errors come with a 4xx or 5xx status, the body is still wanted.
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(e)) => return Err(UreqError::Transport(e)),
+        };
+        let status = response.status();
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(UreqError::Io)?;
+        Ok(Response { status, body })
+    }
+}
+
+/// Why the ureq [`Transport`] failed.
+///
+/// Unlike ureq's own errors it doesn't print the URL, which holds the token.
+pub enum UreqError {
+    /// The request couldn't be sent or its response wasn't received, see
+    /// [`ureq::Transport::kind`].
+    Transport(ureq::Transport),
+    /// Reading the body of the response failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for UreqError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UreqError::Transport(e) => {
+                write!(f, "{}", e.kind())?;
+                if let Some(message) = e.message() {
+                    write!(f, ": {}", message)?;
+                }
+                if let Some(source) = e.source() {
+                    write!(f, ": {}", source)?;
+                }
+                Ok(())
+            }
+            UreqError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl fmt::Debug for UreqError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Keep the URL, and so the token, out of logs.
+        match self {
+            UreqError::Transport(e) => f
+                .debug_struct("Transport")
+                .field("kind", &e.kind())
+                .field("message", &e.message())
+                .field("source", &e.source())
+                .finish_non_exhaustive(),
+            UreqError::Io(e) => f.debug_tuple("Io").field(e).finish(),
+        }
+    }
+}
+
+impl StdError for UreqError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            UreqError::Transport(e) => e.source(),
+            UreqError::Io(e) => Some(e),
+        }
+    }
+}
+
+/// A bot token and the blocking [`Transport`] its requests go through.
+#[derive(Clone)]
+pub struct Bot<T> {
+    token: String,
+    server: Server,
+    timeouts: Arc<Timeouts>,
+    /// The bot's account, once asked for.
+    info: Arc<OnceLock<BotInfo>>,
+    transport: T,
+}
+
+impl Bot<ureq::Agent> {
+    /// A bot with a new ureq agent. Clones of it share the agent and its connection pool.
+    pub fn new<S: Into<String>>(token: S) -> Bot<ureq::Agent> {
+        Bot::with_transport(token, ureq::Agent::new())
+    }
+}
+
+impl<T> Bot<T> {
+    /// Send requests through `transport`, for another HTTP stack or a preconfigured client.
+    pub fn with_transport<S: Into<String>>(token: S, transport: T) -> Bot<T> {
+        Bot {
+            token: token.into(),
+            server: Server::default(),
+            timeouts: Arc::default(),
+            info: Arc::default(),
+            transport,
+        }
+    }
+
+    /// Send requests to another server, such as a
+    /// [local Bot API server](https://github.com/tdlib/telegram-bot-api), keeping the environment
+    /// and mode of the current one.
+    pub fn api_url<S: Into<String>>(self, api_url: S) -> Bot<T> {
+        let server = self.server.clone().at(api_url);
+        Bot { server, ..self }
+    }
+
+    /// Send requests to `server`, for a local server in `--local` mode or the test environment.
+    pub fn server(self, server: Server) -> Bot<T> {
+        Bot { server, ..self }
+    }
+
+    /// Limit how long calls may take, by method.
+    pub fn timeouts(self, timeouts: Timeouts) -> Bot<T> {
+        Bot {
+            timeouts: Arc::new(timeouts),
+            ..self
+        }
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Where the content of `file` is, see [`Server::file_location`].
+    pub fn file_location(&self, file: &File) -> Option<FileLocation> {
+        self.server.file_location(&self.token, file)
+    }
+
+    /// The request sending `method` would make.
+    pub fn request<M: Method>(&self, method: &M) -> Result<Request, Error> {
+        let mut request = Request::new(self.server.url(), &self.token, method)?;
+        request.url = self.server.method_url(&self.token, M::NAME);
+        request.timeout = self.timeouts.get(method, false);
+        Ok(request)
+    }
+
+    /// The request sending `method` with `files` would make.
+    pub fn upload_request<M: Method>(
+        &self,
+        method: &M,
+        files: &[Attachment],
+    ) -> Result<Request, Error> {
+        self.server.check_upload(files)?;
+        let mut request = Request::multipart(self.server.url(), &self.token, method, files)?;
+        request.url = self.server.method_url(&self.token, M::NAME);
+        request.timeout = self.timeouts.get(method, true);
+        Ok(request)
+    }
+}
+
+impl<T: Transport> Bot<T> {
+    /// Send `method` and read the result.
+    pub fn call<M: Method>(&self, method: &M) -> Result<M::Item, Error> {
+        let request = self.request(method)?;
+        self.send::<M>(request, method.message_chat())
+    }
+
+    /// The bot's account, as [`client::Bot::info`](super::Bot::info).
+    pub fn info(&self) -> Result<BotInfo, Error> {
+        if let Some(info) = self.info.get() {
+            return Ok(info.clone());
+        }
+        let info = BotInfo::new(self.call(&GetMe)?);
+        Ok(self.info.get_or_init(|| info).clone())
+    }
+
+    /// Send `method` as a multipart form with `files`, which its
+    /// [`InputFile`](crate::bot::types::InputFile)s refer to with `attach://<name>`.
+    pub fn upload<M: Method>(&self, method: &M, files: &[Attachment]) -> Result<M::Item, Error> {
+        let request = self.upload_request(method, files)?;
+        self.send::<M>(request, method.message_chat())
+    }
+
+    /// Leave the current server for `server` and return the bot calling it, as
+    /// [`client::Bot::move_to`](super::Bot::move_to).
+    pub fn move_to(&self, server: Server) -> Result<Bot<T>, Error>
+    where
+        T: Clone,
+    {
+        if self.server.is_local() {
+            self.call(&DeleteWebhook)?;
+            self.call(&Close)?;
+        } else {
+            self.call(&LogOut)?;
+        }
+        Ok(self.clone().server(server))
+    }
+
+    /// The content of the file at `location`, as [`client::Bot::download`](super::Bot::download).
+    pub fn download(&self, location: &FileLocation) -> Result<Vec<u8>, Error> {
+        let url = match location {
+            FileLocation::Url(url) => url,
+            FileLocation::Path(path) => return std::fs::read(path).map_err(Error::File),
+        };
+        let response = self
+            .transport
+            .send(Request::get(url.clone()))
+            .map_err(|e| Error::Transport(Box::new(e)))?;
+        if !(200..300).contains(&response.status) {
+            return Err(Error::Status(response.status));
+        }
+        Ok(response.body)
+    }
+
+    /// The newest profile picture of `user` at `size` pixels and its content, as
+    /// [`client::Bot::profile_photo`](super::Bot::profile_photo).
+    pub fn profile_photo(
+        &self,
+        user: UserId,
+        size: i64,
+    ) -> Result<Option<(PhotoSize, Vec<u8>)>, Error> {
+        let photos = self.call(&GetUserProfilePhotos::new(user).limit(1))?;
+        let photo = match photos.newest(size) {
+            Some(photo) => photo.clone(),
+            None => return Ok(None),
+        };
+        let file = self.call(&GetFile::new(photo.file_id.0.as_str()))?;
+        let location = match self.file_location(&file) {
+            Some(location) => location,
+            None => return Ok(None),
+        };
+        let content = self.download(&location)?;
+        Ok(Some((photo, content)))
+    }
+
+    /// Build `group` and send it, as a multipart form if it has files to upload.
+    pub fn send_media_group(&self, group: MediaGroupUpload) -> Result<Vec<Message>, Error> {
+        let (method, files) = group.build()?;
+        if files.is_empty() {
+            self.call(&method)
+        } else {
+            self.upload(&method, &files)
+        }
+    }
+
+    fn send<M: Method>(
+        &self,
+        request: Request,
+        chat: Option<&ChatTarget>,
+    ) -> Result<M::Item, Error> {
+        let call = || {
+            let response = self
+                .transport
+                .send(request)
+                .map_err(|e| Error::Transport(Box::new(e)))?;
+            decode_response::<M>(&response)
+        };
+        #[cfg(feature = "tracing")]
+        {
+            let span = crate::trace::CallSpan::new(M::NAME, chat);
+            let result = span.span().in_scope(call);
+            span.finish(&result);
+            result
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = chat;
+            call()
+        }
+    }
+}
+
+impl<T> fmt::Debug for Bot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Keep the token out of logs.
+        f.debug_struct("Bot")
+            .field("server", &self.server)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Updates received by calling [`GetUpdates`] again and again, the blocking counterpart of
+/// [`UpdateStream`](super::UpdateStream).
+///
+/// A failed call is yielded as an error and the next call to `next` tries again, at once unless
+/// the iterator [`reconnect`](Updates::reconnect)s after a backoff, so the iterator only ends
+/// after a [`StopHandle`] is stopped.
+///
+/// ```no_run
+/// use telegram_types::client::blocking::{Bot, Updates};
+///
+/// let bot = Bot::new(std::env::var("BOT_TOKEN").unwrap());
+/// for update in Updates::new(bot.clone()) {
+///     match update {
+///         Ok(update) => println!("{:?}", update.content),
+///         Err(e) => eprintln!("{}", e),
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Updates<T> {
+    bot: Bot<T>,
+    get_updates: GetUpdates<'static>,
+    buffer: VecDeque<Update>,
+    stop: StopHandle,
+    confirm: Confirm,
+    dedup: Option<Dedup>,
+    reconnect: Reconnect,
+    metrics: Option<Arc<dyn MetricsSink>>,
+}
+
+impl<T: Transport> Updates<T> {
+    /// Long poll with a timeout of [`DEFAULT_POLL_TIMEOUT`] seconds.
+    pub fn new(bot: Bot<T>) -> Updates<T> {
+        Updates {
+            bot,
+            get_updates: GetUpdates {
+                timeout: Some(DEFAULT_POLL_TIMEOUT),
+                ..GetUpdates::new()
+            },
+            buffer: VecDeque::new(),
+            stop: StopHandle::default(),
+            confirm: Confirm::default(),
+            dedup: None,
+            reconnect: Reconnect::default(),
+            metrics: None,
+        }
+    }
+
+    /// How long Telegram holds each call open waiting for updates, in seconds.
+    pub fn timeout(mut self, seconds: i64) -> Updates<T> {
+        self.get_updates.timeout = Some(seconds);
+        self
+    }
+
+    /// At most this many updates per call, 1-100.
+    pub fn limit(mut self, limit: u32) -> Updates<T> {
+        self.get_updates.limit = Some(limit);
+        self
+    }
+
+    /// Only these kinds of updates. Telegram keeps the setting for later calls.
+    pub fn allowed_updates(mut self, updates: Vec<UpdateTypes>) -> Updates<T> {
+        self.get_updates.allowed_updates = Some(Cow::Owned(updates));
+        self
+    }
+
+    /// Start from `offset`, such as one saved from [`offset`](Updates::offset) before a
+    /// restart.
+    pub fn start_at(mut self, offset: UpdateId) -> Updates<T> {
+        self.get_updates.offset(offset);
+        self
+    }
+
+    /// The offset of the next call: one past the last update received.
+    pub fn offset(&self) -> Option<UpdateId> {
+        self.get_updates.offset
+    }
+
+    /// Start from the offset saved in `store`, unless [`start_at`](Updates::start_at) sets
+    /// one, and save the offset past each update once the next one is asked for. A failure to
+    /// load or save is yielded as [`Error::Offset`].
+    pub fn offset_store(mut self, store: Arc<dyn OffsetStore>) -> Updates<T> {
+        self.confirm = Confirm::new(store);
+        self
+    }
+
+    /// Skip updates whose id is among the last `window` yielded, in case Telegram delivers
+    /// some again, such as after a restart from an older offset.
+    pub fn dedup(mut self, window: usize) -> Updates<T> {
+        self.dedup = Some(Dedup::new(window));
+        self
+    }
+
+    /// Call again after network errors, timeouts and 5xx responses instead of yielding them,
+    /// sleeping as `policy` says. A stop ends the sleep.
+    ///
+    /// After [`max_retries`](RetryPolicy::max_retries) failures in a row, and for errors the
+    /// policy doesn't retry such as a revoked token, the error is yielded and the count starts
+    /// over.
+    pub fn reconnect(mut self, policy: RetryPolicy) -> Updates<T> {
+        self.reconnect.policy = Some(policy);
+        self
+    }
+
+    /// Call `f` with each failure retried by [`reconnect`](Updates::reconnect) and the wait
+    /// before the next call.
+    pub fn on_error<F>(mut self, f: F) -> Updates<T>
+    where
+        F: Fn(&Error, Duration) + Send + Sync + 'static,
+    {
+        self.reconnect.on_error = Some(Arc::new(f));
+        self
+    }
+
+    /// A handle that ends the iterator from another thread, such as a signal handler.
+    pub fn stop_handle(&self) -> StopHandle {
+        self.stop.clone()
+    }
+
+    /// Report the number of updates each call returns to `sink`.
+    pub fn metrics(mut self, sink: Arc<dyn MetricsSink>) -> Updates<T> {
+        self.metrics = Some(sink);
+        self
+    }
+}
+
+impl<T> Updates<T> {
+    /// Sleep for `delay` or until stopped.
+    fn sleep(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        while !self.stop.is_stopped() {
+            let left = until.saturating_duration_since(Instant::now());
+            if left == Duration::ZERO {
+                break;
+            }
+            std::thread::sleep(left.min(Duration::from_millis(100)));
+        }
+    }
+}
+
+impl<T: Transport> Iterator for Updates<T> {
+    type Item = Result<Update, Error>;
+
+    fn next(&mut self) -> Option<Result<Update, Error>> {
+        if let Err(e) = self.confirm.confirm(&mut self.get_updates) {
+            return Some(Err(e));
+        }
+        loop {
+            if let Some(update) = self.buffer.pop_front() {
+                if let Some(dedup) = &mut self.dedup {
+                    if !dedup.insert(update.update_id) {
+                        continue;
+                    }
+                }
+                #[cfg(feature = "tracing")]
+                crate::trace::update_received(&update, "polling");
+                self.confirm.yielded(update.update_id);
+                return Some(Ok(update));
+            }
+            if self.stop.is_stopped() {
+                return None;
+            }
+            let updates = match self.bot.call(&self.get_updates) {
+                Ok(updates) => updates,
+                Err(e) => match self.reconnect.delay(&e) {
+                    Some(delay) => {
+                        self.sleep(delay);
+                        continue;
+                    }
+                    None => return Some(Err(e)),
+                },
+            };
+            self.reconnect.succeeded();
+            if let Some(sink) = &self.metrics {
+                sink.updates_received(updates.len());
+            }
+            if let Some(last) = updates.last() {
+                self.get_updates.offset(last.update_id + 1);
+            }
+            self.buffer.extend(updates);
+        }
+    }
+}
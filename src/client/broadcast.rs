@@ -0,0 +1,101 @@
+//! Sending one message to many chats.
+use super::{Error, Execute, MaybeSend, Throttle};
+use crate::bot::methods::{ApiError, ChatTarget, Method};
+use crate::bot::types::ChatId;
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+/// What came of each chat of a [`Throttle::broadcast`].
+pub struct BroadcastReport<T> {
+    pub delivered: Vec<(ChatId, T)>,
+    /// Chats that can't get messages from the bot any more, see
+    /// [`ApiError::is_chat_unreachable`]. Remove them from the list.
+    pub unreachable: Vec<(ChatId, ApiError)>,
+    /// Groups that became supergroups, with the id of the supergroup. Send to it instead.
+    pub migrated: Vec<(ChatId, ChatId)>,
+    /// Other failures, which may pass on a later try.
+    pub failed: Vec<(ChatId, Error)>,
+}
+
+impl<T> BroadcastReport<T> {
+    fn new() -> BroadcastReport<T> {
+        BroadcastReport {
+            delivered: Vec::new(),
+            unreachable: Vec::new(),
+            migrated: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, chat: ChatId, result: Result<T, Error>) {
+        let error = match result {
+            Ok(item) => return self.delivered.push((chat, item)),
+            Err(Error::Api(error)) => error,
+            Err(e) => return self.failed.push((chat, e)),
+        };
+        let migrate_to = error.parameters.as_ref().and_then(|p| p.migrate_to_chat_id);
+        if let Some(supergroup) = migrate_to {
+            self.migrated.push((chat, supergroup));
+        } else if error.is_chat_unreachable() {
+            self.unreachable.push((chat, error));
+        } else {
+            self.failed.push((chat, Error::Api(error)));
+        }
+    }
+}
+
+impl<T> fmt::Debug for BroadcastReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BroadcastReport")
+            .field("delivered", &self.delivered.len())
+            .field("unreachable", &self.unreachable.len())
+            .field("migrated", &self.migrated)
+            .field("failed", &self.failed)
+            .finish()
+    }
+}
+
+impl<E, S, F> Throttle<E, S>
+where
+    E: Execute + Sync,
+    S: Fn(Duration) -> F + Sync,
+    F: Future<Output = ()> + MaybeSend,
+{
+    /// Send the method `build` makes for each of `chats`, one after the other under the rate
+    /// limits, and sort out the results.
+    ///
+    /// Failures don't stop the broadcast. Wrap the inner bot in a [`Retry`](super::Retry) to
+    /// sleep through flood waits and retry network errors. For more throughput, split the chats
+    /// between tasks sharing this `Throttle`.
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "reqwest")]
+    /// # async fn run(subscribers: Vec<telegram_types::bot::types::ChatId>) {
+    /// use telegram_types::bot::methods::SendMessage;
+    /// use telegram_types::client::{Bot, Retry, Throttle};
+    ///
+    /// let bot = Retry::new(Bot::new(std::env::var("BOT_TOKEN").unwrap()), tokio::time::sleep);
+    /// let bot = Throttle::new(bot, tokio::time::sleep);
+    /// let report = bot
+    ///     .broadcast(subscribers, |chat| SendMessage::new(chat, "New issue out!"))
+    ///     .await;
+    /// for (chat, _) in &report.unreachable {
+    ///     println!("unsubscribe {}", chat.0);
+    /// }
+    /// # }
+    /// ```
+    pub async fn broadcast<M, I, B>(&self, chats: I, mut build: B) -> BroadcastReport<M::Item>
+    where
+        M: Method + Sync,
+        I: IntoIterator<Item = ChatId>,
+        B: FnMut(ChatTarget<'static>) -> M,
+    {
+        let mut report = BroadcastReport::new();
+        for chat in chats {
+            let method = build(ChatTarget::Id(chat));
+            report.add(chat, self.execute(&method).await);
+        }
+        report
+    }
+}
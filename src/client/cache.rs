@@ -0,0 +1,155 @@
+//! Reusing the answers of getters that change rarely.
+use super::{Middleware, Request, Response, Transport};
+use crate::bot::methods::ChatTarget;
+use crate::bot::types::{Update, UpdateContent, UserId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// The methods a [`Cache`] answers.
+const CACHED: [&str; 4] = ["getMe", "getChat", "getChatAdministrators", "getChatMember"];
+
+/// A [`Middleware`] answering `getMe`, `getChat`, `getChatAdministrators` and `getChatMember`
+/// from the responses of the same calls in the last `ttl`, such as for moderation bots looking
+/// up who is an administrator on every message.
+///
+/// Only successful responses are kept, an error is asked again next time. Clones share the
+/// entries, so keep one to [`invalidate`](Cache::invalidate_chat) them when the bot learns of
+/// a change, or pass each update to [`observe`](Cache::observe). Entries are kept per bot, so
+/// bots may share one, and keyed by the `chat_id` as sent: a chat looked up by `@username` and
+/// by id is two entries.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # fn run() {
+/// use std::time::Duration;
+/// use telegram_types::client::{Bot, Cache, Layered};
+///
+/// let cache = Cache::new(Duration::from_secs(60));
+/// let transport = Layered::new(reqwest::Client::new(), cache.clone());
+/// let bot = Bot::with_transport(std::env::var("BOT_TOKEN").unwrap(), transport);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cache {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<Key, Entry>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Key {
+    /// The whole URL, with the bot's token: clones may be shared between bots.
+    url: String,
+    method: String,
+    /// The `chat_id` as JSON.
+    chat: Option<String>,
+    /// The `user_id` as JSON.
+    user: Option<String>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    response: Response,
+    expires: Instant,
+}
+
+impl Cache {
+    pub fn new(ttl: Duration) -> Cache {
+        Cache {
+            ttl,
+            entries: Arc::default(),
+        }
+    }
+
+    /// Forget what is known about `chat`: the chat, its administrators and its members.
+    pub fn invalidate_chat(&self, chat: &ChatTarget) {
+        let chat = chat_key(chat);
+        self.lock()
+            .retain(|key, _| key.chat.as_ref() != Some(&chat));
+    }
+
+    /// Forget `user` as a member of `chat`, and the administrators of `chat`, which they may
+    /// have joined or left.
+    pub fn invalidate_member(&self, chat: &ChatTarget, user: UserId) {
+        let chat = chat_key(chat);
+        let user = serde_json::to_string(&user).unwrap_or_default();
+        self.lock().retain(|key, _| {
+            key.chat.as_ref() != Some(&chat)
+                || (key.method == "getChatMember" && key.user.as_ref() != Some(&user))
+                || key.method == "getChat"
+        });
+    }
+
+    /// Forget changes `update` reports: a member's status, or the bot's own.
+    pub fn observe(&self, update: &Update) {
+        if let UpdateContent::ChatMember(updated) | UpdateContent::MyChatMember(updated) =
+            &update.content
+        {
+            let chat = ChatTarget::Id(updated.chat.id);
+            self.invalidate_member(&chat, updated.new_chat_member.user.id);
+        }
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Number of responses kept, including expired ones not yet dropped.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Key, Entry>> {
+        // Entries are replaced whole, a panic elsewhere doesn't leave one half written.
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn chat_key(chat: &ChatTarget) -> String {
+    serde_json::to_string(chat).unwrap_or_default()
+}
+
+/// The key of `request` if it is a call to cache.
+fn key(request: &Request) -> Option<Key> {
+    let method = request.url.rsplit('/').next()?;
+    if !CACHED.contains(&method) || request.content_type != Request::JSON {
+        return None;
+    }
+    let params: serde_json::Value = serde_json::from_slice(&request.body).ok()?;
+    Some(Key {
+        url: request.url.clone(),
+        method: method.to_string(),
+        chat: params.get("chat_id").map(|chat| chat.to_string()),
+        user: params.get("user_id").map(|user| user.to_string()),
+    })
+}
+
+impl<T: Transport + Sync> Middleware<T> for Cache {
+    async fn call(&self, request: Request, next: &T) -> Result<Response, T::Error> {
+        let key = match key(&request) {
+            Some(key) => key,
+            None => return next.send(request).await,
+        };
+        let now = Instant::now();
+        if let Some(entry) = self.lock().get(&key) {
+            if entry.expires > now {
+                return Ok(entry.response.clone());
+            }
+        }
+        let response = next.send(request).await?;
+        if response.status == 200 {
+            let mut entries = self.lock();
+            entries.retain(|_, entry| entry.expires > now);
+            let entry = Entry {
+                response: response.clone(),
+                expires: now + self.ttl,
+            };
+            entries.insert(key, entry);
+        }
+        Ok(response)
+    }
+}
@@ -0,0 +1,419 @@
+//! Routing updates to handlers by their kind.
+use super::{BotInfo, BoxFuture, MaybeSend};
+#[cfg(feature = "inline-mode")]
+use crate::bot::inline_mode::{ChosenInlineResult, InlineQuery};
+use crate::bot::methods::SetMyCommands;
+use crate::bot::text::Command;
+use crate::bot::types::{
+    BotCommand, CallbackQuery, ChatId, ChatJoinRequest, ChatMemberUpdated, Message,
+    MessageEntityKind, PollAnswer, Update, UpdateContent,
+};
+#[cfg(feature = "payments")]
+use crate::bot::types::{PreCheckoutQuery, ShippingQuery};
+use futures_core::Stream;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+
+type Handler<C, T> = Box<dyn Fn(C, T) -> BoxFuture<()> + Send + Sync>;
+
+/// A dispatch in progress, borrowing the dispatcher.
+#[cfg(not(target_arch = "wasm32"))]
+type Running<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+#[cfg(target_arch = "wasm32")]
+type Running<'a> = Pin<Box<dyn Future<Output = ()> + 'a>>;
+
+fn handler<C, T, F, Fut>(f: F) -> Handler<C, T>
+where
+    F: Fn(C, T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + MaybeSend + 'static,
+{
+    Box::new(move |context, content| Box::pin(f(context, content)))
+}
+
+macro_rules! dispatcher {
+    ($($(#[cfg($cfg:meta)])? $(#[doc = $doc:expr])* $on:ident: $variant:ident($ty:ty),)*) => {
+        /// Async handlers for each kind of [`UpdateContent`], and a fallback for the rest.
+        ///
+        /// Each handler is called with a context, such as the bot to answer with, and the content
+        /// of the update. Updates of a kind without a handler, including
+        /// [`UpdateContent::Unknown`], go to the [`fallback`](Dispatcher::fallback) whole, or are
+        /// dropped if there is none. Handlers deal with their own errors. Messages with a
+        /// command go to its handler in [`Commands`], if the dispatcher has them.
+        ///
+        /// ```no_run
+        /// # #[cfg(feature = "reqwest")]
+        /// # async fn run() {
+        /// use futures_util::StreamExt;
+        /// use telegram_types::bot::methods::{ChatTarget, SendMessage};
+        /// use telegram_types::client::{Bot, Dispatcher, UpdateStream};
+        ///
+        /// let bot = Bot::new(std::env::var("BOT_TOKEN").unwrap());
+        /// let dispatcher = Dispatcher::new()
+        ///     .on_message(|bot: Bot<reqwest::Client>, message| async move {
+        ///         let text = message.text.unwrap_or_default();
+        ///         let echo = SendMessage::new(ChatTarget::Id(message.chat.id), text);
+        ///         if let Err(e) = bot.call(&echo).await {
+        ///             eprintln!("{}", e);
+        ///         }
+        ///     })
+        ///     .fallback(|_, update| async move { println!("unhandled: {:?}", update.content) });
+        /// let mut updates = UpdateStream::new(bot.clone());
+        /// while let Some(Ok(update)) = updates.next().await {
+        ///     dispatcher.dispatch(bot.clone(), update).await;
+        /// }
+        /// # }
+        /// ```
+        pub struct Dispatcher<C> {
+            $($(#[cfg($cfg)])? $on: Option<Handler<C, $ty>>,)*
+            commands: Option<Commands<C>>,
+            fallback: Option<Handler<C, Update>>,
+        }
+
+        impl<C: 'static> Dispatcher<C> {
+            /// A dispatcher without handlers.
+            pub fn new() -> Dispatcher<C> {
+                Dispatcher {
+                    $($(#[cfg($cfg)])? $on: None,)*
+                    commands: None,
+                    fallback: None,
+                }
+            }
+
+            $(
+                $(#[cfg($cfg)])?
+                $(#[doc = $doc])*
+                pub fn $on<F, Fut>(self, f: F) -> Dispatcher<C>
+                where
+                    F: Fn(C, $ty) -> Fut + Send + Sync + 'static,
+                    Fut: Future<Output = ()> + MaybeSend + 'static,
+                {
+                    Dispatcher {
+                        $on: Some(handler(f)),
+                        ..self
+                    }
+                }
+            )*
+
+            /// Handle messages starting with one of `commands` with its handler, before
+            /// [`on_message`](Dispatcher::on_message).
+            pub fn on_commands(self, commands: Commands<C>) -> Dispatcher<C> {
+                Dispatcher {
+                    commands: Some(commands),
+                    ..self
+                }
+            }
+
+            /// Handle updates no other handler takes.
+            pub fn fallback<F, Fut>(self, f: F) -> Dispatcher<C>
+            where
+                F: Fn(C, Update) -> Fut + Send + Sync + 'static,
+                Fut: Future<Output = ()> + MaybeSend + 'static,
+            {
+                Dispatcher {
+                    fallback: Some(handler(f)),
+                    ..self
+                }
+            }
+
+            async fn route(&self, context: C, update: Update) {
+                let command = match (&self.commands, &update.content) {
+                    (Some(commands), UpdateContent::Message(message)) => commands.find(message),
+                    _ => None,
+                };
+                if let Some((command, args)) = command {
+                    if let UpdateContent::Message(message) = update.content {
+                        return (command.handler)(context, message, args).await;
+                    }
+                }
+                match update.content {
+                    $(
+                        $(#[cfg($cfg)])?
+                        UpdateContent::$variant(content) if self.$on.is_some() => {
+                            return self.$on.as_ref().unwrap()(context, content).await
+                        }
+                    )*
+                    _ => {}
+                }
+                if let Some(fallback) = &self.fallback {
+                    fallback(context, update).await
+                }
+            }
+        }
+
+        impl<C> fmt::Debug for Dispatcher<C> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut handlers = Vec::new();
+                $(
+                    $(#[cfg($cfg)])?
+                    if self.$on.is_some() {
+                        handlers.push(stringify!($on));
+                    }
+                )*
+                if self.fallback.is_some() {
+                    handlers.push("fallback");
+                }
+                f.debug_struct("Dispatcher")
+                    .field("handlers", &handlers)
+                    .field("commands", &self.commands)
+                    .finish()
+            }
+        }
+    };
+}
+
+dispatcher! {
+    /// Handle new messages.
+    on_message: Message(Message),
+    /// Handle new versions of messages.
+    on_edited_message: EditedMessage(Message),
+    /// Handle new channel posts.
+    on_channel_post: ChannelPost(Message),
+    /// Handle new versions of channel posts.
+    on_edited_channel_post: EditedChannelPost(Message),
+    #[cfg(feature = "inline-mode")]
+    /// Handle inline queries.
+    on_inline_query: InlineQuery(InlineQuery),
+    #[cfg(feature = "inline-mode")]
+    /// Handle chosen inline results.
+    on_chosen_inline_result: ChosenInlineResult(ChosenInlineResult),
+    /// Handle callback queries.
+    on_callback_query: CallbackQuery(CallbackQuery),
+    /// Handle changes to the bot's own membership.
+    on_my_chat_member: MyChatMember(ChatMemberUpdated),
+    /// Handle changes to other members, which have to be in
+    /// [`allowed_updates`](crate::bot::methods::GetUpdates::allowed_updates).
+    on_chat_member: ChatMember(ChatMemberUpdated),
+    /// Handle requests to join a chat.
+    on_chat_join_request: ChatJoinRequest(ChatJoinRequest),
+    #[cfg(feature = "payments")]
+    /// Handle shipping queries, for invoices with a flexible price.
+    on_shipping_query: ShippingQuery(ShippingQuery),
+    #[cfg(feature = "payments")]
+    /// Handle pre-checkout queries, which have to be answered within 10 seconds.
+    on_pre_checkout_query: PreCheckoutQuery(PreCheckoutQuery),
+    /// Handle new states of stopped polls and polls the bot sent.
+    on_poll: Poll(crate::bot::types::Poll),
+    /// Handle changed answers in non-anonymous polls the bot sent.
+    on_poll_answer: PollAnswer(PollAnswer),
+}
+
+impl<C: 'static> Dispatcher<C> {
+    /// Call the handler for `update`.
+    pub async fn dispatch(&self, context: C, update: Update) {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+            let span = crate::trace::update_span(&update);
+            self.route(context, update).instrument(span).await
+        }
+        #[cfg(not(feature = "tracing"))]
+        self.route(context, update).await
+    }
+}
+
+impl<C: Clone + MaybeSend + 'static> Dispatcher<C> {
+    /// Dispatch `updates` until the stream ends, the updates of different chats concurrently
+    /// and those of one chat in order, each once the handler of the one before has finished.
+    ///
+    /// A conversation then sees its messages in the order they were sent, while a slow handler
+    /// only holds up its own chat. Updates without a chat, such as inline queries, are handled
+    /// as they come. At most `limit` updates are handled or waiting at a time; the stream isn't
+    /// polled for more until one finishes. The handlers run within the returned future, spawn
+    /// tasks from them to use more threads.
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "reqwest")]
+    /// # async fn run() {
+    /// use futures_util::StreamExt;
+    /// use telegram_types::client::{Bot, Dispatcher, UpdateStream};
+    ///
+    /// let dispatcher = Dispatcher::new().on_message(|bot: Bot<reqwest::Client>, message| {
+    ///     async move { /* the next message of the chat waits for this */ }
+    /// });
+    ///
+    /// let bot = Bot::new(std::env::var("BOT_TOKEN").unwrap());
+    /// let updates = UpdateStream::new(bot.clone()).filter_map(|update| async { update.ok() });
+    /// dispatcher.dispatch_by_chat(bot, Box::pin(updates), 100).await;
+    /// # }
+    /// ```
+    pub async fn dispatch_by_chat<S>(&self, context: C, mut updates: S, limit: usize)
+    where
+        S: Stream<Item = Update> + Unpin,
+    {
+        let limit = limit.max(1);
+        let start = |update| -> Running { Box::pin(self.dispatch(context.clone(), update)) };
+        let mut ended = false;
+        let mut held = 0;
+        // Updates waiting behind the running one of their chat.
+        let mut waiting: HashMap<ChatId, VecDeque<Update>> = HashMap::new();
+        let mut running: Vec<(Option<ChatId>, Running)> = Vec::new();
+        poll_fn(|cx| loop {
+            while !ended && held < limit {
+                let update = match Pin::new(&mut updates).poll_next(cx) {
+                    Poll::Ready(Some(update)) => update,
+                    Poll::Ready(None) => {
+                        ended = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                };
+                held += 1;
+                let chat = update.content.chat_id();
+                match chat.map(|chat| waiting.entry(chat)) {
+                    Some(Entry::Occupied(mut queue)) => queue.get_mut().push_back(update),
+                    Some(Entry::Vacant(queue)) => {
+                        queue.insert(VecDeque::new());
+                        running.push((chat, start(update)));
+                    }
+                    None => running.push((None, start(update))),
+                }
+            }
+            let mut finished = false;
+            let mut i = 0;
+            while i < running.len() {
+                if running[i].1.as_mut().poll(cx).is_pending() {
+                    i += 1;
+                    continue;
+                }
+                finished = true;
+                held -= 1;
+                // The last one takes its place, and is polled next.
+                let (chat, _) = running.swap_remove(i);
+                if let Some(chat) = chat {
+                    match waiting.get_mut(&chat).and_then(VecDeque::pop_front) {
+                        Some(next) => running.push((Some(chat), start(next))),
+                        None => {
+                            waiting.remove(&chat);
+                        }
+                    }
+                }
+            }
+            if ended && running.is_empty() {
+                return Poll::Ready(());
+            }
+            // Room for more updates, take them before waiting.
+            if !(finished && !ended && held < limit) {
+                return Poll::Pending;
+            }
+        })
+        .await
+    }
+}
+
+impl<C: 'static> Default for Dispatcher<C> {
+    fn default() -> Dispatcher<C> {
+        Dispatcher::new()
+    }
+}
+
+type CommandHandler<C> = Box<dyn Fn(C, Message, String) -> BoxFuture<()> + Send + Sync>;
+
+/// Handlers for bot commands such as `/start`, for [`Dispatcher::on_commands`].
+///
+/// A message is taken by the command it starts with, `/name` or `/name@username`, compared
+/// without regard to case. A command with another bot's username is left to the other
+/// handlers. The handler gets the message and the text after the command, trimmed.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn run() -> Result<(), telegram_types::client::Error> {
+/// use telegram_types::client::{Bot, Commands, Dispatcher};
+///
+/// let bot = Bot::new(std::env::var("BOT_TOKEN").unwrap());
+/// let commands = Commands::for_bot(&bot.info().await?)
+///     .command("start", "Say hello", |bot: Bot<reqwest::Client>, message, _| async move {})
+///     .command("roll", "Roll dice, /roll 2d6", |bot, message, args| async move {});
+/// bot.call(&commands.set_my_commands()).await?;
+/// let dispatcher = Dispatcher::new().on_commands(commands);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Commands<C> {
+    username: String,
+    commands: Vec<CommandRoute<C>>,
+}
+
+struct CommandRoute<C> {
+    command: BotCommand,
+    handler: CommandHandler<C>,
+}
+
+impl<C> Commands<C> {
+    /// Commands of the bot with `username`, with or without the `@`.
+    pub fn new<S: Into<String>>(username: S) -> Commands<C> {
+        let username = username.into();
+        Commands {
+            username: username.trim_start_matches('@').to_string(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Commands of the bot `info` is about, see [`Bot::info`](super::Bot::info).
+    pub fn for_bot(info: &BotInfo) -> Commands<C> {
+        Commands::new(info.username())
+    }
+
+    /// Handle `/name`, described by `description` in the menu.
+    pub fn command<N, D, F, Fut>(mut self, name: N, description: D, f: F) -> Commands<C>
+    where
+        N: Into<String>,
+        D: Into<String>,
+        F: Fn(C, Message, String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + MaybeSend + 'static,
+    {
+        let name = name.into();
+        self.commands.push(CommandRoute {
+            command: BotCommand::new(name.trim_start_matches('/'), description),
+            handler: Box::new(move |context, message, args| Box::pin(f(context, message, args))),
+        });
+        self
+    }
+
+    /// The commands with their descriptions, to show them in the menu of the bot's chats.
+    pub fn set_my_commands(&self) -> SetMyCommands<'static> {
+        SetMyCommands::new(
+            self.commands
+                .iter()
+                .map(|route| route.command.clone())
+                .collect(),
+        )
+    }
+
+    /// The command `message` starts with and the text after it.
+    fn find(&self, message: &Message) -> Option<(&CommandRoute<C>, String)> {
+        let text = message.text_or_caption()?;
+        let entity = message.entities_or_caption_entities().first()?;
+        if entity.kind != MessageEntityKind::BotCommand || entity.offset.0 != 0 {
+            return None;
+        }
+        let range = entity.byte_range(text)?;
+        let command = Command::parse(&text[range.clone()])?;
+        match command.username {
+            Some(username) if !username.eq_ignore_ascii_case(&self.username) => return None,
+            _ => {}
+        }
+        let route = self
+            .commands
+            .iter()
+            .find(|route| route.command.command.eq_ignore_ascii_case(command.name))?;
+        Some((route, text[range.end..].trim().to_string()))
+    }
+}
+
+impl<C> fmt::Debug for Commands<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<_> = self
+            .commands
+            .iter()
+            .map(|route| &route.command.command)
+            .collect();
+        f.debug_struct("Commands")
+            .field("username", &self.username)
+            .field("commands", &names)
+            .finish()
+    }
+}
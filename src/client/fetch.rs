@@ -0,0 +1,68 @@
+//! [`Transport`] over the JavaScript `fetch` API.
+use super::{Request, Response, Transport};
+use js_sys::{Function, Promise, Reflect, Uint8Array};
+use std::error::Error as StdError;
+use std::fmt;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+/// Sends requests with the global `fetch` function, in browsers, web workers and JavaScript
+/// runtimes like Cloudflare Workers and Deno. It only works on `wasm32` targets.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fetch;
+
+impl Transport for Fetch {
+    type Error = FetchError;
+
+    async fn send(&self, request: Request) -> Result<Response, FetchError> {
+        fetch(request).await.map_err(FetchError::from)
+    }
+}
+
+async fn fetch(request: Request) -> Result<Response, JsValue> {
+    let headers = web_sys::Headers::new()?;
+    for (name, value) in &request.headers {
+        headers.set(name, value)?;
+    }
+    let init = web_sys::RequestInit::new();
+    if request.is_get() {
+        init.set_method("GET");
+    } else {
+        headers.set("Content-Type", &request.content_type)?;
+        init.set_method("POST");
+        init.set_body(&Uint8Array::from(&request.body[..]));
+    }
+    init.set_headers(&headers);
+    let request = web_sys::Request::new_with_str_and_init(&request.url, &init)?;
+    // `window.fetch` doesn't exist in workers, the global `fetch` does everywhere.
+    let global = js_sys::global();
+    let fetch: Function = Reflect::get(&global, &"fetch".into())?.dyn_into()?;
+    let promise: Promise = fetch.call1(&global, &request)?.dyn_into()?;
+    let response: web_sys::Response = JsFuture::from(promise).await?.dyn_into()?;
+    let body = JsFuture::from(response.array_buffer()?).await?;
+    Ok(Response {
+        status: response.status(),
+        body: Uint8Array::new(&body).to_vec(),
+    })
+}
+
+/// The exception `fetch` threw, as a string since JavaScript values can't leave their thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchError(pub String);
+
+impl From<JsValue> for FetchError {
+    fn from(value: JsValue) -> FetchError {
+        match value.as_string() {
+            Some(message) => FetchError(message),
+            None => FetchError(format!("{:?}", value)),
+        }
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fetch failed: {}", self.0)
+    }
+}
+
+impl StdError for FetchError {}
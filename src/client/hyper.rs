@@ -0,0 +1,71 @@
+//! [`Transport`] for hyper.
+use super::{Request, Response, Transport};
+use hyper::client::connect::Connect;
+use hyper::{Body, Client};
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::Future;
+
+impl<C> Transport for Client<C, Body>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    type Error = HyperError;
+
+    fn send(&self, request: Request) -> impl Future<Output = Result<Response, HyperError>> + Send {
+        let timeout = request.timeout;
+        let response = request
+            .into_http()
+            .map(|request| self.request(request.map(Body::from)));
+        let call = async move {
+            let response = response.map_err(HyperError::Request)?.await?;
+            let status = response.status().as_u16();
+            let body = hyper::body::to_bytes(response.into_body()).await?.to_vec();
+            Ok(Response { status, body })
+        };
+        async move {
+            match timeout {
+                Some(timeout) => tokio::time::timeout(timeout, call)
+                    .await
+                    .map_err(|_| HyperError::Timeout)?,
+                None => call.await,
+            }
+        }
+    }
+}
+
+/// Why the hyper [`Transport`] failed.
+#[derive(Debug)]
+pub enum HyperError {
+    /// The request couldn't be built, the API URL is invalid.
+    Request(http::Error),
+    Hyper(hyper::Error),
+    /// The call took longer than its [`timeout`](super::Request::timeout).
+    Timeout,
+}
+
+impl fmt::Display for HyperError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HyperError::Request(e) => write!(f, "invalid request: {}", e),
+            HyperError::Hyper(e) => e.fmt(f),
+            HyperError::Timeout => f.write_str("request timed out"),
+        }
+    }
+}
+
+impl StdError for HyperError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            HyperError::Request(e) => Some(e),
+            HyperError::Hyper(e) => Some(e),
+            HyperError::Timeout => None,
+        }
+    }
+}
+
+impl From<hyper::Error> for HyperError {
+    fn from(e: hyper::Error) -> HyperError {
+        HyperError::Hyper(e)
+    }
+}
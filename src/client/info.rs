@@ -0,0 +1,103 @@
+//! The bot's own account, for what depends on who the bot is.
+use super::{Error, Execute};
+use crate::bot::methods::GetMe;
+use crate::bot::text::Command;
+use crate::bot::types::{User, UserId};
+
+/// The bot's account from `getMe`: its id, its username and what it may do.
+///
+/// [`Bot::info`](super::Bot::info) calls `getMe` once and keeps the result. With it,
+/// [`Commands::for_bot`](super::Commands::for_bot) knows which `/command@username` are for the
+/// bot, and links to open the bot are built without passing its username around.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn run(bot: telegram_types::client::Bot<reqwest::Client>) -> Option<()> {
+/// let info = bot.info().await.ok()?;
+/// let invite = info.start_link("ref_42")?;
+/// assert_eq!(invite, format!("https://t.me/{}?start=ref_42", info.username()));
+/// # None
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BotInfo {
+    user: User,
+}
+
+impl BotInfo {
+    pub fn new(user: User) -> BotInfo {
+        BotInfo { user }
+    }
+
+    /// Call `getMe`. [`Bot::info`](super::Bot::info) keeps the result, call that instead.
+    pub async fn fetch<E: Execute>(bot: &E) -> Result<BotInfo, Error> {
+        bot.execute(&GetMe).await.map(BotInfo::new)
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    pub fn id(&self) -> UserId {
+        self.user.id
+    }
+
+    /// The bot's username, without the `@`. Every bot has one.
+    pub fn username(&self) -> &str {
+        self.user.username.as_deref().unwrap_or_default()
+    }
+
+    /// Whether the bot may be added to groups.
+    pub fn can_join_groups(&self) -> bool {
+        self.user.can_join_groups.unwrap_or(false)
+    }
+
+    /// Whether the bot gets all messages in groups, not only commands and replies to it.
+    pub fn can_read_all_group_messages(&self) -> bool {
+        self.user.can_read_all_group_messages.unwrap_or(false)
+    }
+
+    pub fn supports_inline_queries(&self) -> bool {
+        self.user.supports_inline_queries.unwrap_or(false)
+    }
+
+    /// Whether `command` is for this bot: it has no username, or the bot's.
+    pub fn is_for(&self, command: &Command) -> bool {
+        match command.username {
+            Some(username) => username.eq_ignore_ascii_case(self.username()),
+            None => true,
+        }
+    }
+
+    /// `https://t.me/<username>`, opening a chat with the bot.
+    pub fn link(&self) -> String {
+        format!("https://t.me/{}", self.username())
+    }
+
+    /// A link opening a chat with the bot, which sends `/start <payload>` when the user presses
+    /// Start. `None` if `payload` isn't 1-64 letters, digits, `_` and `-`, as Telegram requires.
+    pub fn start_link(&self, payload: &str) -> Option<String> {
+        deep_link_payload(payload).map(|payload| format!("{}?start={}", self.link(), payload))
+    }
+
+    /// A link to add the bot to a group, where it gets `/start <payload>`, under the same
+    /// conditions as [`start_link`](BotInfo::start_link).
+    pub fn start_group_link(&self, payload: &str) -> Option<String> {
+        let payload = deep_link_payload(payload)?;
+        Some(format!("{}?startgroup={}", self.link(), payload))
+    }
+}
+
+impl From<User> for BotInfo {
+    fn from(user: User) -> BotInfo {
+        BotInfo::new(user)
+    }
+}
+
+fn deep_link_payload(payload: &str) -> Option<&str> {
+    let allowed = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    if payload.is_empty() || payload.len() > 64 || !payload.chars().all(allowed) {
+        return None;
+    }
+    Some(payload)
+}
@@ -0,0 +1,109 @@
+//! Counting calls and updates for monitoring.
+use super::{Error, Execute};
+use crate::bot::methods::Method;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Where the client reports what it did, such as a Prometheus registry or a StatsD client.
+///
+/// [`Metrics`] and [`Retry::metrics`](super::Retry::metrics) report calls, and
+/// [`UpdateStream::metrics`](super::UpdateStream::metrics) and the blocking
+/// [`Updates::metrics`](super::blocking::Updates::metrics) the updates they receive. Both
+/// methods do nothing by default.
+pub trait MetricsSink: Send + Sync {
+    /// A call finished.
+    fn call(&self, call: &CallMetrics) {
+        let _ = call;
+    }
+
+    /// A long polling call returned `count` updates.
+    fn updates_received(&self, count: usize) {
+        let _ = count;
+    }
+}
+
+impl<T: MetricsSink + ?Sized> MetricsSink for Arc<T> {
+    fn call(&self, call: &CallMetrics) {
+        (**self).call(call)
+    }
+
+    fn updates_received(&self, count: usize) {
+        (**self).updates_received(count)
+    }
+}
+
+impl fmt::Debug for dyn MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
+
+/// A finished call, as reported to a [`MetricsSink`].
+#[derive(Debug, Clone, Copy)]
+pub struct CallMetrics<'a> {
+    /// Name of the method, e.g. `"sendMessage"`.
+    pub method: &'static str,
+    pub outcome: Result<(), &'a Error>,
+    /// From the first try to the result, including the time spent waiting to retry.
+    pub latency: Duration,
+    /// Retries after the first try.
+    pub retries: u32,
+}
+
+impl CallMetrics<'_> {
+    /// The error code of a failed call: Telegram's, or the HTTP status of a response that
+    /// isn't a Bot API one.
+    pub fn error_code(&self) -> Option<i32> {
+        match self.outcome {
+            Err(Error::Api(e)) => Some(e.error_code),
+            Err(Error::Status(status)) => Some(i32::from(*status)),
+            _ => None,
+        }
+    }
+}
+
+/// Report `result` of a call started at `start`.
+pub(crate) fn report<T>(
+    sink: &dyn MetricsSink,
+    method: &'static str,
+    start: Instant,
+    result: &Result<T, Error>,
+    retries: u32,
+) {
+    sink.call(&CallMetrics {
+        method,
+        outcome: result.as_ref().map(|_| ()),
+        latency: start.elapsed(),
+        retries,
+    });
+}
+
+/// An [`Execute`] reporting each call of another one to a [`MetricsSink`].
+///
+/// Calls are reported without retries, use [`Retry::metrics`](super::Retry::metrics) to count
+/// them.
+#[derive(Debug, Clone)]
+pub struct Metrics<E> {
+    inner: E,
+    sink: Arc<dyn MetricsSink>,
+}
+
+impl<E> Metrics<E> {
+    pub fn new(inner: E, sink: Arc<dyn MetricsSink>) -> Metrics<E> {
+        Metrics { inner, sink }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+}
+
+impl<E: Execute + Sync> Execute for Metrics<E> {
+    async fn execute<M: Method + Sync>(&self, method: &M) -> Result<M::Item, Error> {
+        let start = Instant::now();
+        let result = self.inner.execute(method).await;
+        report(&*self.sink, M::NAME, start, &result, 0);
+        result
+    }
+}
@@ -0,0 +1,115 @@
+//! Hooks around the requests of a transport.
+use super::{MaybeSend, Request, Response, Transport};
+use std::future::Future;
+
+/// Code run around each request of a [`Transport`], with [`Layered`].
+///
+/// A middleware gets the request and the transport to send it with. It can change the request
+/// or its [`headers`](Request::headers), look at the response, send it again or answer without
+/// sending at all. [`MapRequest`] and [`InspectResponse`] cover the common cases with a
+/// closure.
+///
+/// ```
+/// use telegram_types::client::{Middleware, Request, Response, Transport};
+///
+/// /// Send once more after a 502 from a flaky proxy.
+/// struct RetryBadGateway;
+///
+/// impl<T: Transport + Sync> Middleware<T> for RetryBadGateway {
+///     async fn call(&self, request: Request, next: &T) -> Result<Response, T::Error> {
+///         let response = next.send(request.clone()).await?;
+///         if response.status != 502 {
+///             return Ok(response);
+///         }
+///         next.send(request).await
+///     }
+/// }
+/// ```
+pub trait Middleware<T: Transport> {
+    fn call(
+        &self,
+        request: Request,
+        next: &T,
+    ) -> impl Future<Output = Result<Response, T::Error>> + MaybeSend;
+}
+
+/// A [`Transport`] that sends its requests through a [`Middleware`] to another one.
+///
+/// Layers nest, the outermost middleware sees a request first and its response last.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # fn run() {
+/// use telegram_types::client::{Bot, Layered, MapRequest, Request};
+///
+/// let transport = Layered::new(
+///     reqwest::Client::new(),
+///     MapRequest(|request: &mut Request| {
+///         let auth = ("Proxy-Authorization".to_string(), "Basic dXNlcjpwYXNz".to_string());
+///         request.headers.push(auth);
+///     }),
+/// );
+/// let bot = Bot::with_transport(std::env::var("BOT_TOKEN").unwrap(), transport);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Layered<T, M> {
+    inner: T,
+    middleware: M,
+}
+
+impl<T, M> Layered<T, M> {
+    pub fn new(inner: T, middleware: M) -> Layered<T, M> {
+        Layered { inner, middleware }
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn middleware(&self) -> &M {
+        &self.middleware
+    }
+}
+
+impl<T: Transport, M: Middleware<T>> Transport for Layered<T, M> {
+    type Error = T::Error;
+
+    fn send(
+        &self,
+        request: Request,
+    ) -> impl Future<Output = Result<Response, T::Error>> + MaybeSend {
+        self.middleware.call(request, &self.inner)
+    }
+}
+
+/// A [`Middleware`] changing each request before it is sent.
+#[derive(Debug, Clone, Copy)]
+pub struct MapRequest<F>(pub F);
+
+impl<T, F> Middleware<T> for MapRequest<F>
+where
+    T: Transport + Sync,
+    F: Fn(&mut Request) + Sync,
+{
+    async fn call(&self, mut request: Request, next: &T) -> Result<Response, T::Error> {
+        (self.0)(&mut request);
+        next.send(request).await
+    }
+}
+
+/// A [`Middleware`] looking at each result of the transport, such as to log failures.
+#[derive(Debug, Clone, Copy)]
+pub struct InspectResponse<F>(pub F);
+
+impl<T, F> Middleware<T> for InspectResponse<F>
+where
+    T: Transport + Sync,
+    F: Fn(&Result<Response, T::Error>) + Sync,
+{
+    async fn call(&self, request: Request, next: &T) -> Result<Response, T::Error> {
+        let result = next.send(request).await;
+        (self.0)(&result);
+        result
+    }
+}
@@ -0,0 +1,167 @@
+//! A transport answering with canned responses, for tests.
+use super::{decode_response, Error, Execute, Request, Response, Transport};
+use crate::bot::methods::Method;
+#[cfg(feature = "full-serde")]
+use crate::bot::methods::TelegramResult;
+use std::collections::{HashMap, VecDeque};
+#[cfg(feature = "full-serde")]
+use std::convert::TryFrom;
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A [`Transport`] and [`Execute`] that answers calls with the responses it is told to expect,
+/// and records them, to test handlers without calling Telegram.
+///
+/// Each call takes the oldest response expected for its method. A call with none left fails
+/// with a [`MockError`], as a transport error. Clones share their expectations and calls, so
+/// the mock can be given to a [`Bot`](super::Bot) and checked afterwards.
+///
+/// ```
+/// # async fn run() {
+/// use telegram_types::bot::methods::{ChatTarget, SendMessage, TelegramResult};
+/// use telegram_types::client::{Execute, MockTransport};
+///
+/// let mock = MockTransport::new();
+/// mock.expect("sendMessage", TelegramResult::<()>::error(403, "Forbidden: bot was blocked"));
+/// let send = SendMessage::new(ChatTarget::id(42), "hello");
+/// assert!(mock.execute(&send).await.is_err());
+/// assert_eq!(mock.calls()[0].params["text"], "hello");
+/// assert!(mock.pending().is_empty());
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    responses: HashMap<String, VecDeque<Response>>,
+    calls: Vec<MockCall>,
+}
+
+/// A call received by a [`MockTransport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// Name of the method, e.g. `"sendMessage"`.
+    pub method: String,
+    /// The parameters sent as JSON, `Null` for a multipart upload.
+    pub params: serde_json::Value,
+}
+
+impl MockTransport {
+    /// A mock expecting no calls.
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    /// Answer the next call of `method` with `response`.
+    #[cfg(feature = "full-serde")]
+    pub fn expect<T: serde::Serialize>(&self, method: &str, response: TelegramResult<T>) {
+        let status = match response.error_code {
+            Some(code) if !response.ok => u16::try_from(code).unwrap_or(400),
+            _ => 200,
+        };
+        let body = serde_json::to_vec(&response).expect("response should serialize");
+        self.respond(method, Response { status, body });
+    }
+
+    /// Answer the next call of `method` with a raw `response`, such as an error page or a
+    /// malformed body.
+    pub fn respond(&self, method: &str, response: Response) {
+        self.state()
+            .responses
+            .entry(method.to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// The calls received so far, oldest first.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state().calls.clone()
+    }
+
+    /// Names of the methods with expected responses that weren't used, one per response.
+    pub fn pending(&self) -> Vec<String> {
+        let state = self.state();
+        let mut pending: Vec<_> = state
+            .responses
+            .iter()
+            .flat_map(|(method, responses)| responses.iter().map(move |_| method.clone()))
+            .collect();
+        pending.sort();
+        pending
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // A test that panicked while holding the lock has already failed.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn answer(&self, method: &str, params: serde_json::Value) -> Result<Response, MockError> {
+        let mut state = self.state();
+        state.calls.push(MockCall {
+            method: method.to_string(),
+            params,
+        });
+        state
+            .responses
+            .get_mut(method)
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| MockError {
+                method: method.to_string(),
+            })
+    }
+}
+
+impl Transport for MockTransport {
+    type Error = MockError;
+
+    async fn send(&self, request: Request) -> Result<Response, MockError> {
+        let method = request.url.rsplit('/').next().unwrap_or_default();
+        let params = if request.content_type == Request::JSON {
+            serde_json::from_slice(&request.body).unwrap_or_default()
+        } else {
+            serde_json::Value::Null
+        };
+        self.answer(method, params)
+    }
+}
+
+impl Execute for MockTransport {
+    async fn execute<M: Method + Sync>(&self, method: &M) -> Result<M::Item, Error> {
+        let params = serde_json::to_value(method).map_err(Error::Json)?;
+        let response = self
+            .answer(M::NAME, params)
+            .map_err(|e| Error::Transport(Box::new(e)))?;
+        decode_response::<M>(&response)
+    }
+}
+
+impl fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = self.state();
+        f.debug_struct("MockTransport")
+            .field("calls", &state.calls.len())
+            .field(
+                "pending",
+                &state.responses.values().map(VecDeque::len).sum::<usize>(),
+            )
+            .finish()
+    }
+}
+
+/// A [`MockTransport`] got a call it had no response for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockError {
+    pub method: String,
+}
+
+impl fmt::Display for MockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected call of {}", self.method)
+    }
+}
+
+impl StdError for MockError {}
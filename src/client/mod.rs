@@ -0,0 +1,511 @@
+//! An async client that sends [`Method`]s to the Bot API.
+//!
+//! The HTTP side is a [`Transport`], which only has to POST a JSON body, or GET a file, and return
+//! the response.
+//! URL building, serialization and decoding the result are shared, so a transport for another
+//! HTTP stack is a few lines. With the `reqwest` feature `reqwest::Client` is one, and
+//! [`Bot::new`] uses it. With `hyper-client` `hyper::Client` is one. The `blocking` feature adds
+//! a synchronous bot in [`blocking`]. A [`Middleware`] wraps a transport to change its requests
+//! or look at its responses. [`MockTransport`] answers with canned responses, for tests.
+//!
+//! The client also builds for `wasm32`, where futures don't have to be `Send` and the `fetch`
+//! feature adds `Fetch`, a transport over the JavaScript `fetch` API.
+//!
+//! ```no_run
+//! # #[cfg(feature = "reqwest")]
+//! # async fn run() -> Result<(), telegram_types::client::Error> {
+//! use telegram_types::bot::methods::{ChatTarget, SendMessage};
+//! use telegram_types::client::Bot;
+//!
+//! let bot = Bot::new(std::env::var("BOT_TOKEN").unwrap());
+//! let message = bot.call(&SendMessage::new(ChatTarget::id(42), "hello")).await?;
+//! # Ok(())
+//! # }
+//! ```
+mod action;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(not(target_arch = "wasm32"))]
+mod broadcast;
+#[cfg(not(target_arch = "wasm32"))]
+mod cache;
+mod dispatch;
+#[cfg(all(feature = "fetch", target_arch = "wasm32"))]
+mod fetch;
+#[cfg(feature = "hyper-client")]
+mod hyper;
+mod info;
+#[cfg(not(target_arch = "wasm32"))]
+mod metrics;
+mod middleware;
+mod mock;
+mod offset;
+pub use self::action::{keep_chat_action, CHAT_ACTION_INTERVAL};
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::broadcast::BroadcastReport;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::cache::Cache;
+pub use self::dispatch::{Commands, Dispatcher};
+#[cfg(all(feature = "fetch", target_arch = "wasm32"))]
+pub use self::fetch::{Fetch, FetchError};
+#[cfg(feature = "hyper-client")]
+pub use self::hyper::HyperError;
+pub use self::info::BotInfo;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::metrics::{CallMetrics, Metrics, MetricsSink};
+pub use self::middleware::{InspectResponse, Layered, MapRequest, Middleware};
+pub use self::mock::{MockCall, MockError, MockTransport};
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::offset::FileOffsetStore;
+pub use self::offset::{MemoryOffsetStore, OffsetStore};
+pub use self::polling::{StopHandle, UpdateStream};
+pub use self::queue::{queue, Overflow, QueueFeed, QueuedUpdates};
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+pub use self::reqwest::BotBuilder;
+pub use self::retry::{Retry, RetryPolicy};
+pub use self::server::{FileLocation, Server};
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::throttle::{RateLimits, Throttle};
+pub use self::timeout::Timeouts;
+mod multipart;
+mod polling;
+mod queue;
+#[cfg(feature = "reqwest")]
+mod reqwest;
+mod retry;
+mod server;
+mod shortcuts;
+#[cfg(not(target_arch = "wasm32"))]
+mod throttle;
+mod timeout;
+
+use crate::bot::methods::{
+    ApiError, ChatTarget, Close, DeleteWebhook, GetFile, GetUserProfilePhotos, LogOut,
+    MediaGroupError, MediaGroupUpload, Method,
+};
+pub use crate::bot::types::Attachment;
+use crate::bot::types::{File, Message, PhotoSize, UserId};
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+/// The public Bot API server.
+pub const DEFAULT_API_URL: &str = crate::bot::API_URL;
+
+/// How long each long polling call waits for updates by default, in seconds.
+pub const DEFAULT_POLL_TIMEOUT: i64 = 30;
+
+/// A method call ready to be sent: where to, and the body, JSON or a multipart form with files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub url: String,
+    /// Content type of [`body`](Request::body).
+    pub content_type: String,
+    /// Other headers, none unless a [`Middleware`] adds them.
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// How long the call may take, from [`Timeouts`]. `None` for no limit but the transport's.
+    pub timeout: Option<Duration>,
+}
+
+impl Request {
+    /// Content type of JSON bodies.
+    pub const JSON: &'static str = "application/json";
+
+    /// A request sending `method` as JSON.
+    pub fn new<M: Method>(api_url: &str, token: &str, method: &M) -> Result<Request, Error> {
+        Ok(Request {
+            url: M::url_in(api_url, token),
+            content_type: Request::JSON.to_string(),
+            headers: Vec::new(),
+            body: serde_json::to_vec(method).map_err(Error::Json)?,
+            timeout: None,
+        })
+    }
+
+    /// A GET request for `url`, such as a file to download: it has no body nor content type.
+    pub fn get(url: String) -> Request {
+        Request {
+            url,
+            content_type: String::new(),
+            headers: Vec::new(),
+            body: Vec::new(),
+            timeout: None,
+        }
+    }
+
+    /// Whether this is a GET from [`Request::get`] rather than a method call to POST.
+    pub fn is_get(&self) -> bool {
+        self.body.is_empty()
+    }
+
+    /// The request to send, for transports built on the `http` crate.
+    #[cfg(feature = "hyper-client")]
+    fn into_http(self) -> Result<::http::Request<Vec<u8>>, ::http::Error> {
+        let mut builder = if self.is_get() {
+            ::http::Request::get(self.url)
+        } else {
+            ::http::Request::post(self.url).header(::http::header::CONTENT_TYPE, self.content_type)
+        };
+        for (name, value) in self.headers {
+            builder = builder.header(name, value);
+        }
+        builder.body(self.body)
+    }
+}
+
+/// What a [`Transport`] got back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// HTTP status code.
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// Read the result of `M` from `response`.
+///
+/// Telegram reports errors in the body with a 4xx or 5xx status, so the body is read whatever
+/// the status. An error status with a body that isn't a Bot API response, such as a proxy's
+/// error page, is [`Error::Status`].
+pub fn decode_response<M: Method>(response: &Response) -> Result<M::Item, Error> {
+    match M::response_from_slice(&response.body) {
+        Ok(result) => result.map_err(Error::Api),
+        Err(_) if !(200..300).contains(&response.status) => Err(Error::Status(response.status)),
+        Err(e) => Err(Error::Json(e)),
+    }
+}
+
+/// `Send` except on `wasm32`, where JavaScript values and the futures holding them stay on
+/// one thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+#[cfg(not(target_arch = "wasm32"))]
+type BoxFuture<T> = std::pin::Pin<Box<dyn Future<Output = T> + Send>>;
+#[cfg(target_arch = "wasm32")]
+type BoxFuture<T> = std::pin::Pin<Box<dyn Future<Output = T>>>;
+
+/// An HTTP client that can send a [`Request`].
+pub trait Transport {
+    type Error: StdError + Send + Sync + 'static;
+
+    /// POST [`body`](Request::body) to [`url`](Request::url) with its
+    /// [`content_type`](Request::content_type) and [`headers`](Request::headers), and return the response whatever its status.
+    /// A request without a body is a GET, see [`Request::get`].
+    fn send(
+        &self,
+        request: Request,
+    ) -> impl Future<Output = Result<Response, Self::Error>> + MaybeSend;
+}
+
+/// Something that sends [`Method`]s and decodes their results, like a [`Bot`].
+pub trait Execute {
+    fn execute<M: Method + Sync>(
+        &self,
+        method: &M,
+    ) -> impl Future<Output = Result<M::Item, Error>> + MaybeSend;
+}
+
+/// A bot token and the [`Transport`] its requests go through.
+#[derive(Clone)]
+pub struct Bot<T> {
+    token: String,
+    server: Server,
+    timeouts: Arc<Timeouts>,
+    /// The bot's account, once asked for.
+    info: Arc<OnceLock<BotInfo>>,
+    transport: T,
+}
+
+#[cfg(feature = "reqwest")]
+impl Bot<::reqwest::Client> {
+    /// A bot with a new reqwest client. Clones of it share the client and its connection pool.
+    pub fn new<S: Into<String>>(token: S) -> Bot<::reqwest::Client> {
+        Bot::with_transport(token, ::reqwest::Client::new())
+    }
+}
+
+impl<T> Bot<T> {
+    /// Send requests through `transport`, for another HTTP stack or a preconfigured client.
+    pub fn with_transport<S: Into<String>>(token: S, transport: T) -> Bot<T> {
+        Bot {
+            token: token.into(),
+            server: Server::default(),
+            timeouts: Arc::default(),
+            info: Arc::default(),
+            transport,
+        }
+    }
+
+    /// Send requests to another server, such as a
+    /// [local Bot API server](https://github.com/tdlib/telegram-bot-api), keeping the environment
+    /// and mode of the current one.
+    pub fn api_url<S: Into<String>>(self, api_url: S) -> Bot<T> {
+        let server = self.server.clone().at(api_url);
+        Bot { server, ..self }
+    }
+
+    /// Send requests to `server`, for a local server in `--local` mode or the test environment.
+    pub fn server(self, server: Server) -> Bot<T> {
+        Bot { server, ..self }
+    }
+
+    /// Limit how long calls may take, by method.
+    pub fn timeouts(self, timeouts: Timeouts) -> Bot<T> {
+        Bot {
+            timeouts: Arc::new(timeouts),
+            ..self
+        }
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Where the content of `file` is, see [`Server::file_location`].
+    pub fn file_location(&self, file: &File) -> Option<FileLocation> {
+        self.server.file_location(&self.token, file)
+    }
+
+    /// The request sending `method` would make.
+    pub fn request<M: Method>(&self, method: &M) -> Result<Request, Error> {
+        let mut request = Request::new(self.server.url(), &self.token, method)?;
+        request.url = self.server.method_url(&self.token, M::NAME);
+        request.timeout = self.timeouts.get(method, false);
+        Ok(request)
+    }
+
+    /// The request sending `method` with `files` would make.
+    pub fn upload_request<M: Method>(
+        &self,
+        method: &M,
+        files: &[Attachment],
+    ) -> Result<Request, Error> {
+        self.server.check_upload(files)?;
+        let mut request = Request::multipart(self.server.url(), &self.token, method, files)?;
+        request.url = self.server.method_url(&self.token, M::NAME);
+        request.timeout = self.timeouts.get(method, true);
+        Ok(request)
+    }
+}
+
+impl<T: Transport + Sync> Bot<T> {
+    /// Send `method` and read the result.
+    pub async fn call<M: Method + Sync>(&self, method: &M) -> Result<M::Item, Error> {
+        let request = self.request(method)?;
+        self.send::<M>(request, method.message_chat()).await
+    }
+
+    /// The bot's account, from `getMe` the first time and kept after, by clones too.
+    pub async fn info(&self) -> Result<BotInfo, Error> {
+        if let Some(info) = self.info.get() {
+            return Ok(info.clone());
+        }
+        let info = BotInfo::fetch(self).await?;
+        Ok(self.info.get_or_init(|| info).clone())
+    }
+
+    /// Send `method` as a multipart form with `files`, which its [`InputFile`]s refer to with
+    /// `attach://<name>`.
+    ///
+    /// [`InputFile`]: crate::bot::types::InputFile
+    pub async fn upload<M: Method + Sync>(
+        &self,
+        method: &M,
+        files: &[Attachment],
+    ) -> Result<M::Item, Error> {
+        let request = self.upload_request(method, files)?;
+        self.send::<M>(request, method.message_chat()).await
+    }
+
+    /// Leave the current server for `server` and return the bot calling it: log out of the
+    /// cloud server, or delete the webhook and close the bot on a local one.
+    ///
+    /// Telegram doesn't let a bot that logged out back in the cloud for 10 minutes, and refuses
+    /// to close a bot in the first 10 minutes after it was launched.
+    pub async fn move_to(&self, server: Server) -> Result<Bot<T>, Error>
+    where
+        T: Clone,
+    {
+        if self.server.is_local() {
+            self.call(&DeleteWebhook).await?;
+            self.call(&Close).await?;
+        } else {
+            self.call(&LogOut).await?;
+        }
+        Ok(self.clone().server(server))
+    }
+
+    /// The content of the file at `location`, from [`file_location`](Bot::file_location):
+    /// downloaded, or read from disk for a local server, which blocks the thread meanwhile.
+    pub async fn download(&self, location: &FileLocation) -> Result<Vec<u8>, Error> {
+        let url = match location {
+            FileLocation::Url(url) => url,
+            FileLocation::Path(path) => return std::fs::read(path).map_err(Error::File),
+        };
+        let response = self
+            .transport
+            .send(Request::get(url.clone()))
+            .await
+            .map_err(|e| Error::Transport(Box::new(e)))?;
+        if !(200..300).contains(&response.status) {
+            return Err(Error::Status(response.status));
+        }
+        Ok(response.body)
+    }
+
+    /// The newest profile picture of `user` in the size to show at `size` pixels, as
+    /// [`UserProfilePhotos::newest`] picks it, and its content. `None` if the user has no
+    /// picture the bot may see, or it is over the [download limit](Server::download_limit).
+    ///
+    /// [`UserProfilePhotos::newest`]: crate::bot::types::UserProfilePhotos::newest
+    pub async fn profile_photo(
+        &self,
+        user: UserId,
+        size: i64,
+    ) -> Result<Option<(PhotoSize, Vec<u8>)>, Error> {
+        let photos = self.call(&GetUserProfilePhotos::new(user).limit(1)).await?;
+        let photo = match photos.newest(size) {
+            Some(photo) => photo.clone(),
+            None => return Ok(None),
+        };
+        let file = self.call(&GetFile::new(photo.file_id.0.as_str())).await?;
+        // Telegram leaves the path out of files too large to download.
+        let location = match self.file_location(&file) {
+            Some(location) => location,
+            None => return Ok(None),
+        };
+        let content = self.download(&location).await?;
+        Ok(Some((photo, content)))
+    }
+
+    /// Build `group` and send it, as a multipart form if it has files to upload.
+    pub async fn send_media_group(
+        &self,
+        group: MediaGroupUpload<'_>,
+    ) -> Result<Vec<Message>, Error> {
+        let (method, files) = group.build()?;
+        if files.is_empty() {
+            self.call(&method).await
+        } else {
+            self.upload(&method, &files).await
+        }
+    }
+
+    async fn send<M: Method>(
+        &self,
+        request: Request,
+        chat: Option<&ChatTarget<'_>>,
+    ) -> Result<M::Item, Error> {
+        let call = async {
+            let response = self
+                .transport
+                .send(request)
+                .await
+                .map_err(|e| Error::Transport(Box::new(e)))?;
+            decode_response::<M>(&response)
+        };
+        #[cfg(feature = "tracing")]
+        let call = crate::trace::instrument(M::NAME, chat, call);
+        #[cfg(not(feature = "tracing"))]
+        let _ = chat;
+        call.await
+    }
+}
+
+impl<T: Transport + Sync> Execute for Bot<T> {
+    fn execute<M: Method + Sync>(
+        &self,
+        method: &M,
+    ) -> impl Future<Output = Result<M::Item, Error>> + MaybeSend {
+        self.call(method)
+    }
+}
+
+impl<T> fmt::Debug for Bot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Keep the token out of logs.
+        f.debug_struct("Bot")
+            .field("server", &self.server)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Why a method call failed.
+#[derive(Debug)]
+pub enum Error {
+    /// The [`Transport`] couldn't send the request or read the response.
+    Transport(Box<dyn StdError + Send + Sync>),
+    /// The request couldn't be encoded or the response wasn't a Bot API response.
+    Json(serde_json::Error),
+    /// An error status without a Bot API response.
+    Status(u16),
+    /// Telegram refused the request.
+    Api(ApiError),
+    /// An [`OffsetStore`] couldn't load or save the polling offset.
+    Offset(std::io::Error),
+    /// A media group to send broke Telegram's rules.
+    MediaGroup(MediaGroupError),
+    /// The attachment `name` is over the upload limit of the [`Server`], in bytes.
+    FileTooLarge { name: String, size: u64, limit: u64 },
+    /// A file on the disk of a local [`Server`] couldn't be read.
+    File(std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Transport(e) => write!(f, "transport error: {}", e),
+            Error::Json(e) => write!(f, "JSON error: {}", e),
+            Error::Status(status) => write!(f, "HTTP status {}", status),
+            Error::Api(e) => write!(f, "Telegram error {}: {}", e.error_code, e.description),
+            Error::Offset(e) => write!(f, "offset store error: {}", e),
+            Error::MediaGroup(e) => write!(f, "invalid media group: {}", e),
+            Error::FileTooLarge { name, size, limit } => write!(
+                f,
+                "file {} has {} bytes, more than the {} the server takes",
+                name, size, limit
+            ),
+            Error::File(e) => write!(f, "file error: {}", e),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Transport(e) => Some(&**e),
+            Error::Json(e) => Some(e),
+            Error::Status(_) => None,
+            Error::Api(e) => Some(e),
+            Error::Offset(e) => Some(e),
+            Error::MediaGroup(e) => Some(e),
+            Error::FileTooLarge { .. } => None,
+            Error::File(e) => Some(e),
+        }
+    }
+}
+
+impl From<MediaGroupError> for Error {
+    fn from(e: MediaGroupError) -> Error {
+        Error::MediaGroup(e)
+    }
+}
+
+impl From<ApiError> for Error {
+    fn from(e: ApiError) -> Error {
+        Error::Api(e)
+    }
+}
@@ -0,0 +1,95 @@
+//! `multipart/form-data` bodies for methods that upload files.
+use super::{Error, Request};
+use crate::bot::methods::Method;
+use crate::bot::types::Attachment;
+
+impl Request {
+    /// A request sending `method` as `multipart/form-data` with `files` attached.
+    ///
+    /// Each field of `method` becomes a form field: strings as they are, other values as JSON.
+    /// Each file is a part named after its [`Attachment::name`].
+    pub fn multipart<M: Method>(
+        api_url: &str,
+        token: &str,
+        method: &M,
+        files: &[Attachment],
+    ) -> Result<Request, Error> {
+        let fields = match serde_json::to_value(method).map_err(Error::Json)? {
+            serde_json::Value::Object(fields) => fields,
+            // Methods without parameters.
+            _ => serde_json::Map::new(),
+        };
+        let fields: Vec<(String, String)> = fields
+            .into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(name, value)| match value {
+                serde_json::Value::String(value) => (name, value),
+                value => (name, value.to_string()),
+            })
+            .collect();
+        let boundary = boundary(&fields, files);
+        let mut body = Vec::new();
+        for (name, value) in &fields {
+            body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+            body.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                    escape(name)
+                )
+                .as_bytes(),
+            );
+            body.extend_from_slice(value.as_bytes());
+            body.extend_from_slice(b"\r\n");
+        }
+        for file in files {
+            body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+            body.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+                    escape(&file.name),
+                    escape(&file.file_name)
+                )
+                .as_bytes(),
+            );
+            body.extend_from_slice(b"Content-Type: application/octet-stream\r\n\r\n");
+            body.extend_from_slice(&file.data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+        Ok(Request {
+            url: format!("{}/bot{}/{}", api_url, token, M::NAME),
+            content_type: format!("multipart/form-data; boundary={}", boundary),
+            headers: Vec::new(),
+            body,
+            timeout: None,
+        })
+    }
+}
+
+/// A boundary that doesn't occur in any part.
+fn boundary(fields: &[(String, String)], files: &[Attachment]) -> String {
+    let occurs = |boundary: &str| {
+        fields
+            .iter()
+            .any(|(name, value)| name.contains(boundary) || value.contains(boundary))
+            || files.iter().any(|file| {
+                file.name.contains(boundary)
+                    || file.file_name.contains(boundary)
+                    || file
+                        .data
+                        .windows(boundary.len())
+                        .any(|window| window == boundary.as_bytes())
+            })
+    };
+    (0u64..)
+        .map(|n| format!("telegram-types-{:016x}", n))
+        .find(|boundary| !occurs(boundary))
+        .unwrap()
+}
+
+/// Quotes and line breaks would end the header value early.
+fn escape(name: &str) -> String {
+    name.replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
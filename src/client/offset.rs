@@ -0,0 +1,152 @@
+//! Keeping the polling offset across restarts.
+use super::Error;
+use crate::bot::methods::GetUpdates;
+use crate::bot::types::UpdateId;
+use std::fmt;
+use std::io;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Where [`UpdateStream::offset_store`](super::UpdateStream::offset_store) and the blocking
+/// [`Updates::offset_store`](super::blocking::Updates::offset_store) keep their offset, so a bot
+/// starts where it stopped.
+///
+/// The offset is one past the last update handled. An update counts as handled once the next
+/// one is asked for, so an update whose handler was interrupted by a crash is delivered again
+/// rather than lost.
+pub trait OffsetStore: Send + Sync {
+    /// The saved offset, `None` if there is none yet.
+    fn load(&self) -> io::Result<Option<UpdateId>>;
+
+    fn save(&self, offset: UpdateId) -> io::Result<()>;
+}
+
+impl<T: OffsetStore + ?Sized> OffsetStore for Arc<T> {
+    fn load(&self) -> io::Result<Option<UpdateId>> {
+        (**self).load()
+    }
+
+    fn save(&self, offset: UpdateId) -> io::Result<()> {
+        (**self).save(offset)
+    }
+}
+
+impl fmt::Debug for dyn OffsetStore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("OffsetStore")
+    }
+}
+
+/// An [`OffsetStore`] in memory, for a stream that is built again in the same process, and
+/// tests.
+#[derive(Debug, Default)]
+pub struct MemoryOffsetStore(Mutex<Option<UpdateId>>);
+
+impl MemoryOffsetStore {
+    pub fn new() -> MemoryOffsetStore {
+        MemoryOffsetStore::default()
+    }
+
+    /// The offset saved last.
+    pub fn get(&self) -> Option<UpdateId> {
+        *self.0.lock().unwrap()
+    }
+}
+
+impl OffsetStore for MemoryOffsetStore {
+    fn load(&self) -> io::Result<Option<UpdateId>> {
+        Ok(self.get())
+    }
+
+    fn save(&self, offset: UpdateId) -> io::Result<()> {
+        *self.0.lock().unwrap() = Some(offset);
+        Ok(())
+    }
+}
+
+/// An [`OffsetStore`] in a file holding the offset as decimal digits.
+///
+/// A missing file means no offset. Each save writes a file next to it and renames it over the
+/// old one, so a crash leaves either offset but not half of one.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileOffsetStore {
+    path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileOffsetStore {
+    pub fn new<P: Into<PathBuf>>(path: P) -> FileOffsetStore {
+        FileOffsetStore { path: path.into() }
+    }
+
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl OffsetStore for FileOffsetStore {
+    fn load(&self) -> io::Result<Option<UpdateId>> {
+        let text = match std::fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let offset = text
+            .trim()
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Some(UpdateId(offset)))
+    }
+
+    fn save(&self, offset: UpdateId) -> io::Result<()> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        std::fs::write(&temporary, offset.0.to_string())?;
+        std::fs::rename(&temporary, &self.path)
+    }
+}
+
+/// The store of a polling loop and the last update it yielded, not yet saved.
+#[derive(Debug, Default)]
+pub(crate) struct Confirm {
+    store: Option<Arc<dyn OffsetStore>>,
+    loaded: bool,
+    yielded: Option<UpdateId>,
+}
+
+impl Confirm {
+    pub(crate) fn new(store: Arc<dyn OffsetStore>) -> Confirm {
+        Confirm {
+            store: Some(store),
+            ..Confirm::default()
+        }
+    }
+
+    /// Before the first call, start from the saved offset unless one was set. Then save the
+    /// offset past the update yielded last, since the next one is asked for.
+    pub(crate) fn confirm(&mut self, get_updates: &mut GetUpdates) -> Result<(), Error> {
+        let store = match &self.store {
+            Some(store) => store,
+            None => return Ok(()),
+        };
+        if !self.loaded {
+            if get_updates.offset.is_none() {
+                if let Some(offset) = store.load().map_err(Error::Offset)? {
+                    get_updates.offset(offset);
+                }
+            }
+            self.loaded = true;
+        }
+        match self.yielded.take() {
+            Some(update_id) => store.save(update_id + 1).map_err(Error::Offset),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn yielded(&mut self, update_id: UpdateId) {
+        self.yielded = Some(update_id);
+    }
+}
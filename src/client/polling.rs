@@ -0,0 +1,396 @@
+//! Long polling as a [`Stream`] of updates.
+use super::offset::Confirm;
+#[cfg(not(target_arch = "wasm32"))]
+use super::MetricsSink;
+use super::{
+    BoxFuture, Error, Execute, MaybeSend, OffsetStore, Overflow, QueueFeed, QueuedUpdates,
+    RetryPolicy, DEFAULT_POLL_TIMEOUT,
+};
+use crate::bot::methods::{GetUpdates, UpdateTypes};
+use crate::bot::types::{Update, UpdateId};
+use futures_core::Stream;
+use std::borrow::Cow;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+type Sleep = Arc<dyn Fn(Duration) -> BoxFuture<()> + Send + Sync>;
+type OnError = Arc<dyn Fn(&Error, Duration) + Send + Sync>;
+
+/// Updates received by calling [`GetUpdates`] again and again.
+///
+/// The offset moves past each batch as it arrives, so an update is yielded once. A failed call
+/// is yielded as an error and the next poll tries again, at once unless the stream
+/// [`reconnect`](UpdateStream::reconnect)s after a backoff. The call in flight is kept in the
+/// stream, so dropping a `next()` future, as `tokio::select!` does, loses no updates.
+///
+/// The stream ends after a [`StopHandle`] is stopped and the batch received so far is yielded.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn run() {
+/// use futures_util::StreamExt;
+/// use telegram_types::client::{Bot, UpdateStream};
+///
+/// let bot = Bot::new(std::env::var("BOT_TOKEN").unwrap());
+/// let mut updates = UpdateStream::new(bot.clone());
+/// while let Some(update) = updates.next().await {
+///     match update {
+///         Ok(update) => println!("{:?}", update.content),
+///         Err(e) => eprintln!("{}", e),
+///     }
+/// }
+/// # }
+/// ```
+pub struct UpdateStream<E> {
+    bot: E,
+    get_updates: GetUpdates<'static>,
+    buffer: VecDeque<Update>,
+    pending: Option<BoxFuture<Result<Vec<Update>, Error>>>,
+    stop: StopHandle,
+    confirm: Confirm,
+    dedup: Option<Dedup>,
+    reconnect: Reconnect,
+    sleep: Option<Sleep>,
+    waiting: Option<BoxFuture<()>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    metrics: Option<Arc<dyn MetricsSink>>,
+}
+
+impl<E: Execute + Clone + MaybeSend + 'static> UpdateStream<E> {
+    /// Long poll with a timeout of [`DEFAULT_POLL_TIMEOUT`] seconds.
+    pub fn new(bot: E) -> UpdateStream<E> {
+        UpdateStream {
+            bot,
+            get_updates: GetUpdates {
+                timeout: Some(DEFAULT_POLL_TIMEOUT),
+                ..GetUpdates::new()
+            },
+            buffer: VecDeque::new(),
+            pending: None,
+            stop: StopHandle::default(),
+            confirm: Confirm::default(),
+            dedup: None,
+            reconnect: Reconnect::default(),
+            sleep: None,
+            waiting: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            metrics: None,
+        }
+    }
+
+    /// How long Telegram holds each call open waiting for updates, in seconds.
+    pub fn timeout(mut self, seconds: i64) -> UpdateStream<E> {
+        self.get_updates.timeout = Some(seconds);
+        self
+    }
+
+    /// At most this many updates per call, 1-100.
+    pub fn limit(mut self, limit: u32) -> UpdateStream<E> {
+        self.get_updates.limit = Some(limit);
+        self
+    }
+
+    /// Only these kinds of updates. Telegram keeps the setting for later calls.
+    pub fn allowed_updates(mut self, updates: Vec<UpdateTypes>) -> UpdateStream<E> {
+        self.get_updates.allowed_updates = Some(Cow::Owned(updates));
+        self
+    }
+
+    /// Start from `offset`, such as one saved from [`offset`](UpdateStream::offset) before a
+    /// restart.
+    pub fn start_at(mut self, offset: UpdateId) -> UpdateStream<E> {
+        self.get_updates.offset(offset);
+        self
+    }
+
+    /// The offset of the next call: one past the last update received.
+    pub fn offset(&self) -> Option<UpdateId> {
+        self.get_updates.offset
+    }
+
+    /// Start from the offset saved in `store`, unless [`start_at`](UpdateStream::start_at)
+    /// sets one, and save the offset past each update once the next one is asked for. A
+    /// failure to load or save is yielded as [`Error::Offset`].
+    pub fn offset_store(mut self, store: Arc<dyn OffsetStore>) -> UpdateStream<E> {
+        self.confirm = Confirm::new(store);
+        self
+    }
+
+    /// Skip updates whose id is among the last `window` yielded, in case Telegram delivers
+    /// some again, such as after a restart from an older offset.
+    pub fn dedup(mut self, window: usize) -> UpdateStream<E> {
+        self.dedup = Some(Dedup::new(window));
+        self
+    }
+
+    /// Call again after network errors, timeouts and 5xx responses instead of yielding them,
+    /// waiting as `policy` says with `sleep`, the runtime's timer such as `tokio::time::sleep`.
+    ///
+    /// After [`max_retries`](RetryPolicy::max_retries) failures in a row, and for errors the
+    /// policy doesn't retry such as a revoked token, the error is yielded and the count starts
+    /// over. Use [`on_error`](UpdateStream::on_error) to log the failures retried.
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "reqwest")]
+    /// # fn run() {
+    /// use telegram_types::client::{Bot, RetryPolicy, UpdateStream};
+    ///
+    /// let policy = RetryPolicy {
+    ///     max_retries: u32::MAX,
+    ///     ..RetryPolicy::default()
+    /// };
+    /// let updates = UpdateStream::new(Bot::new(std::env::var("BOT_TOKEN").unwrap()))
+    ///     .reconnect(policy, tokio::time::sleep)
+    ///     .on_error(|e, delay| eprintln!("{}, polling again in {:?}", e, delay));
+    /// # }
+    /// ```
+    pub fn reconnect<S, F>(mut self, policy: RetryPolicy, sleep: S) -> UpdateStream<E>
+    where
+        S: Fn(Duration) -> F + Send + Sync + 'static,
+        F: Future<Output = ()> + MaybeSend + 'static,
+    {
+        self.reconnect.policy = Some(policy);
+        self.sleep = Some(Arc::new(move |delay| Box::pin(sleep(delay))));
+        self
+    }
+
+    /// Call `f` with each failure retried by [`reconnect`](UpdateStream::reconnect) and the
+    /// wait before the next call.
+    pub fn on_error<F>(mut self, f: F) -> UpdateStream<E>
+    where
+        F: Fn(&Error, Duration) + Send + Sync + 'static,
+    {
+        self.reconnect.on_error = Some(Arc::new(f));
+        self
+    }
+
+    /// A handle that ends the stream from elsewhere, such as a signal handler.
+    pub fn stop_handle(&self) -> StopHandle {
+        self.stop.clone()
+    }
+
+    /// Poll ahead into a queue of at most `capacity` updates, see [`queue`](super::queue).
+    pub fn queue(
+        self,
+        capacity: usize,
+        overflow: Overflow<Result<Update, Error>>,
+    ) -> (QueueFeed<Self>, QueuedUpdates<Result<Update, Error>>) {
+        super::queue(self, capacity, overflow)
+    }
+
+    /// Report the number of updates each call returns to `sink`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn metrics(mut self, sink: Arc<dyn MetricsSink>) -> UpdateStream<E> {
+        self.metrics = Some(sink);
+        self
+    }
+
+    fn call(&self) -> BoxFuture<Result<Vec<Update>, Error>> {
+        let bot = self.bot.clone();
+        let get_updates = self.get_updates.clone();
+        Box::pin(async move { bot.execute(&get_updates).await })
+    }
+}
+
+impl<E: Execute + Clone + MaybeSend + 'static> Stream for UpdateStream<E> {
+    type Item = Result<Update, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let Err(e) = this.confirm.confirm(&mut this.get_updates) {
+            return Poll::Ready(Some(Err(e)));
+        }
+        loop {
+            if let Some(update) = this.buffer.pop_front() {
+                if let Some(dedup) = &mut this.dedup {
+                    if !dedup.insert(update.update_id) {
+                        continue;
+                    }
+                }
+                #[cfg(feature = "tracing")]
+                crate::trace::update_received(&update, "polling");
+                this.confirm.yielded(update.update_id);
+                return Poll::Ready(Some(Ok(update)));
+            }
+            if this.stop.register(cx.waker()) {
+                // Telegram delivers the abandoned call's updates again to the next call.
+                this.pending = None;
+                this.waiting = None;
+                return Poll::Ready(None);
+            }
+            if let Some(waiting) = &mut this.waiting {
+                match waiting.as_mut().poll(cx) {
+                    Poll::Ready(()) => this.waiting = None,
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+            if this.pending.is_none() {
+                this.pending = Some(this.call());
+            }
+            let result = match this.pending.as_mut().unwrap().as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.pending = None;
+            let updates = match result {
+                Ok(updates) => updates,
+                Err(e) => match (this.reconnect.delay(&e), &this.sleep) {
+                    (Some(delay), Some(sleep)) => {
+                        this.waiting = Some(sleep(delay));
+                        continue;
+                    }
+                    _ => return Poll::Ready(Some(Err(e))),
+                },
+            };
+            this.reconnect.succeeded();
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(sink) = &this.metrics {
+                sink.updates_received(updates.len());
+            }
+            if let Some(last) = updates.last() {
+                this.get_updates.offset(last.update_id + 1);
+            }
+            this.buffer.extend(updates);
+        }
+    }
+}
+
+impl<E> Unpin for UpdateStream<E> {}
+
+impl<E: fmt::Debug> fmt::Debug for UpdateStream<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UpdateStream")
+            .field("bot", &self.bot)
+            .field("get_updates", &self.get_updates)
+            .field("buffered", &self.buffer.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Stops an [`UpdateStream`] or a blocking [`Updates`](super::blocking::Updates) iterator.
+///
+/// Updates already received are still yielded, then the stream ends, abandoning a call in
+/// flight. A blocking iterator ends before its next call, so a call in progress finishes first,
+/// within the poll timeout. Save [`UpdateStream::offset`], or give the stream an
+/// [`OffsetStore`], to start where the bot stopped, otherwise Telegram delivers the last batch
+/// again.
+#[derive(Debug, Clone, Default)]
+pub struct StopHandle(Arc<StopState>);
+
+#[derive(Debug, Default)]
+struct StopState {
+    stopped: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl StopHandle {
+    pub fn stop(&self) {
+        self.0.stopped.store(true, Ordering::SeqCst);
+        if let Some(waker) = self.0.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.0.stopped.load(Ordering::SeqCst)
+    }
+
+    /// Whether stopped, otherwise wake `waker` when stopped.
+    fn register(&self, waker: &Waker) -> bool {
+        let mut slot = self.0.waker.lock().unwrap();
+        if self.is_stopped() {
+            return true;
+        }
+        match &*slot {
+            Some(registered) if registered.will_wake(waker) => {}
+            _ => *slot = Some(waker.clone()),
+        }
+        false
+    }
+}
+
+/// When a polling loop calls again after a failure, instead of yielding it.
+#[derive(Default)]
+pub(crate) struct Reconnect {
+    pub(crate) policy: Option<RetryPolicy>,
+    pub(crate) on_error: Option<OnError>,
+    failures: u32,
+}
+
+impl Reconnect {
+    /// How long to wait before calling again after `error`, `None` to yield it.
+    pub(crate) fn delay(&mut self, error: &Error) -> Option<Duration> {
+        let delay = self.policy?.delay(self.failures, error);
+        match delay {
+            Some(delay) => {
+                self.failures += 1;
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    failures = self.failures,
+                    delay_ms = delay.as_millis() as u64,
+                    error = %crate::trace::redacted(error),
+                    "polling again",
+                );
+                if let Some(on_error) = &self.on_error {
+                    on_error(error, delay);
+                }
+            }
+            None => self.failures = 0,
+        }
+        delay
+    }
+
+    pub(crate) fn succeeded(&mut self) {
+        self.failures = 0;
+    }
+}
+
+impl fmt::Debug for Reconnect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Reconnect")
+            .field("policy", &self.policy)
+            .field("failures", &self.failures)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The ids of the last updates yielded, to skip them if they come again.
+#[derive(Debug)]
+pub(crate) struct Dedup {
+    window: usize,
+    order: VecDeque<UpdateId>,
+    seen: HashSet<UpdateId>,
+}
+
+impl Dedup {
+    pub(crate) fn new(window: usize) -> Dedup {
+        Dedup {
+            window,
+            order: VecDeque::with_capacity(window),
+            seen: HashSet::with_capacity(window),
+        }
+    }
+
+    /// Remember `update_id`, `false` if it was already in the window.
+    pub(crate) fn insert(&mut self, update_id: UpdateId) -> bool {
+        if self.window == 0 {
+            return true;
+        }
+        if !self.seen.insert(update_id) {
+            return false;
+        }
+        if self.order.len() == self.window {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.order.push_back(update_id);
+        true
+    }
+}
@@ -0,0 +1,221 @@
+//! A bounded queue between polling and the handlers.
+use futures_core::Stream;
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+type OnDrop<T> = Arc<dyn Fn(T) + Send + Sync>;
+
+/// What a full [`queue`] does with the next update.
+pub enum Overflow<T> {
+    /// Stop polling until the handlers make room. Telegram keeps the updates meanwhile, for up to
+    /// 24 hours.
+    Block,
+    /// Drop the oldest update queued to make room, passing it to the callback, to log or count.
+    DropOldest(OnDrop<T>),
+}
+
+impl<T> Overflow<T> {
+    pub fn drop_oldest<F: Fn(T) + Send + Sync + 'static>(f: F) -> Overflow<T> {
+        Overflow::DropOldest(Arc::new(f))
+    }
+}
+
+impl<T> Clone for Overflow<T> {
+    fn clone(&self) -> Overflow<T> {
+        match self {
+            Overflow::Block => Overflow::Block,
+            Overflow::DropOldest(f) => Overflow::DropOldest(f.clone()),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Overflow<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Overflow::Block => f.write_str("Block"),
+            Overflow::DropOldest(_) => f.write_str("DropOldest"),
+        }
+    }
+}
+
+/// Decouple polling from handling: `updates` are read ahead into a queue of at most `capacity`,
+/// `overflow` says what happens when it is full.
+///
+/// The [`QueueFeed`] is a future that polls `updates` into the queue until they end or the
+/// [`QueuedUpdates`] are dropped; spawn it on the runtime. The `QueuedUpdates` are a stream of
+/// the queued updates for the handlers, such as
+/// [`Dispatcher::dispatch_by_chat`](super::Dispatcher::dispatch_by_chat).
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn run(bot: telegram_types::client::Bot<reqwest::Client>) {
+/// use telegram_types::client::{queue, Overflow, UpdateStream};
+///
+/// let (feed, updates) = queue(UpdateStream::new(bot.clone()), 1000, Overflow::Block);
+/// tokio::spawn(feed);
+/// # }
+/// ```
+pub fn queue<S: Stream + Unpin>(
+    updates: S,
+    capacity: usize,
+    overflow: Overflow<S::Item>,
+) -> (QueueFeed<S>, QueuedUpdates<S::Item>) {
+    let shared = Arc::new(Mutex::new(Shared {
+        queue: VecDeque::new(),
+        capacity: capacity.max(1),
+        ended: false,
+        dropped: false,
+        feed: None,
+        handlers: None,
+    }));
+    let feed = QueueFeed {
+        updates,
+        overflow,
+        shared: shared.clone(),
+    };
+    (feed, QueuedUpdates { shared })
+}
+
+struct Shared<T> {
+    queue: VecDeque<T>,
+    capacity: usize,
+    /// The stream ended, the rest of the queue is all there is.
+    ended: bool,
+    /// The receiving side is gone, polling can stop.
+    dropped: bool,
+    feed: Option<Waker>,
+    handlers: Option<Waker>,
+}
+
+fn lock<T>(shared: &Mutex<Shared<T>>) -> MutexGuard<'_, Shared<T>> {
+    // Each change leaves the queue consistent, a panic in between doesn't break it.
+    shared.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Polls updates into a [`queue`], see there.
+pub struct QueueFeed<S: Stream> {
+    updates: S,
+    overflow: Overflow<S::Item>,
+    shared: Arc<Mutex<Shared<S::Item>>>,
+}
+
+impl<S: Stream + Unpin> Future for QueueFeed<S> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+        loop {
+            {
+                let mut shared = lock(&this.shared);
+                if shared.dropped {
+                    return Poll::Ready(());
+                }
+                let full = shared.queue.len() >= shared.capacity;
+                if full && matches!(this.overflow, Overflow::Block) {
+                    shared.feed = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+            }
+            let item = match Pin::new(&mut this.updates).poll_next(cx) {
+                Poll::Ready(item) => item,
+                Poll::Pending => return Poll::Pending,
+            };
+            let mut shared = lock(&this.shared);
+            let item = match item {
+                Some(item) => item,
+                None => {
+                    shared.ended = true;
+                    if let Some(waker) = shared.handlers.take() {
+                        waker.wake();
+                    }
+                    return Poll::Ready(());
+                }
+            };
+            let dropped = if shared.queue.len() >= shared.capacity {
+                shared.queue.pop_front()
+            } else {
+                None
+            };
+            shared.queue.push_back(item);
+            let waker = shared.handlers.take();
+            drop(shared);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+            if let (Some(item), Overflow::DropOldest(on_drop)) = (dropped, &this.overflow) {
+                on_drop(item);
+            }
+        }
+    }
+}
+
+impl<S: Stream + fmt::Debug> fmt::Debug for QueueFeed<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QueueFeed")
+            .field("updates", &self.updates)
+            .field("overflow", &self.overflow)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The updates in a [`queue`], in the order they arrived. Ends after the polled stream ends and
+/// the queue is empty.
+pub struct QueuedUpdates<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> QueuedUpdates<T> {
+    /// Number of updates waiting.
+    pub fn len(&self) -> usize {
+        lock(&self.shared).queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Stream for QueuedUpdates<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<T>> {
+        let mut shared = lock(&self.shared);
+        if let Some(item) = shared.queue.pop_front() {
+            let waker = shared.feed.take();
+            drop(shared);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+            return Poll::Ready(Some(item));
+        }
+        if shared.ended {
+            return Poll::Ready(None);
+        }
+        shared.handlers = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for QueuedUpdates<T> {
+    fn drop(&mut self) {
+        let mut shared = lock(&self.shared);
+        shared.dropped = true;
+        let waker = shared.feed.take();
+        drop(shared);
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> fmt::Debug for QueuedUpdates<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QueuedUpdates")
+            .field("len", &self.len())
+            .finish()
+    }
+}
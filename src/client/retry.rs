@@ -0,0 +1,193 @@
+//! Retrying failed calls.
+#[cfg(not(target_arch = "wasm32"))]
+use super::metrics::{self, MetricsSink};
+use super::{Error, Execute, MaybeSend};
+use crate::bot::methods::Method;
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+use std::time::Duration;
+
+/// When and how long [`Retry`] waits before calling again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first call, 0 to never retry.
+    pub max_retries: u32,
+    /// Backoff before the first retry of a network error or 5xx status. It doubles with each
+    /// retry, and each wait is a random time between half of it and all of it.
+    pub initial_backoff: Duration,
+    /// Cap on the backoff.
+    pub max_backoff: Duration,
+    /// The longest flood wait to sleep through. A 429 asking for longer is returned as it is,
+    /// for the caller to decide.
+    pub max_retry_after: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// How long to wait before retry number `retry`, counting from 0, after `error`. `None` if
+    /// the error should be returned.
+    pub fn delay(&self, retry: u32, error: &Error) -> Option<Duration> {
+        if retry >= self.max_retries {
+            return None;
+        }
+        match error {
+            Error::Api(e) if e.error_code == 429 => {
+                let seconds = e.parameters.as_ref()?.retry_after?;
+                let wait = Duration::from_secs(seconds.max(0) as u64);
+                if wait <= self.max_retry_after {
+                    Some(wait)
+                } else {
+                    None
+                }
+            }
+            Error::Api(e) if e.error_code >= 500 => Some(self.backoff(retry)),
+            Error::Status(status) if *status >= 500 => Some(self.backoff(retry)),
+            Error::Transport(_) => Some(self.backoff(retry)),
+            _ => None,
+        }
+    }
+
+    fn backoff(&self, retry: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .checked_mul(1 << retry.min(31))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+        // Jitter keeps bots that failed together from retrying together.
+        let random = RandomState::new().build_hasher().finish();
+        let half = backoff / 2;
+        half + half.mul_f64((random >> 11) as f64 / (1u64 << 53) as f64)
+    }
+}
+
+/// An [`Execute`] that retries the calls of another one as its [`RetryPolicy`] says, sleeping
+/// with `sleep` in between.
+///
+/// `sleep` is the runtime's timer, such as `tokio::time::sleep`. Call [`inner`](Retry::inner)
+/// to skip retrying for one call.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn run() -> Result<(), telegram_types::client::Error> {
+/// use telegram_types::bot::methods::{ChatTarget, SendMessage};
+/// use telegram_types::client::{Bot, Execute, Retry};
+///
+/// let bot = Retry::new(Bot::new(std::env::var("BOT_TOKEN").unwrap()), tokio::time::sleep);
+/// bot.execute(&SendMessage::new(ChatTarget::id(42), "hello")).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Retry<E, S> {
+    inner: E,
+    sleep: S,
+    policy: RetryPolicy,
+    #[cfg(not(target_arch = "wasm32"))]
+    metrics: Option<Arc<dyn MetricsSink>>,
+}
+
+impl<E, S> Retry<E, S> {
+    /// Retry with the default [`RetryPolicy`].
+    pub fn new(inner: E, sleep: S) -> Retry<E, S> {
+        Retry {
+            inner,
+            sleep,
+            policy: RetryPolicy::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            metrics: None,
+        }
+    }
+
+    pub fn policy(self, policy: RetryPolicy) -> Retry<E, S> {
+        Retry { policy, ..self }
+    }
+
+    /// Report each call to `sink` once, with its retries.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn metrics(self, sink: Arc<dyn MetricsSink>) -> Retry<E, S> {
+        Retry {
+            metrics: Some(sink),
+            ..self
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+}
+
+impl<E, S> Retry<E, S> {
+    /// Report the `result` of a call of `method` to the metrics sink, if any.
+    fn finish<T>(
+        &self,
+        method: &'static str,
+        start: Start,
+        result: Result<T, Error>,
+        retries: u32,
+    ) -> Result<T, Error> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(sink) = &self.metrics {
+            metrics::report(&**sink, method, start.0, &result, retries);
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = (method, start, retries);
+        result
+    }
+}
+
+/// When a call started, where there is a clock.
+#[derive(Clone, Copy)]
+struct Start(#[cfg(not(target_arch = "wasm32"))] std::time::Instant);
+
+impl Start {
+    fn now() -> Start {
+        Start(
+            #[cfg(not(target_arch = "wasm32"))]
+            std::time::Instant::now(),
+        )
+    }
+}
+
+impl<E, S, F> Execute for Retry<E, S>
+where
+    E: Execute + Sync,
+    S: Fn(Duration) -> F + Sync,
+    F: Future<Output = ()> + MaybeSend,
+{
+    async fn execute<M: Method + Sync>(&self, method: &M) -> Result<M::Item, Error> {
+        let start = Start::now();
+        let mut retry = 0;
+        loop {
+            let error = match self.inner.execute(method).await {
+                Ok(item) => return self.finish(M::NAME, start, Ok(item), retry),
+                Err(error) => error,
+            };
+            let delay = match self.policy.delay(retry, &error) {
+                Some(delay) => delay,
+                None => return self.finish(M::NAME, start, Err(error), retry),
+            };
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                method = M::NAME,
+                retry,
+                delay_ms = delay.as_millis() as u64,
+                error = %crate::trace::redacted(&error),
+                "retrying call",
+            );
+            (self.sleep)(delay).await;
+            retry += 1;
+        }
+    }
+}
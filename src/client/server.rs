@@ -0,0 +1,144 @@
+use super::{Attachment, Error, DEFAULT_API_URL};
+use crate::bot::types::File;
+use std::path::{Path, PathBuf};
+
+/// Where a bot's requests go: the Bot API server, which environment, and whether it's a
+/// [local server](https://github.com/tdlib/telegram-bot-api) in `--local` mode.
+///
+/// The mode changes what the server allows rather than how it is called: uploads of up to
+/// 2000 MB instead of 50 MB, downloads of any size instead of 20 MB, and [`File`]s with a path
+/// on the server's disk instead of a path to download.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Server {
+    url: String,
+    test: bool,
+    local: bool,
+}
+
+impl Server {
+    /// Largest file the cloud server takes in an upload.
+    pub const UPLOAD_LIMIT: u64 = 50 * 1024 * 1024;
+    /// Largest file a local server takes in an upload.
+    pub const LOCAL_UPLOAD_LIMIT: u64 = 2000 * 1024 * 1024;
+    /// Largest file the cloud server lets bots download.
+    pub const DOWNLOAD_LIMIT: u64 = 20 * 1024 * 1024;
+
+    /// The server at `url`, with the limits of the cloud one.
+    pub fn new<S: Into<String>>(url: S) -> Server {
+        Server {
+            url: url.into().trim_end_matches('/').to_string(),
+            test: false,
+            local: false,
+        }
+    }
+
+    /// A local server at `url` running in `--local` mode.
+    pub fn local<S: Into<String>>(url: S) -> Server {
+        Server {
+            local: true,
+            ..Server::new(url)
+        }
+    }
+
+    /// Call the [test environment](https://core.telegram.org/bots/webapps#using-bots-in-the-test-environment)
+    /// of the server, with the token of a bot made by @BotFather of the test servers.
+    pub fn test(self) -> Server {
+        Server { test: true, ..self }
+    }
+
+    /// The same environment and mode at another `url`.
+    pub(crate) fn at<S: Into<String>>(self, url: S) -> Server {
+        Server {
+            url: url.into().trim_end_matches('/').to_string(),
+            ..self
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn is_test(&self) -> bool {
+        self.test
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.local
+    }
+
+    /// Largest file this server takes in an upload, in bytes.
+    pub fn upload_limit(&self) -> u64 {
+        if self.local {
+            Server::LOCAL_UPLOAD_LIMIT
+        } else {
+            Server::UPLOAD_LIMIT
+        }
+    }
+
+    /// Largest file this server lets bots download, in bytes. `None` for no limit.
+    pub fn download_limit(&self) -> Option<u64> {
+        if self.local {
+            None
+        } else {
+            Some(Server::DOWNLOAD_LIMIT)
+        }
+    }
+
+    /// The URL to call the method `name` at.
+    pub fn method_url(&self, token: &str, name: &str) -> String {
+        format!("{}/bot{}/{}{}", self.url, token, self.environment(), name)
+    }
+
+    /// Where the content of `file` is, `None` if it has no `file_path`, which
+    /// [`GetFile`](crate::bot::methods::GetFile) fills in.
+    ///
+    /// A local server gives the absolute path of its copy, readable where the server runs;
+    /// otherwise the file is downloaded with a GET request to its URL, which has the token in it.
+    pub fn file_location(&self, token: &str, file: &File) -> Option<FileLocation> {
+        let path = file.file_path.as_ref()?;
+        // Also a Windows path, from a server on another machine than the bot.
+        let absolute = path.starts_with('/') || Path::new(path).is_absolute();
+        if absolute {
+            return Some(FileLocation::Path(PathBuf::from(path)));
+        }
+        file.download_url_in(&self.url, token, self.environment())
+            .map(FileLocation::Url)
+    }
+
+    /// Refuse `files` before sending them if one is over the upload limit.
+    pub(crate) fn check_upload(&self, files: &[Attachment]) -> Result<(), Error> {
+        let limit = self.upload_limit();
+        match files.iter().find(|file| file.data.len() as u64 > limit) {
+            Some(file) => Err(Error::FileTooLarge {
+                name: file.name.clone(),
+                size: file.data.len() as u64,
+                limit,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn environment(&self) -> &'static str {
+        if self.test {
+            "test/"
+        } else {
+            ""
+        }
+    }
+}
+
+impl Default for Server {
+    /// The cloud server.
+    fn default() -> Server {
+        Server::new(DEFAULT_API_URL)
+    }
+}
+
+/// Where to get the content of a [`File`], see [`Server::file_location`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FileLocation {
+    /// Download it from this URL.
+    Url(String),
+    /// Read it from this path on the local server's machine.
+    Path(PathBuf),
+}
@@ -0,0 +1,63 @@
+//! Calls about a received message or callback query, with its chat and ids filled in.
+use super::{Error, Execute};
+use crate::bot::methods::{
+    AnswerCallbackQuery, ChatTarget, DeleteMessage, EditMessageText, EditTarget, SendMessage,
+};
+use crate::bot::types::{CallbackQuery, Message};
+use std::borrow::Cow;
+
+impl Message {
+    /// Reply to this message with `text`.
+    pub async fn reply_text<'a, E, T>(&self, bot: &E, text: T) -> Result<Message, Error>
+    where
+        E: Execute,
+        T: Into<Cow<'a, str>>,
+    {
+        let send = SendMessage::new(ChatTarget::Id(self.chat.id), text).reply(self.message_id);
+        bot.execute(&send).await
+    }
+
+    /// Replace the text of this message, one sent by the bot, with `text`.
+    pub async fn edit_text<'a, E, T>(&self, bot: &E, text: T) -> Result<Message, Error>
+    where
+        E: Execute,
+        T: Into<Cow<'a, str>>,
+    {
+        let target = EditTarget::chat(ChatTarget::Id(self.chat.id), self.message_id);
+        let edited = bot.execute(&EditMessageText::new(target, text)).await?;
+        // Only inline messages are answered with True.
+        edited.message().ok_or_else(|| {
+            Error::Json(serde::de::Error::invalid_type(
+                serde::de::Unexpected::Bool(true),
+                &"a message",
+            ))
+        })
+    }
+
+    /// Delete this message, see [`DeleteMessage`] for which the bot may delete.
+    pub async fn delete<E: Execute>(&self, bot: &E) -> Result<(), Error> {
+        let delete = DeleteMessage {
+            chat_id: ChatTarget::Id(self.chat.id),
+            message_id: self.message_id,
+        };
+        bot.execute(&delete).await.map(drop)
+    }
+}
+
+impl CallbackQuery {
+    /// Answer this query without a notification, to stop the progress bar on the button.
+    pub async fn answer<E: Execute>(&self, bot: &E) -> Result<(), Error> {
+        let answer = AnswerCallbackQuery::new(self.id.clone());
+        bot.execute(&answer).await.map(drop)
+    }
+
+    /// Answer this query with `text`, shown as a notification at the top of the chat.
+    pub async fn answer_text<E, T>(&self, bot: &E, text: T) -> Result<(), Error>
+    where
+        E: Execute,
+        T: Into<String>,
+    {
+        let answer = AnswerCallbackQuery::new(self.id.clone()).text(text.into());
+        bot.execute(&answer).await.map(drop)
+    }
+}
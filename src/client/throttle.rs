@@ -0,0 +1,118 @@
+//! Pacing messages under Telegram's flood limits.
+use super::{Error, Execute, MaybeSend};
+use crate::bot::methods::{ChatTarget, Method};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The least time between messages, overall and to one chat.
+///
+/// The defaults follow the [limits Telegram documents](https://core.telegram.org/bots/faq#my-bot-is-hitting-limits-how-do-i-avoid-this):
+/// about 30 messages a second, one a second to a private chat and 20 a minute to a group or
+/// channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimits {
+    pub global: Duration,
+    pub private_chat: Duration,
+    /// For groups, supergroups and channels: negative chat ids and `@username`s.
+    pub group: Duration,
+}
+
+impl Default for RateLimits {
+    fn default() -> RateLimits {
+        RateLimits {
+            global: Duration::from_secs(1) / 30,
+            private_chat: Duration::from_secs(1),
+            group: Duration::from_secs(3),
+        }
+    }
+}
+
+/// An [`Execute`] that holds back methods sending messages, those with a
+/// [`message_chat`](Method::message_chat), until [`RateLimits`] allow them. Other methods go
+/// through at once.
+///
+/// Messages wait their turn in the order they were called, sleeping with `sleep`, the runtime's
+/// timer such as `tokio::time::sleep`. Share one `Throttle` between the tasks of a bot, clones
+/// of it don't share the schedule.
+#[derive(Debug)]
+pub struct Throttle<E, S> {
+    inner: E,
+    sleep: S,
+    limits: RateLimits,
+    schedule: Mutex<Schedule>,
+}
+
+/// When the next message may be sent.
+#[derive(Debug, Default)]
+struct Schedule {
+    global: Option<Instant>,
+    chats: HashMap<ChatTarget<'static>, Instant>,
+}
+
+impl<E, S> Throttle<E, S> {
+    /// Pace with the default [`RateLimits`].
+    pub fn new(inner: E, sleep: S) -> Throttle<E, S> {
+        Throttle {
+            inner,
+            sleep,
+            limits: RateLimits::default(),
+            schedule: Mutex::default(),
+        }
+    }
+
+    pub fn limits(self, limits: RateLimits) -> Throttle<E, S> {
+        Throttle { limits, ..self }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    /// Take the next free slot for a message to `chat` and return how long until it comes.
+    fn reserve(&self, chat: &ChatTarget) -> Duration {
+        let interval = match chat {
+            ChatTarget::Id(id) if id.0 > 0 => self.limits.private_chat,
+            _ => self.limits.group,
+        };
+        let chat = match chat {
+            ChatTarget::Id(id) => ChatTarget::Id(*id),
+            ChatTarget::Username(name) => ChatTarget::Username(name.to_string().into()),
+        };
+        let now = Instant::now();
+        let mut schedule = self.schedule.lock().unwrap();
+        schedule.chats.retain(|_, next| *next > now);
+        let at = [schedule.global, schedule.chats.get(&chat).copied()]
+            .iter()
+            .flatten()
+            .fold(now, |at, next| at.max(*next));
+        schedule.global = Some(at + self.limits.global);
+        schedule.chats.insert(chat, at + interval);
+        at - now
+    }
+}
+
+impl<E, S, F> Execute for Throttle<E, S>
+where
+    E: Execute + Sync,
+    S: Fn(Duration) -> F + Sync,
+    F: Future<Output = ()> + MaybeSend,
+{
+    async fn execute<M: Method + Sync>(&self, method: &M) -> Result<M::Item, Error> {
+        if let Some(chat) = method.message_chat() {
+            let wait = self.reserve(chat);
+            if wait > Duration::ZERO {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    method = M::NAME,
+                    chat_id = %chat,
+                    wait_ms = wait.as_millis() as u64,
+                    "throttling message",
+                );
+                (self.sleep)(wait).await;
+            }
+        }
+        self.inner.execute(method).await
+    }
+}
@@ -0,0 +1,64 @@
+//! How long each call may take.
+use crate::bot::methods::Method;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Time limits for calls, set with [`Bot::timeouts`](super::Bot::timeouts).
+///
+/// A call takes the first that applies: its method's entry in [`methods`](Timeouts::methods),
+/// the long poll of [`GetUpdates`](crate::bot::methods::GetUpdates) plus
+/// [`long_poll_slack`](Timeouts::long_poll_slack), [`upload`](Timeouts::upload) for a call with
+/// files, then [`default`](Timeouts::default). A call past its limit fails with a transport
+/// error. `fetch` doesn't support timeouts.
+///
+/// ```
+/// use std::time::Duration;
+/// use telegram_types::bot::methods::{Method, SendDocument};
+/// use telegram_types::client::Timeouts;
+///
+/// let mut timeouts = Timeouts {
+///     default: Some(Duration::from_secs(10)),
+///     upload: Some(Duration::from_secs(300)),
+///     ..Timeouts::default()
+/// };
+/// timeouts.methods.insert(SendDocument::NAME, Duration::from_secs(120));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeouts {
+    /// For calls nothing else applies to. `None` by default, for no limit but the transport's.
+    pub default: Option<Duration>,
+    /// For calls with files. `None` by default, a large file on a slow link takes a while.
+    pub upload: Option<Duration>,
+    /// Time for a long poll beyond the time Telegram holds it, 10 seconds by default.
+    pub long_poll_slack: Duration,
+    /// For methods by [`NAME`](Method::NAME).
+    pub methods: HashMap<&'static str, Duration>,
+}
+
+impl Default for Timeouts {
+    fn default() -> Timeouts {
+        Timeouts {
+            default: None,
+            upload: None,
+            long_poll_slack: Duration::from_secs(10),
+            methods: HashMap::new(),
+        }
+    }
+}
+
+impl Timeouts {
+    /// The limit for sending `method`, `upload` if with files.
+    pub fn get<M: Method>(&self, method: &M, upload: bool) -> Option<Duration> {
+        if let Some(timeout) = self.methods.get(M::NAME) {
+            return Some(*timeout);
+        }
+        if let Some(poll) = method.long_poll() {
+            return Some(poll + self.long_poll_slack);
+        }
+        if upload {
+            self.upload.or(self.default)
+        } else {
+            self.default
+        }
+    }
+}
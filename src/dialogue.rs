@@ -0,0 +1,300 @@
+//! State of multi-step conversations, kept per user in each chat.
+//!
+//! A flow such as "ask the name, then a photo, then confirm" is a state machine: an enum with a
+//! variant per step. A [`Dialogue`] loads the state of the user a message comes from, the
+//! handler matches on it, answers, and [`update`](Dialogue::update)s it to the next step or
+//! [`exit`](Dialogue::exit)s. A [`Storage`] keeps the states, in memory with [`MemoryStorage`]
+//! or across restarts with [`FileStorage`].
+//!
+//! ```
+//! use std::sync::Arc;
+//! use telegram_types::bot::types::Message;
+//! use telegram_types::dialogue::{Dialogue, MemoryStorage};
+//!
+//! #[derive(Clone)]
+//! enum Signup {
+//!     Name,
+//!     Photo { name: String },
+//! }
+//!
+//! fn handle(storage: Arc<MemoryStorage<Signup>>, message: &Message) -> std::io::Result<()> {
+//!     let dialogue = match Dialogue::of_message(storage, message) {
+//!         Some(dialogue) => dialogue,
+//!         None => return Ok(()),
+//!     };
+//!     match dialogue.get()? {
+//!         None => dialogue.update(Signup::Name),
+//!         Some(Signup::Name) => {
+//!             let name = message.text.clone().unwrap_or_default();
+//!             dialogue.update(Signup::Photo { name })
+//!         }
+//!         Some(Signup::Photo { .. }) if !message.photo.is_empty() => dialogue.exit(),
+//!         Some(Signup::Photo { .. }) => Ok(()),
+//!     }
+//! }
+//! ```
+use crate::bot::types::{CallbackQuery, ChatId, Message, UserId};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Whose conversation a state belongs to: a user in a chat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DialogueKey {
+    pub chat: ChatId,
+    pub user: UserId,
+}
+
+impl DialogueKey {
+    pub fn new(chat: ChatId, user: UserId) -> DialogueKey {
+        DialogueKey { chat, user }
+    }
+
+    /// The chat and sender of `message`, `None` for a message without a sender, such as a
+    /// channel post.
+    pub fn of_message(message: &Message) -> Option<DialogueKey> {
+        let user = message.from.as_ref()?;
+        Some(DialogueKey::new(message.chat.id, user.id))
+    }
+
+    /// The chat of the message with the button and the user who pressed it, `None` for a
+    /// button on an inline message.
+    pub fn of_callback_query(query: &CallbackQuery) -> Option<DialogueKey> {
+        let message = query.message.as_ref()?;
+        Some(DialogueKey::new(message.chat.id, query.from.id))
+    }
+}
+
+/// Where [`Dialogue`]s keep their states.
+pub trait Storage<S>: Send + Sync {
+    /// The state of `key`, `None` if it isn't in a dialogue.
+    fn get(&self, key: DialogueKey) -> io::Result<Option<S>>;
+
+    fn set(&self, key: DialogueKey, state: S) -> io::Result<()>;
+
+    fn remove(&self, key: DialogueKey) -> io::Result<()>;
+}
+
+impl<S, T: Storage<S> + ?Sized> Storage<S> for Arc<T> {
+    fn get(&self, key: DialogueKey) -> io::Result<Option<S>> {
+        (**self).get(key)
+    }
+
+    fn set(&self, key: DialogueKey, state: S) -> io::Result<()> {
+        (**self).set(key, state)
+    }
+
+    fn remove(&self, key: DialogueKey) -> io::Result<()> {
+        (**self).remove(key)
+    }
+}
+
+impl<S> fmt::Debug for dyn Storage<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Storage")
+    }
+}
+
+/// A [`Storage`] in memory. States are lost when the bot stops.
+#[derive(Debug)]
+pub struct MemoryStorage<S> {
+    states: Mutex<HashMap<DialogueKey, S>>,
+}
+
+impl<S> MemoryStorage<S> {
+    pub fn new() -> MemoryStorage<S> {
+        MemoryStorage {
+            states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Number of users in a dialogue.
+    pub fn len(&self) -> usize {
+        lock(&self.states).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<S> Default for MemoryStorage<S> {
+    fn default() -> MemoryStorage<S> {
+        MemoryStorage::new()
+    }
+}
+
+impl<S: Clone + Send> Storage<S> for MemoryStorage<S> {
+    fn get(&self, key: DialogueKey) -> io::Result<Option<S>> {
+        Ok(lock(&self.states).get(&key).cloned())
+    }
+
+    fn set(&self, key: DialogueKey, state: S) -> io::Result<()> {
+        lock(&self.states).insert(key, state);
+        Ok(())
+    }
+
+    fn remove(&self, key: DialogueKey) -> io::Result<()> {
+        lock(&self.states).remove(&key);
+        Ok(())
+    }
+}
+
+/// A [`Storage`] in a JSON file, for states that outlive the process.
+///
+/// The states are read once by [`open`](FileStorage::open) and the whole file is written on
+/// each change, next to it first and renamed over it, so a crash leaves either version but not
+/// half of one. Fine for the dialogues of a bot on one machine; a busy bot wants a database
+/// behind its own [`Storage`].
+#[derive(Debug)]
+pub struct FileStorage<S> {
+    path: PathBuf,
+    states: Mutex<HashMap<DialogueKey, S>>,
+}
+
+impl<S: Serialize + DeserializeOwned> FileStorage<S> {
+    /// Read the states in the file at `path`. A missing file means no states.
+    pub fn open<P: Into<PathBuf>>(path: P) -> io::Result<FileStorage<S>> {
+        let path = path.into();
+        let states = match std::fs::read(&path) {
+            Ok(data) => {
+                let entries: Vec<(ChatId, UserId, S)> = serde_json::from_slice(&data)?;
+                entries
+                    .into_iter()
+                    .map(|(chat, user, state)| (DialogueKey::new(chat, user), state))
+                    .collect()
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(FileStorage {
+            path,
+            states: Mutex::new(states),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn save(&self, states: &HashMap<DialogueKey, S>) -> io::Result<()> {
+        let mut entries: Vec<_> = states
+            .iter()
+            .map(|(key, state)| (key.chat, key.user, state))
+            .collect();
+        entries.sort_by_key(|&(chat, user, _)| (chat, user));
+        let data = serde_json::to_vec(&entries)?;
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        std::fs::write(&temporary, data)?;
+        std::fs::rename(&temporary, &self.path)
+    }
+}
+
+impl<S: Serialize + DeserializeOwned + Clone + Send> Storage<S> for FileStorage<S> {
+    fn get(&self, key: DialogueKey) -> io::Result<Option<S>> {
+        Ok(lock(&self.states).get(&key).cloned())
+    }
+
+    fn set(&self, key: DialogueKey, state: S) -> io::Result<()> {
+        let mut states = lock(&self.states);
+        let previous = states.insert(key, state);
+        let saved = self.save(&states);
+        if saved.is_err() {
+            // Keep memory and file in step.
+            match previous {
+                Some(previous) => states.insert(key, previous),
+                None => states.remove(&key),
+            };
+        }
+        saved
+    }
+
+    fn remove(&self, key: DialogueKey) -> io::Result<()> {
+        let mut states = lock(&self.states);
+        let previous = match states.remove(&key) {
+            Some(previous) => previous,
+            None => return Ok(()),
+        };
+        let saved = self.save(&states);
+        if saved.is_err() {
+            states.insert(key, previous);
+        }
+        saved
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // The map is consistent after each statement, a panic elsewhere doesn't break it.
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The dialogue of one user in one chat, a handle to its state in a [`Storage`].
+pub struct Dialogue<S> {
+    storage: Arc<dyn Storage<S>>,
+    key: DialogueKey,
+}
+
+impl<S> Dialogue<S> {
+    pub fn new(storage: Arc<dyn Storage<S>>, key: DialogueKey) -> Dialogue<S> {
+        Dialogue { storage, key }
+    }
+
+    /// The dialogue of the sender of `message`, see [`DialogueKey::of_message`].
+    pub fn of_message<T>(storage: Arc<T>, message: &Message) -> Option<Dialogue<S>>
+    where
+        T: Storage<S> + 'static,
+    {
+        Some(Dialogue::new(storage, DialogueKey::of_message(message)?))
+    }
+
+    /// The dialogue of the user who pressed a button, see
+    /// [`DialogueKey::of_callback_query`].
+    pub fn of_callback_query<T>(storage: Arc<T>, query: &CallbackQuery) -> Option<Dialogue<S>>
+    where
+        T: Storage<S> + 'static,
+    {
+        Some(Dialogue::new(
+            storage,
+            DialogueKey::of_callback_query(query)?,
+        ))
+    }
+
+    pub fn key(&self) -> DialogueKey {
+        self.key
+    }
+
+    /// The current state, `None` outside a dialogue.
+    pub fn get(&self) -> io::Result<Option<S>> {
+        self.storage.get(self.key)
+    }
+
+    /// Move to `state`, starting the dialogue if needed.
+    pub fn update(&self, state: S) -> io::Result<()> {
+        self.storage.set(self.key, state)
+    }
+
+    /// End the dialogue.
+    pub fn exit(&self) -> io::Result<()> {
+        self.storage.remove(self.key)
+    }
+}
+
+impl<S> Clone for Dialogue<S> {
+    fn clone(&self) -> Dialogue<S> {
+        Dialogue {
+            storage: self.storage.clone(),
+            key: self.key,
+        }
+    }
+}
+
+impl<S> fmt::Debug for Dialogue<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dialogue").field("key", &self.key).finish()
+    }
+}
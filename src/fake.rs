@@ -0,0 +1,132 @@
+//! Made-up users, chats, messages and updates for testing handlers, without JSON fixtures or
+//! struct literals naming every field.
+//!
+//! Required fields get plain values: chats and users are named "Test", messages are dated
+//! [`DATE`], and message and update ids count up from 1 across the process. A positive chat id
+//! is a private chat with the user of the same id, a negative one a supergroup where
+//! [`USER_ID`] is talking. The results are the usual types, so set any other field a test needs.
+//!
+//! ```
+//! use telegram_types::bot::types::{ChatId, UpdateContent};
+//! use telegram_types::fake;
+//!
+//! let mut message = fake::text_message(42, "/start hello");
+//! assert_eq!(message.chat.id, ChatId(42));
+//! message.reply_to_message = Some(Box::new(fake::text_message(42, "earlier")));
+//! let update = fake::update(UpdateContent::Message(message));
+//! ```
+use crate::bot::types::{
+    CallbackQuery, Chat, Message, Time, Update, UpdateContent, UpdateId, User, UserId,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicI64, Ordering};
+
+#[cfg(feature = "inline-mode")]
+use crate::bot::inline_mode::InlineQuery;
+
+/// The sender of messages in groups and of callback queries.
+pub const USER_ID: UserId = UserId(42);
+
+/// The date of every message, 2023-11-14 22:13:20 UTC.
+pub const DATE: i64 = 1_700_000_000;
+
+static NEXT_ID: AtomicI64 = AtomicI64::new(1);
+
+fn next_id() -> i64 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Deserialize, so the result has the defaults of every feature set.
+fn from_json<T: DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).expect("fake data should deserialize")
+}
+
+fn user_json(id: i64) -> Value {
+    json!({"id": id, "is_bot": false, "first_name": "Test"})
+}
+
+fn chat_json(id: i64) -> Value {
+    if id > 0 {
+        json!({"id": id, "type": "private", "first_name": "Test"})
+    } else {
+        json!({"id": id, "type": "supergroup", "title": "Test"})
+    }
+}
+
+fn message_json(chat_id: i64) -> Value {
+    let sender = if chat_id > 0 { chat_id } else { USER_ID.0 };
+    json!({
+        "message_id": next_id(),
+        "from": user_json(sender),
+        "chat": chat_json(chat_id),
+        "date": DATE,
+    })
+}
+
+/// A user who isn't a bot.
+pub fn user(id: i64) -> User {
+    from_json(user_json(id))
+}
+
+/// A private chat for a positive `id`, a supergroup for a negative one.
+pub fn chat(id: i64) -> Chat {
+    from_json(chat_json(id))
+}
+
+/// A message in the chat with `chat_id`, without content.
+pub fn message(chat_id: i64) -> Message {
+    from_json(message_json(chat_id))
+}
+
+/// A text message in the chat with `chat_id`. A command at the start of `text`, such as
+/// `/start`, gets its entity as in a message from Telegram.
+pub fn text_message(chat_id: i64, text: &str) -> Message {
+    let mut message = message_json(chat_id);
+    message["text"] = text.into();
+    if text.starts_with('/') {
+        let command = text.split_whitespace().next().unwrap_or_default();
+        let length = command.encode_utf16().count();
+        message["entities"] = json!([{"type": "bot_command", "offset": 0, "length": length}]);
+    }
+    from_json(message)
+}
+
+/// [`USER_ID`] pressing a button with `data` under a message of the bot in their private chat.
+pub fn callback_query(data: &str) -> CallbackQuery {
+    let mut message = message_json(USER_ID.0);
+    message["from"] = json!({"id": 1, "is_bot": true, "first_name": "Bot"});
+    from_json(json!({
+        "id": next_id().to_string(),
+        "from": user_json(USER_ID.0),
+        "message": message,
+        "chat_instance": "1",
+        "data": data,
+    }))
+}
+
+/// [`USER_ID`] typing `query` after the bot's username.
+#[cfg(feature = "inline-mode")]
+pub fn inline_query(query: &str) -> InlineQuery {
+    from_json(json!({
+        "id": next_id().to_string(),
+        "from": user_json(USER_ID.0),
+        "query": query,
+        "offset": "",
+    }))
+}
+
+/// An update with `content` and the next update id.
+pub fn update(content: UpdateContent) -> Update {
+    Update {
+        update_id: UpdateId(next_id()),
+        content,
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    }
+}
+
+/// The [`Time`] of [`DATE`].
+pub fn date() -> Time {
+    from_json(DATE.into())
+}
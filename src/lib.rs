@@ -1,11 +1,72 @@
 //! Types in the Telegram Bot API and their deserializers
 //!
 //! See also [Telegram Bot API](https://core.telegram.org/bots/api).
-#[cfg(feature = "high")]
+//!
+//! Without the default `std` feature the crate is `no_std` and only needs `alloc`.
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+#[cfg(feature = "axum")]
+extern crate axum;
+#[cfg(feature = "chrono")]
 extern crate chrono;
+#[cfg(feature = "client")]
+extern crate futures_core;
+#[cfg(feature = "login")]
+extern crate hmac;
+#[cfg(feature = "hyper-client")]
+extern crate http;
+#[cfg(feature = "webhook")]
+extern crate http1;
+#[cfg(feature = "axum")]
+extern crate http_body_util;
+#[cfg(feature = "hyper-client")]
+extern crate hyper;
+#[cfg(feature = "reqwest")]
+extern crate reqwest;
+#[cfg(feature = "schemars")]
+extern crate schemars;
 extern crate serde;
-#[macro_use]
-extern crate serde_derive;
+#[cfg(feature = "json")]
 extern crate serde_json;
+#[cfg(feature = "login")]
+extern crate sha2;
+#[cfg(feature = "simd-json")]
+extern crate simd_json;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+#[cfg(feature = "blocking")]
+extern crate ureq;
+#[cfg(feature = "uuid")]
+extern crate uuid;
 
 pub mod bot;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "dialogue")]
+pub mod dialogue;
+#[cfg(feature = "test-util")]
+pub mod fake;
+#[cfg(feature = "login")]
+pub mod login;
+#[cfg(feature = "test-data")]
+pub mod test_data;
+#[cfg(all(feature = "tracing", any(feature = "client", feature = "webhook")))]
+mod trace;
+#[cfg(feature = "webhook")]
+pub mod webhook;
+
+/// The newest Bot API version this crate follows.
+///
+/// Not everything added up to this version is modelled yet, compare with
+/// [`Method::SINCE`](bot::methods::Method::SINCE) to guard calls against older servers.
+pub const SUPPORTED_BOT_API_VERSION: &str = "8.0";
+
+/// Items of the `std` prelude that `no_std` builds have to import from `alloc`.
+mod prelude {
+    pub use alloc::boxed::Box;
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+}
@@ -0,0 +1,406 @@
+//! Checking the authorization data of the [Login Widget](https://core.telegram.org/widgets/login),
+//! of [`LoginUrl`](crate::bot::types::LoginUrl) buttons and of
+//! [Mini Apps](https://core.telegram.org/bots/webapps).
+//!
+//! Telegram adds the user's data and a hash to the URL it opens, or to the `initData` of a Mini
+//! App. Anyone can make such data up, so it is only the user's once [`LoginWidget::verify`] or
+//! [`WebApp::verify`] has checked the hash against the bot token and found the data recent.
+use crate::bot::types::{Chat, Time, User, UserId};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::error::Error as StdError;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The user who logged in, from data checked by [`LoginWidget`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LoginData {
+    pub id: UserId,
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub username: Option<String>,
+    pub photo_url: Option<String>,
+    /// When the user logged in.
+    pub auth_date: Time,
+}
+
+/// Checks login data for a bot.
+///
+/// ```
+/// use telegram_types::login::{LoginError, LoginWidget};
+///
+/// let widget = LoginWidget::new("123456:ABC-DEF1234ghIkl-zyx57W2v1u123ew11");
+/// let query = "id=42&first_name=Ada&auth_date=1700000000&hash=00";
+/// assert!(matches!(widget.verify_query(query), Err(LoginError::InvalidHash)));
+/// ```
+#[derive(Clone)]
+pub struct LoginWidget {
+    secret_key: [u8; 32],
+    max_age: Duration,
+}
+
+impl LoginWidget {
+    /// Data older than this is refused by default.
+    pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Check data signed for the bot with `token`.
+    pub fn new(token: &str) -> LoginWidget {
+        LoginWidget {
+            secret_key: Sha256::digest(token.as_bytes()).into(),
+            max_age: LoginWidget::DEFAULT_MAX_AGE,
+        }
+    }
+
+    /// Refuse data whose `auth_date` is further back than `max_age`, against replays of a
+    /// leaked URL.
+    pub fn max_age(self, max_age: Duration) -> LoginWidget {
+        LoginWidget { max_age, ..self }
+    }
+
+    /// Check the query string of the URL Telegram opened, with or without the leading `?`.
+    pub fn verify_query(&self, query: &str) -> Result<LoginData, LoginError> {
+        self.verify_query_at(query, SystemTime::now())
+    }
+
+    /// Check the query string as of `now`.
+    pub fn verify_query_at(&self, query: &str, now: SystemTime) -> Result<LoginData, LoginError> {
+        let fields = parse_query(query)?;
+        self.verify_at(fields.iter().map(|(k, v)| (&**k, &**v)), now)
+    }
+
+    /// Check decoded fields, such as those of a form or of the widget's JavaScript callback.
+    pub fn verify<'a, I>(&self, fields: I) -> Result<LoginData, LoginError>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        self.verify_at(fields, SystemTime::now())
+    }
+
+    /// Check decoded fields as of `now`.
+    pub fn verify_at<'a, I>(&self, fields: I, now: SystemTime) -> Result<LoginData, LoginError>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let fields = Signed::check(&self.secret_key, fields, self.max_age, now)?;
+        let string = |name| fields.get(name).map(str::to_string);
+        Ok(LoginData {
+            id: UserId(fields.number("id")?),
+            first_name: string("first_name").ok_or(LoginError::Missing("first_name"))?,
+            last_name: string("last_name"),
+            username: string("username"),
+            photo_url: string("photo_url"),
+            auth_date: fields.auth_date(),
+        })
+    }
+}
+
+impl fmt::Debug for LoginWidget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The key signs logins, keep it out of logs.
+        f.debug_struct("LoginWidget")
+            .field("max_age", &self.max_age)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The launch data of a Mini App, from `initData` checked by [`WebApp`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InitData {
+    /// Identifies the session, for `answerWebAppQuery`.
+    pub query_id: Option<String>,
+    /// The user who opened the app.
+    pub user: Option<User>,
+    /// The other user of the private chat the app was opened from with the attachment menu.
+    pub receiver: Option<User>,
+    /// The group or channel the app was opened from with the attachment menu.
+    pub chat: Option<Chat>,
+    /// The type of chat the app was opened from: `sender`, `private`, `group`, `supergroup` or
+    /// `channel`.
+    pub chat_type: Option<String>,
+    /// Identifies the chat the app was opened from.
+    pub chat_instance: Option<String>,
+    /// The `startattach` or `startapp` parameter of the link that opened the app.
+    pub start_param: Option<String>,
+    /// Seconds after which a message can be sent with `answerWebAppQuery`.
+    pub can_send_after: Option<i64>,
+    /// When the app was opened.
+    pub auth_date: Time,
+}
+
+/// Checks the `initData` a Mini App gets from `Telegram.WebApp.initData` and sends to its
+/// server.
+///
+/// ```
+/// use telegram_types::login::{LoginError, WebApp};
+///
+/// let web_app = WebApp::new("123456:ABC-DEF1234ghIkl-zyx57W2v1u123ew11");
+/// let init_data = "query_id=AAH&auth_date=1700000000&hash=00";
+/// assert!(matches!(web_app.verify(init_data), Err(LoginError::InvalidHash)));
+/// ```
+#[derive(Clone)]
+pub struct WebApp {
+    secret_key: [u8; 32],
+    max_age: Duration,
+}
+
+impl WebApp {
+    /// Data older than this is refused by default.
+    pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Check data signed for the bot with `token`.
+    pub fn new(token: &str) -> WebApp {
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"WebAppData").expect("any key length");
+        mac.update(token.as_bytes());
+        WebApp {
+            secret_key: mac.finalize().into_bytes().into(),
+            max_age: WebApp::DEFAULT_MAX_AGE,
+        }
+    }
+
+    /// Refuse data whose `auth_date` is further back than `max_age`. The app is usually open
+    /// for minutes, a day is generous.
+    pub fn max_age(self, max_age: Duration) -> WebApp {
+        WebApp { max_age, ..self }
+    }
+
+    /// Check the `initData` query string.
+    pub fn verify(&self, init_data: &str) -> Result<InitData, LoginError> {
+        self.verify_at(init_data, SystemTime::now())
+    }
+
+    /// Check the `initData` query string as of `now`.
+    pub fn verify_at(&self, init_data: &str, now: SystemTime) -> Result<InitData, LoginError> {
+        let fields = parse_query(init_data)?;
+        let fields = fields.iter().map(|(k, v)| (&**k, &**v));
+        let fields = Signed::check(&self.secret_key, fields, self.max_age, now)?;
+        let string = |name| fields.get(name).map(str::to_string);
+        let user = |name| {
+            fields
+                .get(name)
+                .map(|json| serde_json::from_str::<WebAppUser>(json).map(User::from))
+                .transpose()
+                .map_err(|_| LoginError::Malformed(name))
+        };
+        let chat = fields
+            .get("chat")
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|_| LoginError::Malformed("chat"))?;
+        Ok(InitData {
+            query_id: string("query_id"),
+            user: user("user")?,
+            receiver: user("receiver")?,
+            chat,
+            chat_type: string("chat_type"),
+            chat_instance: string("chat_instance"),
+            start_param: string("start_param"),
+            can_send_after: fields
+                .get("can_send_after")
+                .map(|_| fields.number("can_send_after"))
+                .transpose()?,
+            auth_date: fields.auth_date(),
+        })
+    }
+}
+
+impl fmt::Debug for WebApp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WebApp")
+            .field("max_age", &self.max_age)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A user as Mini Apps get it: `is_bot` only for the receiver, and fields such as `photo_url`
+/// that [`User`] doesn't have.
+#[derive(Deserialize)]
+struct WebAppUser {
+    id: UserId,
+    #[serde(default)]
+    is_bot: bool,
+    first_name: String,
+    last_name: Option<String>,
+    username: Option<String>,
+    language_code: Option<String>,
+    #[serde(default)]
+    is_premium: bool,
+    #[serde(default)]
+    added_to_attachment_menu: bool,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    extra: crate::bot::types::ExtraFields,
+}
+
+impl From<WebAppUser> for User {
+    fn from(user: WebAppUser) -> User {
+        User {
+            id: user.id,
+            is_bot: user.is_bot,
+            first_name: user.first_name,
+            last_name: user.last_name,
+            username: user.username,
+            language_code: user.language_code,
+            is_premium: user.is_premium,
+            added_to_attachment_menu: user.added_to_attachment_menu,
+            can_join_groups: None,
+            can_read_all_group_messages: None,
+            supports_inline_queries: None,
+            can_connect_to_business: None,
+            has_main_web_app: None,
+            #[cfg(feature = "extra-fields")]
+            extra: user.extra,
+        }
+    }
+}
+
+/// Fields whose hash matched and whose `auth_date` is recent.
+struct Signed<'a> {
+    fields: Vec<(&'a str, &'a str)>,
+    auth_date: u64,
+}
+
+impl<'a> Signed<'a> {
+    /// Check the `hash` of `fields`: an HMAC-SHA256 with `secret_key` of the other fields,
+    /// sorted, as `name=value` lines. Then check `auth_date` is no older than `max_age` at `now`.
+    fn check<I>(
+        secret_key: &[u8; 32],
+        fields: I,
+        max_age: Duration,
+        now: SystemTime,
+    ) -> Result<Signed<'a>, LoginError>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut fields: Vec<_> = fields.into_iter().collect();
+        let hash = fields
+            .iter()
+            .position(|(name, _)| *name == "hash")
+            .map(|i| fields.remove(i).1)
+            .ok_or(LoginError::Missing("hash"))?;
+        let hash = hex_decode(hash).ok_or(LoginError::InvalidHash)?;
+        fields.sort_unstable();
+        let check = fields
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret_key).expect("any key length");
+        mac.update(check.as_bytes());
+        mac.verify_slice(&hash)
+            .map_err(|_| LoginError::InvalidHash)?;
+
+        let mut signed = Signed {
+            fields,
+            auth_date: 0,
+        };
+        signed.auth_date = signed.number("auth_date")?.max(0) as u64;
+        let since = UNIX_EPOCH + Duration::from_secs(signed.auth_date);
+        if now.duration_since(since).is_ok_and(|age| age > max_age) {
+            return Err(LoginError::Expired);
+        }
+        Ok(signed)
+    }
+
+    fn get(&self, name: &str) -> Option<&'a str> {
+        self.fields
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| *value)
+    }
+
+    fn number(&self, name: &'static str) -> Result<i64, LoginError> {
+        self.get(name)
+            .ok_or(LoginError::Missing(name))?
+            .parse()
+            .map_err(|_| LoginError::Malformed(name))
+    }
+
+    fn auth_date(&self) -> Time {
+        Time::from_unix(self.auth_date)
+    }
+}
+
+/// A decoded field of a query string.
+type Field<'a> = (Cow<'a, str>, Cow<'a, str>);
+
+/// Split a query string, with or without the leading `?`, into decoded fields.
+fn parse_query(query: &str) -> Result<Vec<Field<'_>>, LoginError> {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Ok((percent_decode(name)?, percent_decode(value)?))
+        })
+        .collect()
+}
+
+fn hex_decode(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() & 1 == 1 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Decode `%XX` escapes and `+` for spaces, as browsers encode query strings.
+fn percent_decode(s: &str) -> Result<Cow<'_, str>, LoginError> {
+    if !s.contains(['%', '+']) {
+        return Ok(Cow::Borrowed(s));
+    }
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let escape = rest
+                    .get(..2)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or(LoginError::MalformedQuery)?;
+                bytes.push(escape);
+                rest = &rest[2..];
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes)
+        .map(Cow::Owned)
+        .map_err(|_| LoginError::MalformedQuery)
+}
+
+/// Why login data was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginError {
+    /// A required field is missing.
+    Missing(&'static str),
+    /// A field isn't what it should be, such as an `id` that isn't a number.
+    Malformed(&'static str),
+    /// The query string has an invalid escape or isn't UTF-8.
+    MalformedQuery,
+    /// The hash doesn't match the data: it wasn't signed for this bot, or was changed.
+    InvalidHash,
+    /// The data is older than [`LoginWidget::max_age`].
+    Expired,
+}
+
+impl fmt::Display for LoginError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoginError::Missing(field) => write!(f, "missing field {}", field),
+            LoginError::Malformed(field) => write!(f, "malformed field {}", field),
+            LoginError::MalformedQuery => f.write_str("malformed query string"),
+            LoginError::InvalidHash => f.write_str("hash doesn't match the data"),
+            LoginError::Expired => f.write_str("login data expired"),
+        }
+    }
+}
+
+impl StdError for LoginError {}
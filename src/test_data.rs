@@ -0,0 +1,62 @@
+//! The JSON fixtures this crate is tested against, for reuse in the tests of other crates.
+//!
+//! Each list pairs a fixture name with its contents. Update fixtures are single
+//! [`Update`](crate::bot::types::Update) objects named after the update kind, media fixtures
+//! are [`Message`](crate::bot::types::Message)s named after the field holding the media, and
+//! error fixtures are whole `ok: false` responses.
+//!
+//! The fixtures look like what Telegram sends, including fields this crate doesn't model, so
+//! they don't deserialize with the `strict` feature.
+
+macro_rules! fixtures {
+    ($dir: expr => $($name: expr),* $(,)?) => {
+        &[$(($name, include_str!(concat!("../tests/json/", $dir, "/", $name, ".json")))),*]
+    };
+}
+
+/// One update of every kind.
+pub const UPDATES: &[(&str, &str)] = fixtures!("updates" =>
+    "message",
+    "edited_message",
+    "channel_post",
+    "edited_channel_post",
+    "inline_query",
+    "chosen_inline_result",
+    "callback_query",
+    "shipping_query",
+    "pre_checkout_query",
+    "poll",
+    "poll_answer",
+    "my_chat_member",
+    "chat_member",
+    "chat_join_request",
+);
+
+/// One message for every media type.
+pub const MEDIA: &[(&str, &str)] = fixtures!("media" =>
+    "photo",
+    "document",
+    "audio",
+    "video",
+    "voice",
+    "video_note",
+    "animation",
+    "sticker",
+    "contact",
+    "location",
+    "venue",
+);
+
+/// Error responses, including ones with [`ResponseParameters`](crate::bot::types::ResponseParameters).
+pub const ERRORS: &[(&str, &str)] = fixtures!("errors" =>
+    "bad_request",
+    "unauthorized",
+    "forbidden",
+    "not_found",
+    "conflict",
+    "too_many_requests",
+    "migrate_to_chat_id",
+);
+
+/// A `getUpdates` response holding one text message.
+pub const GET_UPDATES: &str = include_str!("../tests/json/update.json");
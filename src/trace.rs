@@ -0,0 +1,151 @@
+//! Spans and events for the `tracing` feature.
+#[cfg(feature = "client")]
+use crate::bot::methods::ChatTarget;
+use crate::bot::types::Update;
+#[cfg(feature = "client")]
+use crate::client::Error;
+#[cfg(feature = "client")]
+use std::fmt;
+#[cfg(feature = "client")]
+use std::future::Future;
+#[cfg(all(feature = "client", not(target_arch = "wasm32")))]
+use std::time::Instant;
+#[cfg(feature = "client")]
+use tracing::field::{display, Empty};
+#[cfg(feature = "client")]
+use tracing::{Instrument, Span};
+
+/// Note an update arriving through `source`, long polling or a webhook.
+pub(crate) fn update_received(update: &Update, source: &'static str) {
+    tracing::debug!(
+        update_id = update.update_id.0,
+        kind = update.content.kind(),
+        source,
+        "update received",
+    );
+}
+
+/// Note whether [`Webhook::ensure`](crate::webhook::Webhook::ensure) sets the webhook, and why.
+#[cfg(all(feature = "webhook", feature = "client"))]
+pub(crate) fn webhook_changes(changes: &[crate::webhook::WebhookChange]) {
+    if changes.is_empty() {
+        tracing::debug!("webhook up to date");
+    }
+    for change in changes {
+        tracing::info!(%change, "setting webhook");
+    }
+}
+
+/// The span an update is handled in.
+#[cfg(feature = "client")]
+pub(crate) fn update_span(update: &Update) -> Span {
+    tracing::debug_span!(
+        "telegram_update",
+        update_id = update.update_id.0,
+        kind = update.content.kind(),
+    )
+}
+
+/// The span of one call, recording its latency and how it ended.
+#[cfg(feature = "client")]
+pub(crate) struct CallSpan {
+    span: Span,
+    // `Instant` panics on wasm32, calls there go without latency.
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Instant,
+}
+
+#[cfg(feature = "client")]
+impl CallSpan {
+    pub(crate) fn new(method: &'static str, chat: Option<&ChatTarget>) -> CallSpan {
+        let span = tracing::debug_span!(
+            "telegram_call",
+            method,
+            chat_id = Empty,
+            latency_ms = Empty,
+            error_code = Empty,
+        );
+        if let Some(chat) = chat {
+            span.record("chat_id", display(chat));
+        }
+        CallSpan {
+            span,
+            #[cfg(not(target_arch = "wasm32"))]
+            start: Instant::now(),
+        }
+    }
+
+    pub(crate) fn span(&self) -> &Span {
+        &self.span
+    }
+
+    pub(crate) fn finish<T>(&self, result: &Result<T, Error>) {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.span
+            .record("latency_ms", self.start.elapsed().as_millis() as u64);
+        let _entered = self.span.enter();
+        match result {
+            Ok(_) => tracing::debug!("call succeeded"),
+            Err(e) => {
+                match e {
+                    Error::Api(e) => self.span.record("error_code", e.error_code),
+                    Error::Status(status) => self.span.record("error_code", status),
+                    _ => &self.span,
+                };
+                tracing::warn!(error = %redacted(e), "call failed");
+            }
+        }
+    }
+}
+
+/// `error` for logs. The transports of this crate keep URLs out of their errors, but another
+/// [`Transport`](crate::client::Transport) may not, so the token of any `/bot<token>` in a
+/// transport error is hidden.
+#[cfg(feature = "client")]
+pub(crate) fn redacted(error: &Error) -> Redacted<'_> {
+    Redacted(error)
+}
+
+#[cfg(feature = "client")]
+pub(crate) struct Redacted<'a>(&'a Error);
+
+#[cfg(feature = "client")]
+impl fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !matches!(self.0, Error::Transport(_)) {
+            return self.0.fmt(f);
+        }
+        let mut rest = &*self.0.to_string();
+        while let Some(at) = rest.find("/bot") {
+            let (before, after) = rest.split_at(at + "/bot".len());
+            f.write_str(before)?;
+            // A token is the bot's id, a colon and a secret.
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == ':' || c == '_' || c == '-'))
+                .unwrap_or(after.len());
+            if after[..end].contains(':') {
+                f.write_str("<token>")?;
+                rest = &after[end..];
+            } else {
+                rest = after;
+            }
+        }
+        f.write_str(rest)
+    }
+}
+
+/// Run `call` in a [`CallSpan`].
+#[cfg(feature = "client")]
+pub(crate) async fn instrument<T, F>(
+    method: &'static str,
+    chat: Option<&ChatTarget<'_>>,
+    call: F,
+) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    let span = CallSpan::new(method, chat);
+    let result = call.instrument(span.span().clone()).await;
+    span.finish(&result);
+    result
+}
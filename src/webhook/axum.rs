@@ -0,0 +1,77 @@
+//! Webhook extractor for axum.
+use super::{Webhook, WebhookError};
+use crate::bot::methods::{Method, WebhookReply};
+use crate::bot::types::Update;
+use axum::body::Body;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+
+/// An [`Update`] from a request that passes the [`Webhook`] in the router's state.
+///
+/// A request that doesn't is answered with [`WebhookError::status`].
+///
+/// ```no_run
+/// use axum::routing::post;
+/// use axum::Router;
+/// use telegram_types::webhook::{Webhook, WebhookUpdate};
+///
+/// async fn handle(WebhookUpdate(update): WebhookUpdate) {
+///     println!("{:?}", update.content);
+/// }
+///
+/// let webhook = Webhook::new().secret(std::env::var("WEBHOOK_SECRET").unwrap());
+/// let app: Router = Router::new().route("/telegram", post(handle)).with_state(webhook);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookUpdate(pub Update);
+
+impl<S> FromRequest<S> for WebhookUpdate
+where
+    Webhook: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = WebhookError;
+
+    async fn from_request(request: Request, state: &S) -> Result<WebhookUpdate, WebhookError> {
+        let webhook = Webhook::from_ref(state);
+        webhook.check_headers(request.headers())?;
+        let length = request
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok()?.parse::<usize>().ok());
+        if length.is_some_and(|length| length > webhook.max_body_len()) {
+            return Err(WebhookError::TooLarge);
+        }
+        let body = read(request.into_body(), webhook.max_body_len()).await?;
+        webhook.parse(&body).map(WebhookUpdate)
+    }
+}
+
+async fn read(body: Body, limit: usize) -> Result<Vec<u8>, WebhookError> {
+    match Limited::new(body, limit).collect().await {
+        Ok(body) => Ok(body.to_bytes().to_vec()),
+        Err(e) if e.is::<LengthLimitError>() => Err(WebhookError::TooLarge),
+        Err(e) => Err(WebhookError::Body(e)),
+    }
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(error = %self, status = self.status(), "refused webhook request");
+        let status = StatusCode::from_u16(self.status()).unwrap_or(StatusCode::BAD_REQUEST);
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// Answer the webhook request with the method.
+impl<M: Method> IntoResponse for WebhookReply<M> {
+    fn into_response(self) -> Response {
+        match serde_json::to_vec(&self) {
+            Ok(body) => ([(header::CONTENT_TYPE, "application/json")], body).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Receiving updates through a webhook.
+//!
+//! [`Webhook`] holds what a request has to satisfy: the secret token set with
+//! [`SetWebhook`](crate::bot::methods::SetWebhook), a JSON content type and a size limit.
+//! [`Webhook::receive_http`] reads the [`Update`] in an `http::Request`, and
+//! [`Webhook::receive`] the one in header values and a body, for frameworks with their own
+//! request types. With the `axum` feature [`WebhookUpdate`] extracts it in a handler, which can
+//! answer with a [`WebhookReply`](crate::bot::methods::WebhookReply). With the `client` feature
+//! [`Webhook::ensure`] sets the webhook at startup if Telegram doesn't have it yet.
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "client")]
+mod setup;
+
+#[cfg(feature = "axum")]
+pub use self::axum::WebhookUpdate;
+#[cfg(feature = "client")]
+pub use self::setup::WebhookChange;
+
+use crate::bot::types::Update;
+use http1::header::{HeaderMap, CONTENT_TYPE};
+use std::error::Error as StdError;
+use std::fmt;
+
+/// Header carrying the `secret_token` of [`SetWebhook`](crate::bot::methods::SetWebhook).
+pub const SECRET_TOKEN_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// Checks for webhook requests.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Webhook {
+    secret: Option<String>,
+    max_body: usize,
+}
+
+impl Webhook {
+    /// Updates are a few kilobytes, bodies past this are refused by default.
+    pub const DEFAULT_MAX_BODY: usize = 1 << 20;
+
+    /// Accept any request, up to [`DEFAULT_MAX_BODY`](Webhook::DEFAULT_MAX_BODY) bytes.
+    pub fn new() -> Webhook {
+        Webhook {
+            secret: None,
+            max_body: Webhook::DEFAULT_MAX_BODY,
+        }
+    }
+
+    /// Only accept requests whose [`SECRET_TOKEN_HEADER`] is `secret`.
+    pub fn secret<S: Into<String>>(self, secret: S) -> Webhook {
+        Webhook {
+            secret: Some(secret.into()),
+            ..self
+        }
+    }
+
+    pub fn max_body(self, bytes: usize) -> Webhook {
+        Webhook {
+            max_body: bytes,
+            ..self
+        }
+    }
+
+    pub fn max_body_len(&self) -> usize {
+        self.max_body
+    }
+
+    /// Check the value of [`SECRET_TOKEN_HEADER`], `None` if the header is missing.
+    pub fn check_secret(&self, header: Option<&[u8]>) -> Result<(), WebhookError> {
+        let secret = match &self.secret {
+            Some(secret) => secret.as_bytes(),
+            None => return Ok(()),
+        };
+        match header {
+            Some(header) if constant_time_eq(header, secret) => Ok(()),
+            _ => Err(WebhookError::Unauthorized),
+        }
+    }
+
+    /// Check the `Content-Type` header, `None` if it is missing. Telegram sends JSON.
+    pub fn check_content_type(&self, header: Option<&[u8]>) -> Result<(), WebhookError> {
+        let media_type = header
+            .and_then(|header| header.split(|&b| b == b';').next())
+            .map(|media_type| media_type.trim_ascii());
+        match media_type {
+            Some(media_type) if media_type.eq_ignore_ascii_case(b"application/json") => Ok(()),
+            _ => Err(WebhookError::UnsupportedMediaType),
+        }
+    }
+
+    /// Check the secret token and content type in `headers`.
+    pub fn check_headers(&self, headers: &HeaderMap) -> Result<(), WebhookError> {
+        let header = |name| headers.get(name).map(|value| value.as_bytes());
+        self.check_secret(header(SECRET_TOKEN_HEADER))?;
+        self.check_content_type(header(CONTENT_TYPE.as_str()))
+    }
+
+    /// Check a request by the values of its [`SECRET_TOKEN_HEADER`] and `Content-Type` headers,
+    /// and read the update in its body.
+    pub fn receive(
+        &self,
+        secret: Option<&[u8]>,
+        content_type: Option<&[u8]>,
+        body: &[u8],
+    ) -> Result<Update, WebhookError> {
+        self.check_secret(secret)?;
+        self.check_content_type(content_type)?;
+        self.parse(body)
+    }
+
+    /// Check `request` and read the update in its body.
+    pub fn receive_http<B: AsRef<[u8]>>(
+        &self,
+        request: &http1::Request<B>,
+    ) -> Result<Update, WebhookError> {
+        self.check_headers(request.headers())?;
+        self.parse(request.body().as_ref())
+    }
+
+    /// Read the update in `body`.
+    pub fn parse(&self, body: &[u8]) -> Result<Update, WebhookError> {
+        if body.len() > self.max_body {
+            return Err(WebhookError::TooLarge);
+        }
+        let update = serde_json::from_slice(body).map_err(WebhookError::Malformed)?;
+        #[cfg(feature = "tracing")]
+        crate::trace::update_received(&update, "webhook");
+        Ok(update)
+    }
+}
+
+impl Default for Webhook {
+    fn default() -> Webhook {
+        Webhook::new()
+    }
+}
+
+impl fmt::Debug for Webhook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Keep the secret out of logs.
+        f.debug_struct("Webhook")
+            .field("secret", &self.secret.as_ref().map(|_| ".."))
+            .field("max_body", &self.max_body)
+            .finish()
+    }
+}
+
+/// Comparing every byte doesn't tell an attacker how much of a guess was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// Why a webhook request was refused.
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The secret token is missing or wrong.
+    Unauthorized,
+    /// The body isn't declared as JSON.
+    UnsupportedMediaType,
+    /// The body is longer than [`Webhook::max_body`].
+    TooLarge,
+    /// The body couldn't be read.
+    Body(Box<dyn StdError + Send + Sync>),
+    /// The body isn't an update.
+    Malformed(serde_json::Error),
+}
+
+impl WebhookError {
+    /// HTTP status to answer with.
+    pub fn status(&self) -> u16 {
+        match self {
+            WebhookError::Unauthorized => 401,
+            WebhookError::TooLarge => 413,
+            WebhookError::UnsupportedMediaType => 415,
+            WebhookError::Body(_) | WebhookError::Malformed(_) => 400,
+        }
+    }
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WebhookError::Unauthorized => f.write_str("wrong or missing secret token"),
+            WebhookError::TooLarge => f.write_str("body too large"),
+            WebhookError::UnsupportedMediaType => f.write_str("body isn't JSON"),
+            WebhookError::Body(e) => write!(f, "failed to read body: {}", e),
+            WebhookError::Malformed(e) => write!(f, "not an update: {}", e),
+        }
+    }
+}
+
+impl StdError for WebhookError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            WebhookError::Unauthorized
+            | WebhookError::TooLarge
+            | WebhookError::UnsupportedMediaType => None,
+            WebhookError::Body(e) => Some(&**e),
+            WebhookError::Malformed(e) => Some(e),
+        }
+    }
+}
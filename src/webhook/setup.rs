@@ -0,0 +1,168 @@
+//! Setting the webhook at startup, only when it changed.
+use super::Webhook;
+use crate::bot::methods::{GetWebhookInfo, SetWebhook};
+use crate::bot::types::WebhookInfo;
+use crate::client::{Attachment, Bot, Error, Transport};
+use std::borrow::Cow;
+use std::fmt;
+
+/// What Telegram uses when [`SetWebhook`] doesn't say.
+const DEFAULT_MAX_CONNECTIONS: u32 = 40;
+
+impl Webhook {
+    /// Set the webhook described by `set` unless Telegram already has it, and return what
+    /// changed, nothing if it was up to date. `files` are uploaded with it, such as the
+    /// [`certificate`](SetWebhook::certificate).
+    ///
+    /// The secret token of this webhook goes in `set` unless it has one. With the `tracing`
+    /// feature the changes are logged. See [`WebhookChange::between`] for what is compared.
+    pub async fn ensure<T: Transport + Sync>(
+        &self,
+        bot: &Bot<T>,
+        set: SetWebhook<'_>,
+        files: &[Attachment],
+    ) -> Result<Vec<WebhookChange>, Error> {
+        let mut set = set;
+        if set.secret_token.is_none() {
+            set.secret_token = self.secret.clone().map(Cow::Owned);
+        }
+        let info = bot.call(&GetWebhookInfo).await?;
+        let changes = WebhookChange::between(&info, &set);
+        #[cfg(feature = "tracing")]
+        crate::trace::webhook_changes(&changes);
+        if changes.is_empty() {
+            return Ok(changes);
+        }
+        if files.is_empty() {
+            bot.call(&set).await?;
+        } else {
+            bot.upload(&set, files).await?;
+        }
+        Ok(changes)
+    }
+}
+
+/// A difference between the webhook Telegram has and the one to set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WebhookChange {
+    /// The URL, empty without a webhook.
+    Url {
+        current: String,
+        wanted: String,
+    },
+    /// Whether a custom certificate is used.
+    Certificate {
+        current: bool,
+        wanted: bool,
+    },
+    IpAddress {
+        current: Option<String>,
+        wanted: String,
+    },
+    MaxConnections {
+        current: u32,
+        wanted: u32,
+    },
+    /// The update types, sorted, none for Telegram's default.
+    AllowedUpdates {
+        current: Vec<String>,
+        wanted: Vec<String>,
+    },
+    /// This many updates are waiting and the webhook is set to drop them.
+    PendingUpdates(i64),
+    /// The last delivery was refused as unauthorized, the secret token Telegram sends is wrong.
+    SecretToken,
+}
+
+impl WebhookChange {
+    /// What would change from `info` by sending `set`.
+    ///
+    /// Fields `set` leaves out aren't compared, except `max_connections`, which Telegram resets
+    /// to 40. Telegram doesn't tell the secret token or which certificate it has, so only
+    /// whether there is a certificate is compared, and a secret token is only found out of
+    /// date by a last delivery error of 401 Unauthorized.
+    pub fn between(info: &WebhookInfo, set: &SetWebhook<'_>) -> Vec<WebhookChange> {
+        let mut changes = Vec::new();
+        if info.url != set.url {
+            changes.push(WebhookChange::Url {
+                current: info.url.clone(),
+                wanted: set.url.to_string(),
+            });
+        }
+        let certificate = set.certificate.is_some();
+        if info.has_custom_certificate != certificate {
+            changes.push(WebhookChange::Certificate {
+                current: info.has_custom_certificate,
+                wanted: certificate,
+            });
+        }
+        if let Some(address) = set.ip_address {
+            let wanted = address.to_string();
+            if info.ip_address.as_ref() != Some(&wanted) {
+                changes.push(WebhookChange::IpAddress {
+                    current: info.ip_address.clone(),
+                    wanted,
+                });
+            }
+        }
+        let wanted = set.max_connections.unwrap_or(DEFAULT_MAX_CONNECTIONS);
+        match info.max_connections {
+            Some(current) if current != wanted => {
+                changes.push(WebhookChange::MaxConnections { current, wanted })
+            }
+            _ => {}
+        }
+        if let Some(updates) = &set.allowed_updates {
+            let mut current = info.allowed_updates.clone().unwrap_or_default();
+            current.sort();
+            let mut wanted: Vec<String> = updates
+                .iter()
+                .filter_map(|kind| match serde_json::to_value(kind) {
+                    Ok(serde_json::Value::String(name)) => Some(name),
+                    _ => None,
+                })
+                .collect();
+            wanted.sort();
+            wanted.dedup();
+            if current != wanted {
+                changes.push(WebhookChange::AllowedUpdates { current, wanted });
+            }
+        }
+        if set.drop_pending_updates == Some(true) && info.pending_update_count > 0 {
+            changes.push(WebhookChange::PendingUpdates(info.pending_update_count));
+        }
+        let unauthorized = info
+            .last_error_message
+            .as_ref()
+            .is_some_and(|message| message.contains("401"));
+        if set.secret_token.is_some() && unauthorized {
+            changes.push(WebhookChange::SecretToken);
+        }
+        changes
+    }
+}
+
+impl fmt::Display for WebhookChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WebhookChange::Url { current, wanted } => {
+                write!(f, "url {:?} -> {:?}", current, wanted)
+            }
+            WebhookChange::Certificate { current, wanted } => {
+                write!(f, "custom certificate {} -> {}", current, wanted)
+            }
+            WebhookChange::IpAddress { current, wanted } => {
+                let current = current.as_deref().unwrap_or("none");
+                write!(f, "ip address {} -> {}", current, wanted)
+            }
+            WebhookChange::MaxConnections { current, wanted } => {
+                write!(f, "max connections {} -> {}", current, wanted)
+            }
+            WebhookChange::AllowedUpdates { current, wanted } => {
+                write!(f, "allowed updates {:?} -> {:?}", current, wanted)
+            }
+            WebhookChange::PendingUpdates(count) => write!(f, "drop {} pending updates", count),
+            WebhookChange::SecretToken => f.write_str("secret token refused"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg(all(feature = "full-serde", feature = "json"))]
 extern crate serde;
 extern crate serde_json;
 extern crate telegram_types;
@@ -16,17 +17,17 @@ where
 
 #[test]
 fn get_me() {
-    from_result::<types::User>(&include_str!("json/getMe.json")).unwrap();
+    from_result::<types::User>(include_str!("json/getMe.json")).unwrap();
 }
 
 #[test]
 fn empty_inline_keyboard_markup() {
-    from_result::<types::InlineKeyboardMarkup>(&include_str!("json/empty.json")).unwrap();
+    from_result::<types::InlineKeyboardMarkup>(include_str!("json/empty.json")).unwrap();
 }
 
 #[test]
 fn empty_reply_keyboard_markup() {
-    from_result::<types::ReplyKeyboardMarkup>(&include_str!("json/empty.json")).unwrap();
+    from_result::<types::ReplyKeyboardMarkup>(include_str!("json/empty.json")).unwrap();
 }
 
 #[test]
@@ -39,13 +40,37 @@ fn fake_inline_keyboard_markup() {
 #[test]
 fn fake_illegal_inline_keyboard_markup() {
     let raw = include_str!("json/fake_illegal_inline_keyboard_markup.json");
-    let _markup = from_result::<types::InlineKeyboardMarkup>(&raw).unwrap();
+    let _markup = from_result::<types::InlineKeyboardMarkup>(raw).unwrap();
 }
 
 #[test]
 fn chat() {
     let raw = include_str!("json/chat.json");
     let _chat = serde_json::from_str::<types::Chat>(raw).unwrap();
+
+    let raw = r#"{"id": -42, "type": "channel", "title": "Cocona", "first_name": "Papika"}"#;
+    let chat = serde_json::from_str::<types::Chat>(raw).unwrap();
+    assert!(matches!(chat.kind, types::ChatType::Channel { .. }));
+    let raw = r#"{"id": -42, "type": "forum", "title": "Cocona"}"#;
+    let chat = serde_json::from_str::<types::Chat>(raw).unwrap();
+    assert_eq!(chat.kind, types::ChatType::Unknown);
+    let raw = r#"{"id": 42, "type": "private", "title": "Cocona"}"#;
+    let error = serde_json::from_str::<types::Chat>(raw).unwrap_err();
+    assert!(error.to_string().contains("first_name"));
+}
+
+#[test]
+fn chat_names() {
+    let raw = include_str!("json/message.json");
+    let message = serde_json::from_str::<types::Message>(raw).unwrap();
+    let chat = message.chat;
+    assert!(chat.is_private());
+    assert_eq!(chat.title_or_name().unwrap(), "Homura Akemi");
+    assert_eq!(chat.username(), Some("homura"));
+    let sender_chat = message.sender_chat.unwrap();
+    assert!(sender_chat.is_channel());
+    assert_eq!(sender_chat.title_or_name().unwrap(), "Channel");
+    assert_eq!(sender_chat.username(), None);
 }
 
 #[test]
@@ -54,22 +79,362 @@ fn message() {
     let _chat = serde_json::from_str::<types::Message>(raw).unwrap();
 }
 
+#[test]
+fn text_or_caption() {
+    let raw = include_str!("json/message.json");
+    let mut message = serde_json::from_str::<types::Message>(raw).unwrap();
+    assert_eq!(message.text_or_caption(), Some("/start"));
+    assert_eq!(message.entities_or_caption_entities().len(), 1);
+    message.caption = message.text.take();
+    message.caption_entities = std::mem::take(&mut message.entities);
+    assert_eq!(message.text_or_caption(), Some("/start"));
+    assert_eq!(message.entities_or_caption_entities().len(), 1);
+}
+
 #[test]
 fn update() {
     let raw = include_str!("json/update.json");
-    let _updates = serde_json::from_str::<methods::UpdateList>(&raw).unwrap();
+    let _updates = serde_json::from_str::<methods::UpdateList>(raw).unwrap();
+}
+
+#[test]
+fn poll() {
+    use serde_json::from_str;
+    use types::{PollType, Update, UpdateContent};
+    let raw = include_str!("json/updates/poll.json");
+    let poll = match from_str::<Update>(raw).unwrap().content {
+        UpdateContent::Poll(poll) => poll,
+        _ => panic!("not a poll"),
+    };
+    assert_eq!(poll.question, "Tea or coffee?");
+    assert_eq!(poll.options[0].text, "Tea");
+    assert_eq!(poll.options[1].voter_count, 1);
+    assert_eq!(poll.total_voter_count, 4);
+    assert!(poll.is_anonymous);
+    assert_eq!(poll.kind, PollType::Regular);
+    assert_eq!(poll.correct_option_id, None);
+
+    let raw = r#"{
+        "id": "42", "question": "2 + 2?", "total_voter_count": 1, "is_closed": true,
+        "is_anonymous": false, "type": "quiz", "correct_option_id": 1, "explanation": "Math",
+        "open_period": 60, "close_date": 1700000000,
+        "options": [{"text": "3", "voter_count": 0}, {"text": "4", "voter_count": 1}]
+    }"#;
+    let quiz = from_str::<types::Poll>(raw).unwrap();
+    assert!(quiz.is_quiz());
+    assert_eq!(quiz.correct_option_id, Some(1));
+    assert_eq!(quiz.explanation.as_deref(), Some("Math"));
+    assert_eq!(quiz.open_period, Some(60));
+    assert!(quiz.close_date.is_some());
+    assert!(!quiz.allows_multiple_answers);
+
+    let raw = include_str!("json/updates/poll_answer.json");
+    let answer = match from_str::<Update>(raw).unwrap().content {
+        UpdateContent::PollAnswer(answer) => answer,
+        _ => panic!("not a poll answer"),
+    };
+    assert_eq!(answer.poll_id, poll.id);
+    assert_eq!(answer.user.unwrap().username.as_deref(), Some("homura"));
+    assert_eq!(answer.option_ids, vec![0]);
+    assert!(answer.voter_chat.is_none());
+
+    let raw =
+        r#"{"poll_id": "42", "voter_chat": {"id": -1001, "type": "channel", "title": "News"}}"#;
+    let answer = from_str::<types::PollAnswer>(raw).unwrap();
+    assert_eq!(answer.voter_chat.unwrap().id, types::ChatId(-1001));
+    assert!(answer.option_ids.is_empty());
 }
 
 #[test]
+fn callback_payload() {
+    use serde_json::{from_str, from_value, Value};
+    use types::{CallbackPayload, CallbackQuery, Update, UpdateContent};
+    let raw = include_str!("json/updates/callback_query.json");
+    let query = match from_str::<Update>(raw).unwrap().content {
+        UpdateContent::CallbackQuery(query) => query,
+        _ => panic!("not a callback query"),
+    };
+    assert_eq!(
+        query.payload,
+        CallbackPayload::Data(query.data.clone().unwrap())
+    );
+
+    let mut raw: Value = from_str(raw).unwrap();
+    let raw = raw["callback_query"].as_object_mut().unwrap();
+    raw.remove("data");
+    raw.insert("game_short_name".to_string(), "magica".into());
+    let query: CallbackQuery = from_value(raw.clone().into()).unwrap();
+    assert_eq!(
+        query.payload,
+        CallbackPayload::GameShortName("magica".to_string())
+    );
+    raw.remove("game_short_name");
+    let query: CallbackQuery = from_value(raw.clone().into()).unwrap();
+    assert_eq!(query.payload, CallbackPayload::Unknown);
+}
+
+#[test]
+#[cfg_attr(
+    feature = "strict",
+    ignore = "has the undocumented `left_chat_participant`"
+)]
 fn update_my_chat_member() {
     let raw = include_str!("json/update_my_chat_member.json");
-    let _updates = serde_json::from_str::<methods::UpdateList>(&raw).unwrap();
+    let updates = serde_json::from_str::<methods::UpdateList>(raw).unwrap();
+    let update = &updates.result.unwrap()[0];
+    let updated = match &update.content {
+        types::UpdateContent::MyChatMember(updated) => updated,
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(updated.from.username.as_deref(), Some("upsuper"));
+    assert_eq!(
+        updated.new_chat_member.status,
+        types::ChatMemberStatus::Left
+    );
+    assert!(updated.left());
+    assert!(!updated.joined());
+}
+
+#[test]
+fn chat_member_invite_link() {
+    use serde_json::json;
+    let user = json!({"id": 7, "is_bot": false, "first_name": "Madoka"});
+    let updated = json!({
+        "chat": {"id": -1001, "type": "supergroup", "title": "Club"},
+        "from": user,
+        "date": 1700000000,
+        "old_chat_member": {"user": user, "status": "left"},
+        "new_chat_member": {"user": user, "status": "member"},
+        "invite_link": {
+            "invite_link": "https://t.me/+AbCd…", "creator": user,
+            "creates_join_request": false, "is_primary": false, "is_revoked": false,
+            "name": "Flyers", "member_limit": 100,
+            "subscription_period": 2592000, "subscription_price": 50
+        },
+        "via_chat_folder_invite_link": true
+    });
+    let updated: types::ChatMemberUpdated = serde_json::from_value(updated).unwrap();
+    assert!(updated.joined());
+    assert_eq!(updated.via_chat_folder_invite_link, Some(true));
+    let link = updated.invite_link.unwrap();
+    assert_eq!(link.name.as_deref(), Some("Flyers"));
+    assert_eq!(link.creator.id, types::UserId(7));
+    assert_eq!(link.subscription_price, Some(50));
+
+    let restricted: types::ChatMember = serde_json::from_value(json!({
+        "user": user, "status": "restricted", "is_member": false
+    }))
+    .unwrap();
+    assert!(!restricted.is_present());
+}
+
+#[test]
+fn chat_join_request() {
+    use methods::{ApproveJoinRequest, ChatTarget, DeclineJoinRequest};
+    use serde_json::json;
+    use types::{ChatId, UserId};
+    let user = json!({"id": 7, "is_bot": false, "first_name": "Madoka"});
+    let update = json!({"update_id": 1, "chat_join_request": {
+        "chat": {"id": -1001, "type": "supergroup", "title": "Club"},
+        "from": user,
+        "user_chat_id": 7,
+        "date": 1700000000,
+        "bio": "Magical girl",
+        "invite_link": {
+            "invite_link": "https://t.me/+AbCd…", "creator": user,
+            "creates_join_request": true, "is_primary": false, "is_revoked": false
+        }
+    }});
+    let update: types::Update = serde_json::from_value(update).unwrap();
+    let request = match &update.content {
+        types::UpdateContent::ChatJoinRequest(request) => request,
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(request.bio.as_deref(), Some("Magical girl"));
+    assert!(request.invite_link.as_ref().unwrap().creates_join_request);
+    assert_eq!(update.content.chat_id(), Some(ChatId(-1001)));
+
+    let approve = ApproveJoinRequest::from(request);
+    assert_eq!(
+        approve,
+        ApproveJoinRequest::new(ChatTarget::id(-1001), UserId(7))
+    );
+    let decline = serde_json::to_value(DeclineJoinRequest::from(request)).unwrap();
+    assert_eq!(
+        decline,
+        json!({"chat_id": ChatId(-1001), "user_id": UserId(7)})
+    );
+}
+
+#[test]
+fn ban_chat_member() {
+    use methods::{BanChatMember, BanChatSenderChat, ChatTarget, UnbanChatMember};
+    use serde_json::{json, to_value};
+    use types::{ChatId, UserId};
+    let chat = ChatTarget::username("@club");
+    let ban = BanChatMember::new(chat.clone(), UserId(7))
+        .until(1700000000)
+        .revoke_messages(true);
+    assert_eq!(
+        to_value(&ban).unwrap(),
+        json!({
+            "chat_id": "@club", "user_id": UserId(7), "until_date": 1700000000,
+            "revoke_messages": true
+        })
+    );
+    let unban = UnbanChatMember::new(chat.clone(), UserId(7)).only_if_banned(true);
+    assert_eq!(
+        to_value(&unban).unwrap(),
+        json!({"chat_id": "@club", "user_id": UserId(7), "only_if_banned": true})
+    );
+    let channel = BanChatSenderChat::new(chat, ChatId(-1002));
+    assert_eq!(
+        to_value(&channel).unwrap(),
+        json!({"chat_id": "@club", "sender_chat_id": ChatId(-1002)})
+    );
+}
+
+#[test]
+fn chat_settings() {
+    use methods::{ChatTarget, LeaveChat, SetChatDescription, SetChatPhoto, SetChatTitle};
+    use serde_json::{json, to_value};
+    let id = types::ChatId(-1001);
+    let chat = ChatTarget::Id(id);
+    let title = SetChatTitle::new(chat.clone(), "Club");
+    assert_eq!(
+        to_value(&title).unwrap(),
+        json!({"chat_id": id, "title": "Club"})
+    );
+    let clear = SetChatDescription::clear(chat.clone());
+    assert_eq!(to_value(&clear).unwrap(), json!({"chat_id": id}));
+
+    let (photo, file) = SetChatPhoto::upload(chat.clone(), "logo.jpg", vec![0xff, 0xd8]);
+    assert_eq!(file.file_name, "logo.jpg");
+    assert_eq!(
+        to_value(&photo).unwrap(),
+        json!({"chat_id": id, "photo": "attach://photo"})
+    );
+    assert_eq!(
+        to_value(LeaveChat::new(chat)).unwrap(),
+        json!({"chat_id": id})
+    );
+}
+
+#[test]
+fn pin_chat_message() {
+    use methods::{ChatTarget, PinChatMessage, UnpinAllChatMessages, UnpinChatMessage};
+    use serde_json::{json, to_value};
+    use types::{ChatId, MessageId};
+    let id = ChatId(-1001);
+    let chat = ChatTarget::Id(id);
+    let pin = PinChatMessage::new(chat.clone(), MessageId(3)).disable_notification(true);
+    assert_eq!(
+        to_value(&pin).unwrap(),
+        json!({"chat_id": id, "message_id": MessageId(3), "disable_notification": true})
+    );
+    let latest = UnpinChatMessage::new(chat.clone());
+    assert_eq!(to_value(&latest).unwrap(), json!({"chat_id": id}));
+    let unpin = latest.message(MessageId(3));
+    assert_eq!(
+        to_value(&unpin).unwrap(),
+        json!({"chat_id": id, "message_id": MessageId(3)})
+    );
+    let all = UnpinAllChatMessages::new(chat);
+    assert_eq!(to_value(&all).unwrap(), json!({"chat_id": id}));
+}
+
+#[test]
+fn chat_permissions() {
+    use methods::{ChatTarget, RestrictChatMember, SetChatPermissions};
+    use serde_json::{json, to_value};
+    use types::{ChatId, ChatPermissions, UserId};
+    let mute = RestrictChatMember::mute(ChatTarget::id(-1001), UserId(7)).until(1700000000);
+    assert_eq!(
+        to_value(&mute).unwrap(),
+        json!({
+            "chat_id": ChatId(-1001), "user_id": UserId(7),
+            "permissions": {"can_manage_topics": false}, "until_date": 1700000000
+        })
+    );
+    assert!(!mute.permissions.can_send_anything());
+
+    let text_only = ChatPermissions {
+        can_send_messages: true,
+        ..ChatPermissions::default()
+    };
+    let defaults =
+        SetChatPermissions::new(ChatTarget::id(-1001), text_only).independent_permissions(true);
+    assert_eq!(
+        to_value(&defaults).unwrap(),
+        json!({
+            "chat_id": ChatId(-1001),
+            "permissions": {"can_send_messages": true, "can_manage_topics": false},
+            "use_independent_chat_permissions": true
+        })
+    );
+
+    // Left out, `can_manage_topics` would follow `can_pin_messages`.
+    let pin_only = ChatPermissions {
+        can_pin_messages: true,
+        ..ChatPermissions::default()
+    };
+    let pin_only = SetChatPermissions::new(ChatTarget::id(-1001), pin_only);
+    assert_eq!(
+        to_value(&pin_only).unwrap()["permissions"],
+        json!({"can_pin_messages": true, "can_manage_topics": false})
+    );
+
+    let all = to_value(ChatPermissions::all()).unwrap();
+    assert_eq!(all.as_object().unwrap().len(), 14);
+    assert_eq!(
+        serde_json::from_value::<ChatPermissions>(all).unwrap(),
+        ChatPermissions::all()
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn administrator_rights() {
+    use methods::{ChatTarget, GetMyDefaultAdministratorRights, Method, PromoteChatMember};
+    use serde_json::{json, to_value};
+    use types::{ChatAdministratorRights, ChatId, UserId};
+    let moderator = ChatAdministratorRights {
+        can_delete_messages: true,
+        can_restrict_members: true,
+        ..ChatAdministratorRights::default()
+    };
+    let promote = PromoteChatMember::new(ChatTarget::id(-1001), UserId(7), moderator);
+    assert_eq!(
+        to_value(&promote).unwrap(),
+        json!({
+            "chat_id": ChatId(-1001), "user_id": UserId(7),
+            "can_delete_messages": true, "can_restrict_members": true
+        })
+    );
+    let demote = PromoteChatMember::demote(ChatTarget::id(-1001), UserId(7));
+    assert_eq!(
+        to_value(&demote).unwrap(),
+        json!({"chat_id": ChatId(-1001), "user_id": UserId(7)})
+    );
+
+    let get = GetMyDefaultAdministratorRights::new().for_channels(true);
+    assert_eq!(to_value(&get).unwrap(), json!({"for_channels": true}));
+    let response = br#"{"ok": true, "result": {
+        "is_anonymous": false, "can_manage_chat": true, "can_delete_messages": true,
+        "can_manage_video_chats": false, "can_restrict_members": true,
+        "can_promote_members": false, "can_change_info": false, "can_invite_users": true,
+        "can_post_stories": false, "can_edit_stories": false, "can_delete_stories": false,
+        "can_post_messages": true
+    }}"#;
+    let rights = GetMyDefaultAdministratorRights::response_from_slice(response);
+    let rights = rights.unwrap().unwrap();
+    assert!(rights.can_manage_chat && rights.can_post_messages && !rights.can_pin_messages);
 }
 
 #[test]
 fn failure() {
     let raw = include_str!("json/error.json");
-    let update = serde_json::from_str::<methods::UpdateList>(&raw).unwrap();
+    let update = serde_json::from_str::<methods::UpdateList>(raw).unwrap();
     assert!(update.result.is_none());
     assert_eq!(update.error_code, Some(401));
     assert_eq!(update.description, Some("Unauthorized".to_string()))
@@ -82,6 +447,536 @@ fn send_message() {
     serde_json::to_string(&params).unwrap();
 }
 
+#[test]
+fn dice() {
+    use methods::{ChatTarget, SendDice};
+    use serde_json::{from_value, json, to_value};
+    use types::{ChatId, MessageId};
+    let send = SendDice::new(ChatTarget::id(42));
+    assert_eq!(to_value(&send).unwrap(), json!({"chat_id": ChatId(42)}));
+    let send = send.emoji("🎯").reply(MessageId(7));
+    let value = to_value(&send).unwrap();
+    assert_eq!(value["emoji"], "🎯");
+    assert_eq!(value["reply_to_message_id"], json!(MessageId(7)));
+
+    let mut message: serde_json::Value =
+        serde_json::from_str(include_str!("json/message.json")).unwrap();
+    message["dice"] = json!({"emoji": "🎯", "value": 6});
+    let message = from_value::<types::Message>(message).unwrap();
+    let dice = message.dice.unwrap();
+    assert_eq!(dice.emoji, "🎯");
+    assert_eq!(dice.value, 6);
+}
+
+#[cfg(feature = "passport")]
+#[test]
+fn passport() {
+    use serde_json::{from_value, json, to_value};
+    use telegram_types::bot::passport::{
+        EncryptedPassportElementType as Kind, PassportElementError, SetPassportDataErrors,
+    };
+    let mut message: serde_json::Value =
+        serde_json::from_str(include_str!("json/message.json")).unwrap();
+    message["passport_data"] = json!({
+        "data": [
+            {"type": "phone_number", "phone_number": "15551234567", "hash": "aGFzaDE="},
+            {
+                "type": "driver_license", "data": "ZW5j", "hash": "aGFzaDI=",
+                "front_side": {"file_id": "front", "file_size": 1024, "file_date": 1700000000},
+                "translation": [{
+                    "file_id": "tr", "file_unique_id": "u", "file_size": 10,
+                    "file_date": 1700000000
+                }]
+            }
+        ],
+        "credentials": {"data": "Y3JlZA==", "hash": "aA==", "secret": "cw=="}
+    });
+    let passport = from_value::<types::Message>(message)
+        .unwrap()
+        .passport_data
+        .unwrap();
+    assert_eq!(passport.data[0].kind, Kind::PhoneNumber);
+    assert_eq!(
+        passport.data[0].phone_number.as_deref(),
+        Some("15551234567")
+    );
+    let license = &passport.data[1];
+    assert_eq!(license.kind, Kind::DriverLicense);
+    assert_eq!(license.front_side.as_ref().unwrap().file_size, 1024);
+    assert_eq!(license.translation.len(), 1);
+    assert_eq!(passport.credentials.secret, "cw==");
+
+    let errors = SetPassportDataErrors::new(
+        types::UserId(7),
+        vec![
+            PassportElementError::Data {
+                kind: Kind::PersonalDetails,
+                field_name: "first_name".to_string(),
+                data_hash: "ZGF0YQ==".to_string(),
+                message: "Doesn't match the document".to_string(),
+            },
+            PassportElementError::Files {
+                kind: Kind::UtilityBill,
+                file_hashes: vec!["YQ==".to_string(), "Yg==".to_string()],
+                message: "Unreadable".to_string(),
+            },
+        ],
+    );
+    assert_eq!(
+        to_value(&errors).unwrap()["errors"],
+        json!([
+            {
+                "source": "data", "type": "personal_details", "field_name": "first_name",
+                "data_hash": "ZGF0YQ==", "message": "Doesn't match the document"
+            },
+            {
+                "source": "files", "type": "utility_bill",
+                "file_hashes": ["YQ==", "Yg=="], "message": "Unreadable"
+            }
+        ])
+    );
+}
+
+#[cfg(feature = "payments")]
+#[test]
+fn payments() {
+    use serde_json::{from_value, json, to_value};
+    use telegram_types::bot::payments::{
+        AnswerPreCheckoutQuery, AnswerShippingQuery, LabeledPrice, ShippingOption,
+    };
+    let message: serde_json::Value =
+        serde_json::from_str(include_str!("json/message.json")).unwrap();
+
+    let mut invoice = message.clone();
+    invoice["invoice"] = json!({
+        "title": "Tea", "description": "Green tea", "start_parameter": "tea",
+        "currency": "USD", "total_amount": 145
+    });
+    let invoice = from_value::<types::Message>(invoice)
+        .unwrap()
+        .invoice
+        .unwrap();
+    assert_eq!(invoice.currency, "USD");
+    assert_eq!(invoice.total_amount, 145);
+
+    let mut paid = message;
+    paid["successful_payment"] = json!({
+        "currency": "USD", "total_amount": 145, "invoice_payload": "order-1",
+        "shipping_option_id": "post",
+        "order_info": {"name": "Homura", "shipping_address": {
+            "country_code": "JP", "state": "", "city": "Mitakihara",
+            "street_line1": "1-1", "street_line2": "", "post_code": "100-0001"
+        }},
+        "telegram_payment_charge_id": "tg", "provider_payment_charge_id": "provider"
+    });
+    let paid = from_value::<types::Message>(paid).unwrap();
+    assert!(paid.service.is_some());
+    let payment = paid.successful_payment().unwrap().clone();
+    assert_eq!(payment.invoice_payload, "order-1");
+    let order = payment.order_info.unwrap();
+    assert_eq!(order.shipping_address.unwrap().city, "Mitakihara");
+    assert_eq!(order.email, None);
+
+    let option = ShippingOption::new("post", "Post", vec![LabeledPrice::new("Shipping", 500)]);
+    assert_eq!(
+        to_value(&option).unwrap(),
+        json!({"id": "post", "title": "Post", "prices": [{"label": "Shipping", "amount": 500}]})
+    );
+
+    let answer = AnswerShippingQuery::ok("q".to_string(), vec![option]);
+    assert_eq!(
+        to_value(&answer).unwrap()["shipping_options"][0]["id"],
+        "post"
+    );
+    let refuse = AnswerShippingQuery::error("q".to_string(), "Too far".to_string());
+    assert_eq!(
+        to_value(&refuse).unwrap(),
+        json!({"shipping_query_id": "q", "ok": false, "error_message": "Too far"})
+    );
+    let confirm = AnswerPreCheckoutQuery::ok("c".to_string());
+    assert_eq!(
+        to_value(&confirm).unwrap(),
+        json!({"pre_checkout_query_id": "c", "ok": true})
+    );
+}
+
+#[cfg(feature = "payments")]
+#[test]
+fn shipping_query() {
+    use serde_json::{from_str, json, to_value};
+    use telegram_types::bot::payments::{
+        AnswerPreCheckoutQuery, AnswerShippingQuery, LabeledPrice, ShippingOption,
+    };
+    use types::UpdateContent;
+    let raw = include_str!("json/updates/shipping_query.json");
+    let update = from_str::<types::Update>(raw).unwrap();
+    let query = match update.content {
+        UpdateContent::ShippingQuery(query) => query,
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(query.invoice_payload, "order-1");
+    assert_eq!(query.shipping_address.country_code, "JP");
+    let option = ShippingOption::new("post", "Post", vec![LabeledPrice::new("Shipping", 500)]);
+    let answer = AnswerShippingQuery::ok(query.id, vec![option]);
+    let answer = to_value(&answer).unwrap();
+    assert_eq!(answer["shipping_query_id"], "shipping-1");
+    assert_eq!(answer["ok"], true);
+
+    let raw = include_str!("json/updates/pre_checkout_query.json");
+    let query = match from_str::<types::Update>(raw).unwrap().content {
+        UpdateContent::PreCheckoutQuery(query) => query,
+        other => panic!("{:?}", other),
+    };
+    assert_eq!((query.currency.as_str(), query.total_amount), ("JPY", 1000));
+    assert_eq!(query.order_info, None);
+    assert_eq!(
+        to_value(AnswerPreCheckoutQuery::ok(query.id)).unwrap(),
+        json!({"pre_checkout_query_id": "checkout-1", "ok": true})
+    );
+}
+
+#[cfg(feature = "payments")]
+#[test]
+fn refund_star_payment() {
+    use serde_json::{from_value, json, to_value};
+    use telegram_types::bot::payments::RefundStarPayment;
+    let mut message: serde_json::Value =
+        serde_json::from_str(include_str!("json/message.json")).unwrap();
+    message["refunded_payment"] = json!({
+        "currency": "XTR", "total_amount": 50, "invoice_payload": "sticker-pack",
+        "telegram_payment_charge_id": "stxabc"
+    });
+    let message = from_value::<types::Message>(message).unwrap();
+    let refunded = message.refunded_payment().unwrap().clone();
+    assert_eq!(refunded.currency, "XTR");
+    assert_eq!(refunded.total_amount, 50);
+    assert_eq!(refunded.provider_payment_charge_id, None);
+
+    let refund = RefundStarPayment::new(types::UserId(7), refunded.telegram_payment_charge_id);
+    assert_eq!(
+        to_value(&refund).unwrap(),
+        json!({"user_id": types::UserId(7), "telegram_payment_charge_id": "stxabc"})
+    );
+}
+
+#[cfg(feature = "payments")]
+#[cfg(feature = "json")]
+#[test]
+fn star_transactions() {
+    use methods::Method;
+    use serde_json::{json, to_value};
+    use telegram_types::bot::payments::{
+        GetStarTransactions, RevenueWithdrawalState, TransactionPartner,
+    };
+    let response = json!({"ok": true, "result": {"transactions": [
+        {
+            "id": "stxabc", "amount": 50, "date": 1700000000,
+            "source": {
+                "type": "user", "invoice_payload": "sticker-pack",
+                "user": {"id": 7, "is_bot": false, "first_name": "Madoka"}
+            }
+        },
+        {
+            "id": "w1", "amount": 1000, "date": 1700000100,
+            "receiver": {"type": "fragment", "withdrawal_state": {
+                "type": "succeeded", "date": 1700000200, "url": "https://fragment.com/tx/w1"
+            }}
+        },
+        {"id": "ads", "amount": 10, "date": 1700000300, "receiver": {"type": "telegram_ads"}},
+        {"id": "new", "amount": 1, "date": 1700000400, "source": {"type": "gift_of_the_future"}}
+    ]}});
+    let bytes = serde_json::to_vec(&response).unwrap();
+    let page = GetStarTransactions::response_from_slice(&bytes)
+        .unwrap()
+        .unwrap();
+    assert_eq!(page.transactions.len(), 4);
+    let (paid, withdrawn) = (&page.transactions[0], &page.transactions[1]);
+    let (ads, new) = (&page.transactions[2], &page.transactions[3]);
+
+    assert!(paid.is_incoming());
+    match &paid.source {
+        Some(TransactionPartner::User {
+            user,
+            invoice_payload,
+            ..
+        }) => {
+            assert_eq!(user.id, types::UserId(7));
+            assert_eq!(invoice_payload.as_deref(), Some("sticker-pack"));
+        }
+        other => panic!("{:?}", other),
+    }
+    assert!(!withdrawn.is_incoming());
+    match &withdrawn.receiver {
+        Some(TransactionPartner::Fragment {
+            withdrawal_state: Some(RevenueWithdrawalState::Succeeded { url, .. }),
+        }) => assert_eq!(url, "https://fragment.com/tx/w1"),
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(ads.receiver, Some(TransactionPartner::TelegramAds));
+    assert_eq!(new.source, Some(TransactionPartner::Unknown));
+
+    let get = GetStarTransactions::new().offset(100).limit(50);
+    assert_eq!(to_value(&get).unwrap(), json!({"offset": 100, "limit": 50}));
+}
+
+#[cfg(feature = "payments")]
+#[test]
+fn send_invoice() {
+    use methods::{ChatTarget, Method};
+    use serde_json::{from_value, json, to_value};
+    use telegram_types::bot::payments::{
+        CreateInvoiceLink, InvoiceParams, LabeledPrice, SendInvoice,
+    };
+    use types::ChatId;
+    let prices = vec![LabeledPrice::new("Tea", 120), LabeledPrice::new("Tax", 25)];
+    let invoice = InvoiceParams::new("Tea", "Green tea", "order-1", "USD", prices)
+        .provider_token("token")
+        .tips(100, vec![20, 50])
+        .need_shipping_address(true)
+        .flexible(true);
+    let send = SendInvoice::new(ChatTarget::id(42), invoice.clone()).start_parameter("tea");
+    assert_eq!(send.message_chat(), Some(&ChatTarget::id(42)));
+    let value = to_value(&send).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "chat_id": ChatId(42), "title": "Tea", "description": "Green tea", "payload": "order-1",
+            "provider_token": "token", "currency": "USD",
+            "prices": [{"label": "Tea", "amount": 120}, {"label": "Tax", "amount": 25}],
+            "max_tip_amount": 100, "suggested_tip_amounts": [20, 50],
+            "need_shipping_address": true, "is_flexible": true, "start_parameter": "tea"
+        })
+    );
+    assert_eq!(from_value::<SendInvoice>(value).unwrap(), send);
+
+    let link = to_value(CreateInvoiceLink::from(invoice)).unwrap();
+    assert_eq!(link["payload"], "order-1");
+    assert!(link.get("chat_id").is_none());
+    assert_eq!(CreateInvoiceLink::NAME, "createInvoiceLink");
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn bot_commands() {
+    use methods::{
+        BotCommandScope, ChatTarget, DeleteMyCommands, GetMyCommands, Method, SetMyCommands,
+    };
+    use serde_json::{json, to_value};
+    use types::{BotCommand, ChatId};
+    let commands = vec![BotCommand::new("ban", "Ban a member")];
+    let admins = BotCommandScope::ChatAdministrators {
+        chat_id: ChatTarget::id(-1001),
+    };
+    let set = SetMyCommands::new(commands.clone())
+        .scope(admins.clone())
+        .language_code("en");
+    assert_eq!(
+        to_value(&set).unwrap(),
+        json!({
+            "commands": [{"command": "ban", "description": "Ban a member"}],
+            "scope": {"type": "chat_administrators", "chat_id": ChatId(-1001)},
+            "language_code": "en"
+        })
+    );
+
+    let get = GetMyCommands::new().scope(admins).language_code("en");
+    let response = br#"{
+        "ok": true, "result": [{"command": "ban", "description": "Ban a member"}]
+    }"#;
+    assert_eq!(
+        GetMyCommands::response_from_slice(response)
+            .unwrap()
+            .unwrap(),
+        commands
+    );
+    assert_eq!(
+        to_value(&get).unwrap()["scope"]["type"],
+        "chat_administrators"
+    );
+
+    let delete = DeleteMyCommands::new().scope(BotCommandScope::AllPrivateChats);
+    assert_eq!(
+        to_value(&delete).unwrap(),
+        json!({"scope": {"type": "all_private_chats"}})
+    );
+    assert_eq!(to_value(DeleteMyCommands::new()).unwrap(), json!({}));
+}
+
+#[test]
+fn file_download_url() {
+    use methods::GetFile;
+    use serde_json::{json, to_value};
+    let id = types::FileId("BQAD".to_string());
+    assert_eq!(
+        to_value(GetFile::from(&id)).unwrap(),
+        json!({"file_id": "BQAD"})
+    );
+
+    let mut file = types::File {
+        file_id: id,
+        file_size: Some(1024),
+        file_path: None,
+    };
+    assert_eq!(file.download_url("123:abc"), None);
+    file.file_path = Some("documents/file_1.pdf".to_string());
+    assert_eq!(
+        file.download_url("123:abc").unwrap(),
+        "https://api.telegram.org/file/bot123:abc/documents/file_1.pdf"
+    );
+    assert_eq!(
+        file.download_url_at("http://localhost:8081/", "123:abc")
+            .unwrap(),
+        "http://localhost:8081/file/bot123:abc/documents/file_1.pdf"
+    );
+}
+
+#[test]
+fn send_media() {
+    use methods::{ChatTarget, SendAnimation, SendAudio, SendVideo, SendVideoNote, SendVoice};
+    use serde_json::{json, to_value};
+    use types::{Attachment, ChatId, FileId, FileToSend};
+    let song = Attachment::new("song", "song.mp3", vec![0xff, 0xfb]);
+    let cover = Attachment::new("cover", "cover.jpg", vec![0xff, 0xd8]);
+    let audio = SendAudio::new(ChatTarget::id(42), FileToSend::InputFile(song.input_file()))
+        .performer("Kalafina")
+        .title("Magia")
+        .thumbnail(cover.input_file());
+    assert_eq!(
+        to_value(&audio).unwrap(),
+        json!({
+            "chat_id": ChatId(42), "audio": "attach://song", "performer": "Kalafina",
+            "title": "Magia", "thumbnail": "attach://cover"
+        })
+    );
+
+    let file = || FileToSend::FileId(FileId("BAAD".to_string()));
+    let video = SendVideo::new(ChatTarget::id(42), file())
+        .width(1280)
+        .height(720)
+        .supports_streaming(true);
+    let video = to_value(&video).unwrap();
+    assert_eq!(video["supports_streaming"], true);
+    assert_eq!(video["width"], 1280);
+    let voice = SendVoice::new(ChatTarget::id(42), file()).duration(3);
+    assert_eq!(
+        to_value(&voice).unwrap(),
+        json!({"chat_id": ChatId(42), "voice": "BAAD", "duration": 3})
+    );
+    let animation = SendAnimation::new(ChatTarget::id(42), file())
+        .duration(2)
+        .thumbnail(cover.input_file());
+    assert_eq!(
+        to_value(&animation).unwrap(),
+        json!({
+            "chat_id": ChatId(42), "animation": "BAAD", "duration": 2,
+            "thumbnail": "attach://cover"
+        })
+    );
+    let note = SendVideoNote::new(ChatTarget::id(42), file()).length(240);
+    assert_eq!(to_value(&note).unwrap()["length"], 240);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn live_location() {
+    use methods::{
+        ChatTarget, EditMessageLiveLocation, EditTarget, Method, SendLocation,
+        StopMessageLiveLocation,
+    };
+    use serde_json::{from_value, json, to_value};
+    use types::{ChatId, MessageId};
+    let send = SendLocation::new(ChatTarget::id(42), 35.5, 139.75)
+        .live(900)
+        .heading(90);
+    assert_eq!(
+        to_value(&send).unwrap(),
+        json!({
+            "chat_id": ChatId(42), "latitude": 35.5, "longitude": 139.75,
+            "live_period": 900, "heading": 90
+        })
+    );
+
+    let target = EditTarget::chat(ChatTarget::id(42), MessageId(5));
+    let edit = EditMessageLiveLocation::new(target.clone(), 35.25, 139.5);
+    let edit = edit.horizontal_accuracy(12.5);
+    assert_eq!(
+        to_value(&edit).unwrap(),
+        json!({
+            "chat_id": ChatId(42), "message_id": MessageId(5),
+            "latitude": 35.25, "longitude": 139.5,
+            "horizontal_accuracy": 12.5
+        })
+    );
+    let stop = StopMessageLiveLocation::new(target).clear_reply_markup();
+    assert_eq!(
+        to_value(&stop).unwrap(),
+        json!({
+            "chat_id": ChatId(42), "message_id": MessageId(5),
+            "reply_markup": {"inline_keyboard": []}
+        })
+    );
+    let stopped = StopMessageLiveLocation::response_from_slice(br#"{"ok": true, "result": true}"#);
+    assert_eq!(stopped.unwrap().unwrap(), types::EditResult::Inline(true));
+
+    let mut message: serde_json::Value =
+        serde_json::from_str(include_str!("json/message.json")).unwrap();
+    message["location"] = json!({
+        "latitude": 35.25, "longitude": 139.5, "horizontal_accuracy": 12.5,
+        "live_period": 900, "heading": 90, "proximity_alert_radius": 100
+    });
+    let location = from_value::<types::Message>(message)
+        .unwrap()
+        .location
+        .unwrap();
+    assert!(location.is_live());
+    assert_eq!(location.heading, Some(90));
+    assert_eq!(location.proximity_alert_radius, Some(100));
+}
+
+#[test]
+fn edit_message_caption() {
+    use methods::{ChatTarget, EditMessageCaption, EditTarget, FieldUpdate};
+    use serde_json::{from_str, json, to_value};
+    use types::{ChatId, MessageId};
+    let target = EditTarget::chat(ChatTarget::id(42), MessageId(1));
+    let edit = EditMessageCaption::new(target);
+    assert_eq!(
+        to_value(&edit).unwrap(),
+        json!({"chat_id": ChatId(42), "message_id": MessageId(1)})
+    );
+    let clear = to_value(edit.clone().clear_caption().clear_reply_markup()).unwrap();
+    assert_eq!(clear["caption"], "");
+    assert_eq!(clear["reply_markup"], json!({"inline_keyboard": []}));
+    let set = edit.caption("Cocona".to_string());
+    assert_eq!(to_value(&set).unwrap()["caption"], "Cocona");
+
+    let raw = r#"{"chat_id": 42, "message_id": 1, "caption": null}"#;
+    let edit = from_str::<EditMessageCaption>(raw).unwrap();
+    assert_eq!(edit.caption, FieldUpdate::Clear);
+    assert_eq!(edit.reply_markup, FieldUpdate::Keep);
+
+    let edit = EditMessageCaption::new(EditTarget::inline("Mimi"));
+    assert_eq!(
+        to_value(&edit).unwrap(),
+        json!({"inline_message_id": "Mimi"})
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn edit_result() {
+    use methods::{EditMessageText, Method};
+    use types::EditResult;
+    let message = include_str!("json/message.json");
+    let response = format!(r#"{{"ok": true, "result": {}}}"#, message);
+    let edited = EditMessageText::response_from_slice(response.as_bytes())
+        .unwrap()
+        .unwrap();
+    assert_eq!(edited.message().unwrap().message_id, types::MessageId(1));
+    let inline = EditMessageText::response_from_slice(br#"{"ok": true, "result": true}"#);
+    assert_eq!(inline.unwrap().unwrap(), EditResult::Inline(true));
+}
+
 #[test]
 fn unknown() {
     use serde_json::from_str;
@@ -101,7 +996,7 @@ fn file_id() {
     let file_id = types::FileId("42".to_string());
     let file_to_send = FileToSend::FileId(file_id);
     let file_id_serialized = to_string(&file_to_send).unwrap();
-    let file_id_deserialized = from_str::<FileToSend>(&*file_id_serialized);
+    let file_id_deserialized = from_str::<FileToSend>(&file_id_serialized);
     assert_eq!(file_id_serialized, "\"42\"".to_string());
     assert_eq!(file_id_deserialized.unwrap(), file_to_send);
 }
@@ -115,3 +1010,979 @@ fn input_file() {
     let input_file_serialized = to_string(&input_file).unwrap();
     assert_eq!(input_file_serialized, r#""attach://cocona.webp""#);
 }
+
+#[test]
+fn large_counters() {
+    use serde_json::from_str;
+    use types::{Document, WebhookInfo};
+    let document: Document = from_str(r#"{"file_id":"a","file_size":4294967296}"#).unwrap();
+    assert_eq!(document.file_size, Some(4_294_967_296));
+    let info: WebhookInfo =
+        from_str(r#"{"url":"","has_custom_certificate":false,"pending_update_count":3000000000}"#)
+            .unwrap();
+    assert_eq!(info.pending_update_count, 3_000_000_000);
+}
+
+#[test]
+fn split_text() {
+    use telegram_types::bot::text::split_text;
+    use types::{MessageEntity, MessageEntityKind, Utf16Len, Utf16Offset};
+    let bold = MessageEntity {
+        kind: MessageEntityKind::Bold,
+        offset: Utf16Offset(4),
+        length: Utf16Len(9),
+        url: None,
+        user: None,
+    };
+    // "🎉" takes two UTF-16 code units.
+    let chunks = split_text("one two🎉\nthree", &[bold], 8);
+    let texts: Vec<&str> = chunks.iter().map(|chunk| &*chunk.text).collect();
+    assert_eq!(texts, vec!["one", "two🎉", "three"]);
+    assert!(chunks[0].entities.is_empty());
+    assert_eq!(chunks[1].entities[0].offset, Utf16Offset(0));
+    assert_eq!(chunks[1].entities[0].length, Utf16Len(5));
+    assert_eq!(chunks[2].entities[0].offset, Utf16Offset(0));
+    assert_eq!(chunks[2].entities[0].length, Utf16Len(3));
+
+    let chunks = split_text("abcdef", &[], 4);
+    let texts: Vec<&str> = chunks.iter().map(|chunk| &*chunk.text).collect();
+    assert_eq!(texts, vec!["abcd", "ef"]);
+}
+
+#[test]
+fn keyboard_from_buttons() {
+    use types::{
+        InlineKeyboardButton, InlineKeyboardButtonPressed, InlineKeyboardMarkup,
+        KeyboardLayoutError,
+    };
+    let button = |i: usize| InlineKeyboardButton {
+        text: i.to_string(),
+        pressed: InlineKeyboardButtonPressed::CallbackData(i.to_string()),
+    };
+    let markup = InlineKeyboardMarkup::from_buttons((0..7).map(button), 3).unwrap();
+    let row_sizes: Vec<usize> = markup.inline_keyboard.iter().map(Vec::len).collect();
+    assert_eq!(row_sizes, vec![3, 3, 1]);
+    assert_eq!(
+        InlineKeyboardMarkup::from_buttons((0..7).map(button), 9),
+        Err(KeyboardLayoutError::InvalidRowSize(9))
+    );
+    assert_eq!(
+        InlineKeyboardMarkup::from_buttons((0..101).map(button), 8),
+        Err(KeyboardLayoutError::TooManyButtons(101))
+    );
+}
+
+#[cfg(feature = "inline-mode")]
+#[test]
+fn result_id() {
+    use telegram_types::bot::inline_mode::ResultId;
+    assert!(ResultId::new("").is_err());
+    assert!(ResultId::new("a".repeat(65)).is_err());
+    assert_eq!(
+        ResultId::new("cocona").unwrap(),
+        ResultId("cocona".to_string())
+    );
+    assert_eq!(ResultId::from_hash("papika"), ResultId::from_hash("papika"));
+    assert_ne!(ResultId::from_hash("papika"), ResultId::from_hash("cocona"));
+}
+
+#[cfg(feature = "inline-mode")]
+#[test]
+fn inline_pagination() {
+    use telegram_types::bot::inline_mode::*;
+    let article = |i: usize| {
+        InlineQueryResult::Article(InlineQueryResultArticle {
+            id: ResultId(i.to_string()),
+            title: i.to_string().into(),
+            input_message_content: InputMessageContent::Text(InputTextMessageContent {
+                message_text: i.to_string().into(),
+                parse_mode: None,
+                disable_web_page_preview: None,
+            }),
+            reply_markup: None,
+            url: None,
+            hide_url: None,
+            description: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        })
+    };
+    let id = || InlineQueryId("madoka".to_string());
+    let ids = |answer: &AnswerInlineQuery| -> Vec<String> {
+        answer
+            .results
+            .iter()
+            .map(|result| match result {
+                InlineQueryResult::Article(article) => article.id.0.clone(),
+                _ => unreachable!(),
+            })
+            .collect()
+    };
+
+    let first = AnswerInlineQuery::page(id(), (0..120).map(article), "", 100);
+    assert_eq!(first.results.len(), 50);
+    assert_eq!(first.next_offset.as_deref(), Some("1"));
+    let last = AnswerInlineQuery::page(id(), (0..120).map(article), "2", 50);
+    assert_eq!(
+        ids(&last),
+        (100..120).map(|i| i.to_string()).collect::<Vec<_>>()
+    );
+    assert_eq!(last.next_offset, None);
+    let exact = AnswerInlineQuery::page(id(), (0..20).map(article), "1", 10);
+    assert_eq!(exact.results.len(), 10);
+    assert_eq!(exact.next_offset, None);
+    let foreign = AnswerInlineQuery::page(id(), (0..20).map(article), "~cursor", 10);
+    assert_eq!(ids(&foreign)[0], "0");
+
+    assert_eq!(InlineOffset::parse("-1"), None);
+    assert_eq!(InlineOffset::parse("99999999999"), None);
+    assert!(InlineOffset::cursor("a".repeat(63)).is_ok());
+    assert!(InlineOffset::cursor("a".repeat(64)).is_err());
+    let cursor = InlineOffset::cursor("1234").unwrap();
+    let answer = AnswerInlineQuery::new(id(), vec![article(0)]).next_offset(cursor.clone());
+    assert_eq!(answer.next_offset.as_deref(), Some("~1234"));
+    assert_eq!(InlineOffset::parse("~1234"), Some(cursor));
+}
+
+#[test]
+fn message_entities() {
+    use telegram_types::bot::text::{Command, Mention};
+    use types::{MessageEntity, MessageEntityKind, Utf16Len, Utf16Offset};
+    let raw = include_str!("json/message.json");
+    let mut message = serde_json::from_str::<types::Message>(raw).unwrap();
+    let entity = |kind, offset, length| MessageEntity {
+        kind,
+        offset: Utf16Offset(offset),
+        length: Utf16Len(length),
+        url: None,
+        user: None,
+    };
+    // "🎉" takes two UTF-16 code units.
+    message.text = Some("🎉 /start@homura_bot #madoka @kyubey https://t.me".to_string());
+    message.entities = vec![
+        entity(MessageEntityKind::BotCommand, 3, 17),
+        entity(MessageEntityKind::Hashtag, 21, 7),
+        entity(MessageEntityKind::Mention, 29, 7),
+        entity(MessageEntityKind::Url, 37, 12),
+    ];
+    let commands: Vec<Command> = message.commands().collect();
+    assert_eq!(
+        commands,
+        vec![Command {
+            name: "start",
+            username: Some("homura_bot")
+        }]
+    );
+    assert_eq!(message.hashtags().collect::<Vec<_>>(), vec!["madoka"]);
+    assert_eq!(
+        message.mentions().collect::<Vec<_>>(),
+        vec![Mention::Username("kyubey")]
+    );
+    assert_eq!(message.urls().collect::<Vec<_>>(), vec!["https://t.me"]);
+
+    let text = message.text.as_deref().unwrap();
+    let command = &message.entities[0];
+    assert_eq!(command.byte_range(text), Some(5..22));
+    assert_eq!(command.end(), Utf16Offset(20));
+    assert_eq!(Utf16Offset(3).to_byte(text), Some(5));
+    assert_eq!(Utf16Offset(1).to_byte(text), None);
+    assert_eq!(Utf16Offset::from_byte(text, 5), Some(Utf16Offset(3)));
+    assert_eq!(Utf16Offset::from_byte(text, 1), None);
+    assert_eq!(Utf16Len::of(text), Utf16Len(49));
+}
+
+#[test]
+fn time() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use types::Time;
+    let time = Time::from_unix(1528706565);
+    assert_eq!(time.unix(), 1528706565);
+    let system_time: SystemTime = time.clone().into();
+    assert_eq!(system_time, UNIX_EPOCH + Duration::from_secs(1528706565));
+    assert_eq!(Time::from(system_time), time);
+    let raw = include_str!("json/message.json");
+    let message = serde_json::from_str::<types::Message>(raw).unwrap();
+    assert_eq!(message.date, time);
+}
+
+#[test]
+fn send_media_group_builder() {
+    use methods::{MediaGroupError, SendMediaGroup};
+    use types::{FileId, FileToSend, InputMedia, ParseMode};
+    let file = || FileToSend::FileId(FileId("42".to_string()));
+    let group = SendMediaGroup::builder(methods::ChatTarget::id(42))
+        .media(
+            InputMedia::photo(file())
+                .caption("*Cocona*")
+                .parse_mode(ParseMode::Markdown),
+        )
+        .media(InputMedia::video(file()))
+        .build()
+        .unwrap();
+    let value = serde_json::to_value(&group).unwrap();
+    assert_eq!(value["media"][0]["type"], "photo");
+    assert_eq!(value["media"][0]["caption"], "*Cocona*");
+    assert_eq!(value["media"][1]["type"], "video");
+
+    let single = SendMediaGroup::builder(methods::ChatTarget::id(42))
+        .media(InputMedia::photo(file()))
+        .build();
+    assert_eq!(single, Err(MediaGroupError::InvalidSize(1)));
+    let mixed = SendMediaGroup::builder(methods::ChatTarget::id(42))
+        .media(InputMedia::photo(file()))
+        .media(InputMedia::document(file()))
+        .build();
+    assert_eq!(mixed, Err(MediaGroupError::MixedTypes));
+}
+
+#[test]
+fn media_group_upload() {
+    use methods::{MediaGroupError, SendMediaGroup};
+    use types::{FileId, FileToSend, InputMedia};
+    let (group, files) = SendMediaGroup::upload(methods::ChatTarget::id(42))
+        .file(
+            |file| InputMedia::video(file).caption("Cocona"),
+            "cocona.mp4",
+            vec![1],
+        )
+        .media(InputMedia::photo(FileToSend::FileId(FileId(
+            "42".to_string(),
+        ))))
+        .photo("papika.jpg", vec![2, 3])
+        .disable_notification(true)
+        .build()
+        .unwrap();
+    let value = serde_json::to_value(&group).unwrap();
+    assert_eq!(value["media"][0]["media"], "attach://file0");
+    assert_eq!(value["media"][0]["caption"], "Cocona");
+    assert_eq!(value["media"][1]["media"], "42");
+    assert_eq!(value["media"][2]["media"], "attach://file1");
+    assert_eq!(value["disable_notification"], true);
+    let names: Vec<_> = files
+        .iter()
+        .map(|file| (&*file.name, &*file.file_name))
+        .collect();
+    assert_eq!(names, [("file0", "cocona.mp4"), ("file1", "papika.jpg")]);
+    assert_eq!(files[1].data, [2, 3]);
+
+    let path = std::env::temp_dir().join("telegram-types-media-group.txt");
+    std::fs::write(&path, "cocona").unwrap();
+    let (_, files) = SendMediaGroup::upload(methods::ChatTarget::id(42))
+        .path(InputMedia::document, &path)
+        .unwrap()
+        .document("papika.txt", vec![])
+        .build()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(files[0].file_name, "telegram-types-media-group.txt");
+    assert_eq!(files[0].data, b"cocona");
+
+    let mixed = SendMediaGroup::upload(methods::ChatTarget::id(42))
+        .photo("cocona.jpg", vec![])
+        .audio("papika.mp3", vec![])
+        .build();
+    assert_eq!(mixed, Err(MediaGroupError::MixedTypes));
+}
+
+#[test]
+fn update_content() {
+    use types::{Update, UpdateContent, UpdateId};
+    let raw = include_str!("json/update.json");
+    let updates = serde_json::from_str::<methods::UpdateList>(raw).unwrap();
+    let update = &updates.result.unwrap()[0];
+    assert!(matches!(update.content, UpdateContent::Message(_)));
+    let reserialized = serde_json::to_string(update).unwrap();
+    assert_eq!(
+        &serde_json::from_str::<Update>(&reserialized).unwrap(),
+        update
+    );
+
+    let raw = r#"{"update_id": 42, "business_message": {"Cocona": "Papika"}}"#;
+    if cfg!(all(feature = "strict", not(feature = "extra-fields"))) {
+        assert!(serde_json::from_str::<Update>(raw).is_err());
+        return;
+    }
+    let update = serde_json::from_str::<Update>(raw).unwrap();
+    assert_eq!(update.update_id, UpdateId(42));
+    assert_eq!(update.content, UpdateContent::Unknown);
+    #[cfg(not(feature = "extra-fields"))]
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        serde_json::json!({ "update_id": UpdateId(42) })
+    );
+}
+
+#[test]
+fn strict_chat() {
+    let raw = r#"{"id": -1001, "type": "supergroup", "title": "Tea", "is_tea_party": true}"#;
+    let chat = serde_json::from_str::<types::Chat>(raw);
+    assert_eq!(
+        chat.is_err(),
+        cfg!(all(feature = "strict", not(feature = "extra-fields")))
+    );
+}
+
+fn assert_round_trip<T>(value: &T)
+where
+    T: serde::Serialize + for<'de> serde::Deserialize<'de> + PartialEq + std::fmt::Debug,
+{
+    let serialized = serde_json::to_string(value).unwrap();
+    assert!(!serialized.contains("null"), "{}", serialized);
+    assert_eq!(&serde_json::from_str::<T>(&serialized).unwrap(), value);
+}
+
+#[test]
+#[cfg_attr(
+    feature = "strict",
+    ignore = "has the undocumented `left_chat_participant`"
+)]
+fn round_trip() {
+    assert_round_trip(&from_result::<types::User>(include_str!("json/getMe.json")).unwrap());
+    assert_round_trip(
+        &serde_json::from_str::<types::Chat>(include_str!("json/chat.json")).unwrap(),
+    );
+    assert_round_trip(
+        &serde_json::from_str::<types::Message>(include_str!("json/message.json")).unwrap(),
+    );
+    assert_round_trip(
+        &from_result::<types::InlineKeyboardMarkup>(include_str!(
+            "json/fake_inline_keyboard_markup.json"
+        ))
+        .unwrap(),
+    );
+    for raw in &[
+        include_str!("json/update.json"),
+        include_str!("json/update_my_chat_member.json"),
+    ] {
+        for update in from_result::<Vec<types::Update>>(raw).unwrap() {
+            assert_round_trip(&update);
+        }
+    }
+}
+
+#[cfg(feature = "extra-fields")]
+#[test]
+fn extra_fields() {
+    let raw = r#"{"id": 42, "type": "private", "first_name": "Cocona", "is_forum": true}"#;
+    let chat = serde_json::from_str::<types::Chat>(raw).unwrap();
+    assert_eq!(chat.extra.len(), 1);
+    assert_eq!(chat.extra["is_forum"], true);
+    assert_round_trip(&chat);
+
+    let raw = r#"{"update_id": 42, "business_message": {"Cocona": "Papika"}}"#;
+    let update = serde_json::from_str::<types::Update>(raw).unwrap();
+    assert_eq!(update.extra["business_message"]["Cocona"], "Papika");
+    assert_round_trip(&update);
+}
+
+#[cfg(all(feature = "strict", not(feature = "extra-fields")))]
+#[test]
+fn strict() {
+    let raw = r#"{"id": 42, "is_bot": false, "first_name": "Cocona", "is_forum": true}"#;
+    assert!(serde_json::from_str::<types::User>(raw).is_err());
+}
+
+#[test]
+fn borrowed_update() {
+    use std::borrow::Cow;
+    use telegram_types::bot::borrowed::{ChatKind, Update, UpdateContent};
+    let raw = include_str!("json/update.json");
+    let result: serde_json::Value = serde_json::from_str(raw).unwrap();
+    let raw = serde_json::to_string(&result["result"][0]).unwrap();
+    let update = serde_json::from_str::<Update>(&raw).unwrap();
+    let message = match update.content {
+        UpdateContent::Message(message) => message,
+        _ => panic!("expected a message"),
+    };
+    assert!(matches!(message.text, Some(Cow::Borrowed("/start"))));
+    assert_eq!(message.chat.kind, ChatKind::Private);
+    assert_eq!(message.entities.len(), 1);
+
+    let raw = r#"{"update_id": 42, "poll": {}}"#;
+    let update = serde_json::from_str::<Update>(raw).unwrap();
+    assert_eq!(update.content, UpdateContent::Other);
+}
+
+#[test]
+fn manual_untagged() {
+    use serde_json::from_str;
+    use types::FileToSend;
+    assert!(matches!(
+        from_str::<FileToSend>(r#""https://example.com/cocona.webp""#).unwrap(),
+        FileToSend::Url(_)
+    ));
+    assert!(matches!(
+        from_str::<FileToSend>(r#""attach://cocona.webp""#).unwrap(),
+        FileToSend::InputFile(_)
+    ));
+
+    let markup = from_str::<methods::ReplyMarkup>(r#"{"force_reply": true, "selective": true}"#);
+    assert!(matches!(
+        markup.unwrap(),
+        methods::ReplyMarkup::ForceReply(_)
+    ));
+    let markup = from_str::<methods::ReplyMarkup>(r#"{"keyboard": [[{"text": "Papika"}]]}"#);
+    assert!(matches!(
+        markup.unwrap(),
+        methods::ReplyMarkup::ReplyKeyboard(_)
+    ));
+    let error = from_str::<methods::ReplyMarkup>(r#"{"selective": true}"#).unwrap_err();
+    assert!(error.to_string().contains("force_reply"));
+}
+
+#[cfg(feature = "inline-mode")]
+#[test]
+fn input_message_content() {
+    use serde_json::from_str;
+    use telegram_types::bot::inline_mode::InputMessageContent;
+    let venue = r#"{"latitude": 1.0, "longitude": 2.0, "title": "Cocona", "address": "Papika"}"#;
+    assert!(matches!(
+        from_str::<InputMessageContent>(venue).unwrap(),
+        InputMessageContent::Venue(_)
+    ));
+    let location = r#"{"latitude": 1.0, "longitude": 2.0}"#;
+    assert!(matches!(
+        from_str::<InputMessageContent>(location).unwrap(),
+        InputMessageContent::Location(_)
+    ));
+}
+
+#[cfg(feature = "games")]
+#[test]
+fn inline_keyboard_buttons() {
+    use types::InlineKeyboardButtonPressed::*;
+    let raw = include_str!("json/inline_keyboard_buttons.json");
+    let markup = serde_json::from_str::<types::InlineKeyboardMarkup>(raw).unwrap();
+    let pressed: Vec<_> = markup
+        .inline_keyboard
+        .iter()
+        .flatten()
+        .map(|button| &button.pressed)
+        .collect();
+    assert!(matches!(pressed[0], CallbackGame(_)));
+    assert_eq!(pressed[1], &Pay(true));
+    assert_eq!(pressed[2], &Url("https://example.com/".to_string()));
+    assert_eq!(pressed[3], &CallbackData("cocona".to_string()));
+    assert_eq!(pressed[4], &SwitchInlineQuery(String::new()));
+    assert_eq!(
+        pressed[5],
+        &SwitchInlineQueryCurrentChat("papika".to_string())
+    );
+    assert!(matches!(pressed[6], LoginUrl(_)));
+    assert_eq!(
+        serde_json::to_value(&markup).unwrap(),
+        serde_json::from_str::<serde_json::Value>(raw).unwrap()
+    );
+
+    let unknown = r#"{"text": "App", "web_app": {"url": "https://example.com/"}}"#;
+    let button = serde_json::from_str::<types::InlineKeyboardButton>(unknown).unwrap();
+    assert_eq!(button.pressed, Unknown);
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn json_schema() {
+    let schema = serde_json::to_value(schemars::schema_for!(types::Update)).unwrap();
+    assert_eq!(schema["required"], serde_json::json!(["update_id"]));
+    let message = schema["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|variant| variant["required"] == serde_json::json!(["message"]));
+    assert!(message.is_some());
+
+    let schema = serde_json::to_string(&schemars::schema_for!(types::InlineKeyboardButton));
+    let schema = schema.unwrap();
+    assert!(schema.contains(r#""pay":{"type":"boolean"}"#));
+    assert!(schema.contains("callback_game"));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_serialize() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    fn check<'a, T: Arbitrary<'a> + serde::Serialize>(data: &'a [u8]) {
+        let mut u = Unstructured::new(data);
+        if let Ok(value) = T::arbitrary(&mut u) {
+            serde_json::to_string(&value).unwrap();
+        }
+    }
+
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..256 {
+        let data: Vec<u8> = (0..512)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as u8
+            })
+            .collect();
+        check::<types::Update>(&data);
+        check::<types::InlineKeyboardMarkup>(&data);
+        check::<methods::SendMessage>(&data);
+        check::<methods::SendChatAction>(&data);
+        check::<methods::SendMediaGroup>(&data);
+        #[cfg(feature = "inline-mode")]
+        check::<telegram_types::bot::inline_mode::AnswerInlineQuery>(&data);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_round_trip() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use std::fmt::Debug;
+
+    fn has_null(value: &Value) -> bool {
+        match value {
+            Value::Null => true,
+            Value::Array(values) => values.iter().any(has_null),
+            Value::Object(map) => map.values().any(has_null),
+            _ => false,
+        }
+    }
+
+    /// Counts of the samples checked and skipped.
+    #[derive(Default)]
+    struct Counts {
+        checked: usize,
+        skipped: usize,
+    }
+
+    fn check<'a, T>(data: &'a [u8], counts: &mut Counts)
+    where
+        T: Arbitrary<'a> + Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        let mut u = Unstructured::new(data);
+        if let Ok(value) = T::arbitrary(&mut u) {
+            let json = serde_json::to_value(&value).unwrap();
+            let parsed = serde_json::from_value::<T>(json.clone());
+            if parsed.as_ref().ok() != Some(&value) && has_null(&json) {
+                // Non-finite floats serialize as `null`, neither they nor `NaN != NaN` round-trip.
+                // Unit structs are `null` too, but do round-trip and are checked.
+                counts.skipped += 1;
+                return;
+            }
+            counts.checked += 1;
+            assert_eq!(parsed.as_ref().ok(), Some(&value), "{}: {:?}", json, parsed);
+        }
+    }
+
+    let mut counts = Counts::default();
+    let mut seed = 0x9e37_79b9_7f4a_7c15_u64;
+    for _ in 0..1024 {
+        let data: Vec<u8> = (0..512)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as u8
+            })
+            .collect();
+        check::<methods::GetUpdates>(&data, &mut counts);
+        check::<methods::SetWebhook>(&data, &mut counts);
+        check::<methods::SendMessage>(&data, &mut counts);
+        #[cfg(feature = "stickers")]
+        check::<methods::SendSticker>(&data, &mut counts);
+        check::<methods::SendPhoto>(&data, &mut counts);
+        check::<methods::SendDice>(&data, &mut counts);
+        check::<methods::SendLocation>(&data, &mut counts);
+        check::<methods::EditMessageLiveLocation>(&data, &mut counts);
+        check::<methods::StopMessageLiveLocation>(&data, &mut counts);
+        check::<methods::SendDocument>(&data, &mut counts);
+        check::<methods::SendAudio>(&data, &mut counts);
+        check::<methods::SendVideo>(&data, &mut counts);
+        check::<methods::SendAnimation>(&data, &mut counts);
+        check::<methods::SendVoice>(&data, &mut counts);
+        check::<methods::SendVideoNote>(&data, &mut counts);
+        check::<methods::ForwardMessage>(&data, &mut counts);
+        check::<methods::CopyMessage>(&data, &mut counts);
+        check::<methods::GetUserProfilePhotos>(&data, &mut counts);
+        check::<methods::GetChat>(&data, &mut counts);
+        check::<methods::GetChatMembersCount>(&data, &mut counts);
+        check::<methods::GetChatAdministrators>(&data, &mut counts);
+        check::<methods::GetChatMember>(&data, &mut counts);
+        check::<methods::BanChatMember>(&data, &mut counts);
+        check::<methods::UnbanChatMember>(&data, &mut counts);
+        check::<methods::BanChatSenderChat>(&data, &mut counts);
+        check::<methods::UnbanChatSenderChat>(&data, &mut counts);
+        check::<methods::RestrictChatMember>(&data, &mut counts);
+        check::<methods::SetChatPermissions>(&data, &mut counts);
+        check::<methods::PromoteChatMember>(&data, &mut counts);
+        check::<methods::SetChatTitle>(&data, &mut counts);
+        check::<methods::SetChatDescription>(&data, &mut counts);
+        check::<methods::SetChatPhoto>(&data, &mut counts);
+        check::<methods::DeleteChatPhoto>(&data, &mut counts);
+        check::<methods::LeaveChat>(&data, &mut counts);
+        check::<methods::PinChatMessage>(&data, &mut counts);
+        check::<methods::UnpinChatMessage>(&data, &mut counts);
+        check::<methods::UnpinAllChatMessages>(&data, &mut counts);
+        check::<methods::SetMyDefaultAdministratorRights>(&data, &mut counts);
+        check::<methods::GetMyDefaultAdministratorRights>(&data, &mut counts);
+        check::<methods::AnswerCallbackQuery>(&data, &mut counts);
+        #[cfg(feature = "payments")]
+        check::<telegram_types::bot::payments::AnswerShippingQuery>(&data, &mut counts);
+        #[cfg(feature = "payments")]
+        check::<telegram_types::bot::payments::AnswerPreCheckoutQuery>(&data, &mut counts);
+        #[cfg(feature = "payments")]
+        check::<telegram_types::bot::payments::SendInvoice>(&data, &mut counts);
+        #[cfg(feature = "payments")]
+        check::<telegram_types::bot::payments::CreateInvoiceLink>(&data, &mut counts);
+        #[cfg(feature = "payments")]
+        check::<telegram_types::bot::payments::RefundStarPayment>(&data, &mut counts);
+        #[cfg(feature = "payments")]
+        check::<telegram_types::bot::payments::GetStarTransactions>(&data, &mut counts);
+        #[cfg(feature = "passport")]
+        check::<telegram_types::bot::passport::SetPassportDataErrors>(&data, &mut counts);
+        check::<methods::SendMediaGroup>(&data, &mut counts);
+        check::<methods::EditMessageText>(&data, &mut counts);
+        check::<methods::EditMessageCaption>(&data, &mut counts);
+        check::<methods::EditMessageMedia>(&data, &mut counts);
+        check::<methods::EditMessageReplyMarkup>(&data, &mut counts);
+        check::<methods::DeleteMessage>(&data, &mut counts);
+        check::<methods::ApproveJoinRequest>(&data, &mut counts);
+        check::<methods::DeclineJoinRequest>(&data, &mut counts);
+        check::<methods::SetMyCommands>(&data, &mut counts);
+        check::<methods::GetMyCommands>(&data, &mut counts);
+        check::<methods::DeleteMyCommands>(&data, &mut counts);
+        check::<methods::GetMe>(&data, &mut counts);
+        check::<methods::DeleteWebhook>(&data, &mut counts);
+        check::<methods::GetWebhookInfo>(&data, &mut counts);
+        check::<methods::LogOut>(&data, &mut counts);
+        check::<methods::Close>(&data, &mut counts);
+        check::<methods::GetFile>(&data, &mut counts);
+        check::<methods::GetUserProfilePhotos>(&data, &mut counts);
+        #[cfg(feature = "inline-mode")]
+        check::<telegram_types::bot::inline_mode::AnswerInlineQuery>(&data, &mut counts);
+    }
+    assert!(
+        counts.skipped * 100 < counts.checked,
+        "{} skipped",
+        counts.skipped
+    );
+}
+
+#[test]
+fn hash_keys() {
+    use std::collections::HashSet;
+    let markup = from_result::<types::InlineKeyboardMarkup>(include_str!("json/empty.json"));
+    let user = from_result::<types::User>(include_str!("json/getMe.json")).unwrap();
+    let mut seen = HashSet::new();
+    assert!(seen.insert((markup.unwrap(), user.clone())));
+    let empty = types::InlineKeyboardMarkup {
+        inline_keyboard: vec![],
+    };
+    assert!(!seen.insert((empty, user)));
+}
+
+#[test]
+fn enum_tags() {
+    fn tags<T: serde::Serialize>(values: &[T]) -> Vec<String> {
+        let tag = |v| match serde_json::to_value(v).unwrap() {
+            Value::String(s) => s,
+            other => panic!("{} is not a string", other),
+        };
+        values.iter().map(tag).collect()
+    }
+
+    use methods::UpdateTypes::*;
+    let update_types = [
+        Message,
+        EditedMessage,
+        ChannelPost,
+        EditedChannelPost,
+        InlineQuery,
+        ChosenInlineResult,
+        CallbackQuery,
+        ShippingQuery,
+        PreCheckoutQuery,
+        MessageReaction,
+        MessageReactionCount,
+        MyChatMember,
+        ChatMember,
+        ChatJoinRequest,
+    ];
+    assert_eq!(
+        tags(&update_types),
+        [
+            "message",
+            "edited_message",
+            "channel_post",
+            "edited_channel_post",
+            "inline_query",
+            "chosen_inline_result",
+            "callback_query",
+            "shipping_query",
+            "pre_checkout_query",
+            "message_reaction",
+            "message_reaction_count",
+            "my_chat_member",
+            "chat_member",
+            "chat_join_request",
+        ]
+    );
+
+    use types::ParseMode;
+    let parse_modes = [ParseMode::Markdown, ParseMode::MarkdownV2, ParseMode::HTML];
+    assert_eq!(tags(&parse_modes), ["Markdown", "MarkdownV2", "HTML"]);
+
+    use types::ChatMemberStatus::*;
+    assert_eq!(
+        tags(&[Creator, Administrator, Member, Restricted, Left, Kicked]),
+        [
+            "creator",
+            "administrator",
+            "member",
+            "restricted",
+            "left",
+            "kicked"
+        ]
+    );
+
+    use types::MessageEntityKind::*;
+    let entity_kinds = [
+        Mention,
+        Hashtag,
+        Cashtag,
+        BotCommand,
+        Url,
+        Email,
+        PhoneNumber,
+        Bold,
+        Italic,
+        Code,
+        Pre,
+        TextLink,
+        TextMention,
+    ];
+    assert_eq!(
+        tags(&entity_kinds),
+        [
+            "mention",
+            "hashtag",
+            "cashtag",
+            "bot_command",
+            "url",
+            "email",
+            "phone_number",
+            "bold",
+            "italic",
+            "code",
+            "pre",
+            "text_link",
+            "text_mention",
+        ]
+    );
+}
+
+#[test]
+fn string_ids() {
+    let id = serde_json::from_str::<types::ChatId>(r#""-1001234567890123""#).unwrap();
+    assert_eq!(id, types::ChatId(-1001234567890123));
+    assert_eq!(
+        serde_json::from_str::<types::ChatId>("-1001234567890123").unwrap(),
+        id
+    );
+    assert!(serde_json::from_str::<types::ChatId>(r#""@channel""#).is_err());
+    let expected = if cfg!(feature = "string-ids") {
+        r#""-1001234567890123""#
+    } else {
+        "-1001234567890123"
+    };
+    assert_eq!(serde_json::to_string(&id).unwrap(), expected);
+
+    let target = serde_json::from_str::<methods::ChatTarget>(r#""@channel""#).unwrap();
+    assert_eq!(target, methods::ChatTarget::username("@channel"));
+}
+
+#[test]
+fn ids_in_binary_formats() {
+    let id = types::ChatId(-1001234567890123);
+    let bytes = bincode::serialize(&id).unwrap();
+    assert_eq!(bytes, (-1001234567890123i64).to_le_bytes());
+    assert_eq!(bincode::deserialize::<types::ChatId>(&bytes).unwrap(), id);
+    let id = types::UpdateId(65331110);
+    let bytes = bincode::serialize(&id).unwrap();
+    assert_eq!(bincode::deserialize::<types::UpdateId>(&bytes).unwrap(), id);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn parse_response() {
+    use methods::{GetMe, GetUpdates, Method};
+    let raw = include_bytes!("json/update.json");
+    let updates = GetUpdates::response_from_slice(raw).unwrap().unwrap();
+    assert!(!updates.is_empty());
+    let error = GetMe::response_from_slice(include_bytes!("json/error.json")).unwrap();
+    assert!(error.is_err());
+
+    #[cfg(feature = "simd-json")]
+    for raw in &[
+        &include_bytes!("json/update.json")[..],
+        include_bytes!("json/update_my_chat_member.json"),
+    ] {
+        let expected = GetUpdates::response_from_slice(raw).unwrap().unwrap();
+        let mut bytes = raw.to_vec();
+        let updates = GetUpdates::response_from_simd_slice(&mut bytes)
+            .unwrap()
+            .unwrap();
+        assert_eq!(updates, expected);
+    }
+}
+
+#[test]
+fn method_url() {
+    use methods::{GetMe, Method};
+    assert_eq!(GetMe::url("42:T"), "https://api.telegram.org/bot42:T/getMe");
+    assert_eq!(
+        GetMe::url_in("http://localhost:8081/", "42:T"),
+        "http://localhost:8081/bot42:T/getMe"
+    );
+}
+
+#[test]
+fn method_since() {
+    use methods::{ApproveJoinRequest, GetChat, GetMe, Method, UnbanChatMember};
+    fn version(v: &str) -> Vec<u32> {
+        v.split('.').map(|x| x.parse().unwrap()).collect()
+    }
+    assert_eq!(GetMe::SINCE, "1.0");
+    assert_eq!(ApproveJoinRequest::SINCE, "5.4");
+    assert_eq!(GetChat::SINCE, "2.1");
+    assert_eq!(UnbanChatMember::SINCE, "2.0");
+    #[cfg(feature = "payments")]
+    assert_eq!(
+        telegram_types::bot::payments::AnswerShippingQuery::SINCE,
+        "3.0"
+    );
+    let supported = version(telegram_types::SUPPORTED_BOT_API_VERSION);
+    assert!(version(ApproveJoinRequest::SINCE) <= supported);
+}
+
+#[test]
+fn message_extras() {
+    let raw = include_str!("json/message.json");
+    let message = serde_json::from_str::<types::Message>(raw).unwrap();
+    assert!(message.forward.is_none());
+    assert!(message.service.is_none());
+    assert!(message.new_chat_members().is_empty());
+
+    let raw = r#"{
+        "message_id": 7,
+        "date": 1600000000,
+        "chat": {"id": -42, "type": "group", "title": "Cocona"},
+        "forward_date": 1500000000,
+        "forward_sender_name": "Papika",
+        "new_chat_title": "Flip Flappers",
+        "group_chat_created": true
+    }"#;
+    let message = serde_json::from_str::<types::Message>(raw).unwrap();
+    assert_eq!(message.forward_sender_name(), Some("Papika"));
+    assert_eq!(
+        message.forward_date(),
+        Some(&types::Time::from_unix(1500000000))
+    );
+    assert_eq!(message.new_chat_title(), Some("Flip Flappers"));
+    assert!(message.group_chat_created());
+    assert!(!message.delete_chat_photo());
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["forward_sender_name"], "Papika");
+    assert_eq!(value["group_chat_created"], true);
+    assert_eq!(
+        serde_json::from_value::<types::Message>(value).unwrap(),
+        message
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn raw_update() {
+    let raw = include_str!("json/update.json");
+    let response = serde_json::from_str::<methods::TelegramResult<Vec<types::RawUpdate>>>(raw);
+    let expected = from_result::<Vec<types::Update>>(raw).unwrap();
+    let updates = response.unwrap().into_result().unwrap();
+    assert_eq!(updates.len(), expected.len());
+    for (update, expected) in updates.iter().zip(&expected) {
+        assert_eq!(update.update_id, expected.update_id);
+        assert_eq!(update.kind(), Some("message"));
+        assert_eq!(expected.content.kind(), Some("message"));
+        assert_eq!(&update.parse().unwrap(), expected);
+    }
+
+    let raw = r#"{"update_id": 3, "message_reaction": {"chat": {}}}"#;
+    let update = serde_json::from_str::<types::RawUpdate>(raw).unwrap();
+    assert_eq!(update.kind(), Some("message_reaction"));
+    assert_eq!(update.content().unwrap().get(), r#"{"chat": {}}"#);
+    if !cfg!(feature = "strict") {
+        assert_eq!(
+            update.parse().unwrap().content,
+            types::UpdateContent::Unknown
+        );
+    }
+
+    let raw = r#"{"upd\u0061te_id": 4, "message_re\u0061ction": {"chat": {}}}"#;
+    let update = serde_json::from_str::<types::RawUpdate>(raw).unwrap();
+    assert_eq!(update.update_id, types::UpdateId(4));
+    assert_eq!(update.kind(), Some("message_reaction"));
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+#[cfg_attr(
+    feature = "strict",
+    ignore = "fixtures have fields like `file_unique_id`"
+)]
+fn test_data() {
+    use telegram_types::test_data::{ERRORS, GET_UPDATES, MEDIA, UPDATES};
+    for (kind, raw) in UPDATES {
+        let update = serde_json::from_str::<types::Update>(raw).unwrap();
+        let value = serde_json::to_value(&update).unwrap();
+        assert!(
+            value.get(kind).is_some(),
+            "{} parsed as {:?}",
+            kind,
+            update.content
+        );
+    }
+    for (field, raw) in MEDIA {
+        let message = serde_json::from_str::<types::Message>(raw).unwrap();
+        let value = serde_json::to_value(&message).unwrap();
+        assert!(
+            value.get(field).is_some(),
+            "{} missing from {:?}",
+            field,
+            message
+        );
+    }
+    for (name, raw) in ERRORS {
+        let response = serde_json::from_str::<methods::TelegramResult<Value>>(raw).unwrap();
+        let error = response.into_result().unwrap_err();
+        assert_ne!(error.error_code, 0, "{}", name);
+    }
+    let (_, raw) = ERRORS
+        .iter()
+        .find(|(name, _)| *name == "too_many_requests")
+        .unwrap();
+    let error = serde_json::from_str::<methods::TelegramResult<Value>>(raw).unwrap();
+    let parameters = error.into_result().unwrap_err().parameters.unwrap();
+    assert_eq!(parameters.retry_after, Some(35));
+    assert!(!from_result::<Vec<types::Update>>(GET_UPDATES)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn webhook_reply() {
+    let send = methods::SendMessage::new(methods::ChatTarget::id(42), "hello");
+    let reply = serde_json::to_value(methods::WebhookReply(send)).unwrap();
+    assert_eq!(reply["method"], "sendMessage");
+    assert_eq!(reply["text"], "hello");
+    // Methods without parameters are only their name.
+    let reply = serde_json::to_value(methods::WebhookReply(methods::GetMe)).unwrap();
+    assert_eq!(reply, serde_json::json!({"method": "getMe"}));
+}
@@ -0,0 +1,1548 @@
+#![cfg(feature = "client")]
+use telegram_types::bot::methods::GetMe;
+use telegram_types::client::{Bot, Error, Execute, Request, Response, Transport};
+
+#[test]
+fn debug_hides_token() {
+    let bot = Bot::with_transport("42:TOKEN", ());
+    assert!(!format!("{:?}", bot).contains("TOKEN"));
+}
+
+/// Answers every request with a fixture and remembers the last one.
+struct Canned(&'static str, std::sync::Mutex<Option<Request>>);
+
+impl Transport for Canned {
+    type Error = std::io::Error;
+
+    async fn send(&self, request: Request) -> Result<Response, std::io::Error> {
+        *self.1.lock().unwrap() = Some(request);
+        Ok(Response {
+            status: 200,
+            body: self.0.as_bytes().to_vec(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn custom_transport() {
+    let canned = Canned(include_str!("json/getMe.json"), Default::default());
+    let bot = Bot::with_transport("42:TOKEN", canned);
+    let me = bot.execute(&GetMe).await.unwrap();
+    assert!(me.is_bot);
+    let request = bot.transport().1.lock().unwrap().take().unwrap();
+    assert_eq!(request.url, "https://api.telegram.org/bot42:TOKEN/getMe");
+    assert_eq!(request.body, b"null");
+}
+
+#[tokio::test]
+async fn bad_gateway_page() {
+    let page = Canned("<html>Bad Gateway</html>", Default::default());
+    let bot = Bot::with_transport("42:TOKEN", page);
+    // `Canned` answers 200, a proxy page with a success status is a JSON error.
+    assert!(matches!(bot.call(&GetMe).await, Err(Error::Json(_))));
+}
+
+#[tokio::test]
+async fn middleware() {
+    use telegram_types::client::{InspectResponse, Layered, MapRequest};
+
+    let statuses: &'static std::sync::Mutex<Vec<u16>> = Box::leak(Default::default());
+    let canned = Canned(include_str!("json/getMe.json"), Default::default());
+    let header = ("X-Proxy-Key".to_string(), "secret".to_string());
+    let add_header = MapRequest(move |request: &mut Request| request.headers.push(header.clone()));
+    let record_status = InspectResponse(|result: &Result<Response, std::io::Error>| {
+        let status = result.as_ref().unwrap().status;
+        statuses.lock().unwrap().push(status);
+    });
+    let transport = Layered::new(Layered::new(canned, add_header), record_status);
+    let bot = Bot::with_transport("42:TOKEN", transport);
+    bot.execute(&GetMe).await.unwrap();
+    assert_eq!(*statuses.lock().unwrap(), vec![200]);
+    let canned = bot.transport().inner().inner();
+    let request = canned.1.lock().unwrap().take().unwrap();
+    assert_eq!(request.headers[0].0, "X-Proxy-Key");
+}
+
+#[test]
+fn timeouts() {
+    use std::time::Duration;
+    use telegram_types::bot::methods::{ChatTarget, GetUpdates, Method, SendMessage};
+    use telegram_types::client::{Attachment, Timeouts};
+
+    let mut timeouts = Timeouts {
+        default: Some(Duration::from_secs(5)),
+        upload: Some(Duration::from_secs(60)),
+        ..Timeouts::default()
+    };
+    timeouts.methods.insert(GetMe::NAME, Duration::from_secs(1));
+    let bot = Bot::with_transport("42:TOKEN", ()).timeouts(timeouts);
+    let timeout = |request: Request| request.timeout.map(|timeout| timeout.as_secs());
+    assert_eq!(timeout(bot.request(&GetMe).unwrap()), Some(1));
+    let send = SendMessage::new(ChatTarget::id(42), "hello");
+    assert_eq!(timeout(bot.request(&send).unwrap()), Some(5));
+    let upload = bot.upload_request(&send, &[Attachment::new("a", "a.txt", vec![])]);
+    assert_eq!(timeout(upload.unwrap()), Some(60));
+    let poll = GetUpdates {
+        timeout: Some(30),
+        ..GetUpdates::new()
+    };
+    assert_eq!(timeout(bot.request(&poll).unwrap()), Some(40));
+}
+
+/// Answers requests with the responses in order and keeps their bodies.
+#[derive(Default)]
+struct Script {
+    responses: std::sync::Mutex<std::collections::VecDeque<(u16, String)>>,
+    requests: std::sync::Mutex<Vec<serde_json::Value>>,
+}
+
+impl Transport for &'static Script {
+    type Error = std::io::Error;
+
+    async fn send(&self, request: Request) -> Result<Response, std::io::Error> {
+        let body = serde_json::from_slice(&request.body).unwrap();
+        self.requests.lock().unwrap().push(body);
+        let (status, body) = self.responses.lock().unwrap().pop_front().unwrap();
+        Ok(Response {
+            status,
+            body: body.into_bytes(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn update_stream() {
+    use futures_util::StreamExt;
+    use telegram_types::bot::types::UpdateId;
+    use telegram_types::client::UpdateStream;
+
+    let script: &'static Script = Box::leak(Default::default());
+    let batch = format!(
+        r#"{{"ok": true, "result": [{}, {}]}}"#,
+        include_str!("json/updates/message.json"),
+        include_str!("json/updates/edited_message.json")
+    );
+    script.responses.lock().unwrap().extend(vec![
+        (200, batch),
+        (502, "<html>Bad Gateway</html>".to_string()),
+    ]);
+    let bot = Bot::with_transport("42:TOKEN", script);
+    let mut updates = UpdateStream::new(bot).timeout(10);
+    let first = updates.next().await.unwrap().unwrap();
+    assert_eq!(first.update_id, UpdateId(65331110));
+    let second = updates.next().await.unwrap().unwrap();
+    assert_eq!(second.update_id, UpdateId(65331111));
+    assert!(matches!(
+        updates.next().await,
+        Some(Err(Error::Status(502)))
+    ));
+    assert_eq!(updates.offset(), Some(UpdateId(65331112)));
+    let requests = script.requests.lock().unwrap();
+    assert_eq!(requests[0], serde_json::json!({"timeout": 10}));
+    assert_eq!(
+        requests[1],
+        serde_json::json!({"offset": UpdateId(65331112), "timeout": 10})
+    );
+}
+
+#[tokio::test]
+async fn offset_store() {
+    use futures_util::StreamExt;
+    use std::sync::Arc;
+    use telegram_types::bot::types::UpdateId;
+    use telegram_types::client::{FileOffsetStore, MemoryOffsetStore, OffsetStore, UpdateStream};
+
+    let script: &'static Script = Box::leak(Default::default());
+    let batch = format!(
+        r#"{{"ok": true, "result": [{}, {}]}}"#,
+        include_str!("json/updates/message.json"),
+        include_str!("json/updates/edited_message.json")
+    );
+    script.responses.lock().unwrap().push_back((200, batch));
+    let store = Arc::new(MemoryOffsetStore::new());
+    store.save(UpdateId(65331110)).unwrap();
+    let bot = Bot::with_transport("42:TOKEN", script);
+    let mut updates = UpdateStream::new(bot).offset_store(store.clone());
+    assert!(updates.next().await.unwrap().is_ok());
+    // The first update isn't handled until the second is asked for.
+    assert_eq!(store.get(), Some(UpdateId(65331110)));
+    assert!(updates.next().await.unwrap().is_ok());
+    assert_eq!(store.get(), Some(UpdateId(65331111)));
+    updates.stop_handle().stop();
+    assert!(updates.next().await.is_none());
+    assert_eq!(store.get(), Some(UpdateId(65331112)));
+    let requests = script.requests.lock().unwrap();
+    assert_eq!(requests[0]["offset"], serde_json::json!(UpdateId(65331110)));
+
+    let path = std::env::temp_dir().join(format!("telegram-offset-{}", std::process::id()));
+    let file = FileOffsetStore::new(&path);
+    assert_eq!(file.load().unwrap(), None);
+    file.save(UpdateId(65331112)).unwrap();
+    assert_eq!(file.load().unwrap(), Some(UpdateId(65331112)));
+    std::fs::write(&path, "not a number").unwrap();
+    assert!(file.load().is_err());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn dedup_update_stream() {
+    use futures_util::StreamExt;
+    use telegram_types::bot::types::UpdateId;
+    use telegram_types::client::UpdateStream;
+
+    let script: &'static Script = Box::leak(Default::default());
+    let message = include_str!("json/updates/message.json");
+    let edited = include_str!("json/updates/edited_message.json");
+    let callback = include_str!("json/updates/callback_query.json");
+    let first = format!(r#"{{"ok": true, "result": [{}, {}]}}"#, message, edited);
+    // Delivered again, as after a restart from an older offset.
+    let second = format!(r#"{{"ok": true, "result": [{}, {}]}}"#, edited, callback);
+    script
+        .responses
+        .lock()
+        .unwrap()
+        .extend(vec![(200, first), (200, second)]);
+    let bot = Bot::with_transport("42:TOKEN", script);
+    let updates = UpdateStream::new(bot).dedup(100).take(3);
+    let ids: Vec<_> = updates.map(|u| u.unwrap().update_id).collect().await;
+    let expected = vec![UpdateId(65331110), UpdateId(65331111), UpdateId(65331116)];
+    assert_eq!(ids, expected);
+}
+
+#[tokio::test]
+async fn reconnect_update_stream() {
+    use futures_util::StreamExt;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use telegram_types::bot::types::UpdateId;
+    use telegram_types::client::{RetryPolicy, UpdateStream};
+
+    let script: &'static Script = Box::leak(Default::default());
+    let batch = format!(
+        r#"{{"ok": true, "result": [{}]}}"#,
+        include_str!("json/updates/message.json")
+    );
+    let unauthorized = include_str!("json/error.json").to_string();
+    script.responses.lock().unwrap().extend(vec![
+        (502, "<html>Bad Gateway</html>".to_string()),
+        (504, String::new()),
+        (200, batch),
+        (401, unauthorized),
+    ]);
+    let slept = Arc::new(Mutex::new(Vec::new()));
+    let sleep = {
+        let slept = slept.clone();
+        move |delay| {
+            slept.lock().unwrap().push(delay);
+            std::future::ready(())
+        }
+    };
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let on_error = {
+        let failures = failures.clone();
+        move |e: &Error, _| failures.lock().unwrap().push(e.to_string())
+    };
+    let bot = Bot::with_transport("42:TOKEN", script);
+    let mut updates = UpdateStream::new(bot)
+        .reconnect(RetryPolicy::default(), sleep)
+        .on_error(on_error);
+
+    let update = updates.next().await.unwrap().unwrap();
+    assert_eq!(update.update_id, UpdateId(65331110));
+    let failures = failures.lock().unwrap().clone();
+    assert_eq!(failures, ["HTTP status 502", "HTTP status 504"]);
+    let slept = slept.lock().unwrap().clone();
+    assert_eq!(slept.len(), 2);
+    assert!(slept[1] >= Duration::from_millis(500));
+    // Errors that calling again won't fix are yielded.
+    assert!(matches!(updates.next().await, Some(Err(Error::Api(_)))));
+}
+
+/// Never answers, like a long poll with no updates.
+#[derive(Clone)]
+struct Hang;
+
+impl Transport for Hang {
+    type Error = std::io::Error;
+
+    async fn send(&self, _: Request) -> Result<Response, std::io::Error> {
+        std::future::pending().await
+    }
+}
+
+#[tokio::test]
+async fn stop_update_stream() {
+    use futures_util::StreamExt;
+    use telegram_types::client::UpdateStream;
+
+    let script: &'static Script = Box::leak(Default::default());
+    let batch = format!(
+        r#"{{"ok": true, "result": [{}, {}]}}"#,
+        include_str!("json/updates/message.json"),
+        include_str!("json/updates/edited_message.json")
+    );
+    script.responses.lock().unwrap().push_back((200, batch));
+    let mut updates = UpdateStream::new(Bot::with_transport("42:TOKEN", script));
+    assert!(updates.next().await.unwrap().is_ok());
+    updates.stop_handle().stop();
+    // The rest of the batch is still yielded.
+    assert!(updates.next().await.unwrap().is_ok());
+    assert!(updates.next().await.is_none());
+
+    let mut updates = UpdateStream::new(Bot::with_transport("42:TOKEN", Hang));
+    let stop = updates.stop_handle();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        stop.stop();
+    });
+    assert!(updates.next().await.is_none());
+}
+
+#[tokio::test]
+async fn retry() {
+    use std::time::Duration;
+    use telegram_types::client::{Retry, RetryPolicy};
+
+    let script: &'static Script = Box::leak(Default::default());
+    script.responses.lock().unwrap().extend(vec![
+        (502, "<html>Bad Gateway</html>".to_string()),
+        (
+            429,
+            include_str!("json/errors/too_many_requests.json").to_string(),
+        ),
+        (200, include_str!("json/getMe.json").to_string()),
+    ]);
+    let slept: &'static std::sync::Mutex<Vec<Duration>> = Box::leak(Default::default());
+    let sleep = move |duration| {
+        slept.lock().unwrap().push(duration);
+        async {}
+    };
+    let bot = Retry::new(Bot::with_transport("42:TOKEN", script), sleep);
+    let me = bot.execute(&GetMe).await.unwrap();
+    assert_eq!(me.first_name, "ELPIS");
+    let slept = slept.lock().unwrap().clone();
+    assert!(slept[0] >= Duration::from_millis(250) && slept[0] <= Duration::from_millis(500));
+    assert_eq!(slept[1], Duration::from_secs(35));
+
+    // A flood wait longer than the cap is returned.
+    script.responses.lock().unwrap().push_back((
+        429,
+        include_str!("json/errors/too_many_requests.json").to_string(),
+    ));
+    let bot = bot.policy(RetryPolicy {
+        max_retry_after: Duration::from_secs(10),
+        ..RetryPolicy::default()
+    });
+    match bot.execute(&GetMe).await {
+        Err(Error::Api(e)) => assert_eq!(e.error_code, 429),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn metrics() {
+    use futures_util::StreamExt;
+    use std::sync::{Arc, Mutex};
+    use telegram_types::client::{CallMetrics, Metrics, MetricsSink, Retry, UpdateStream};
+
+    #[derive(Default)]
+    struct Sink {
+        calls: Mutex<Vec<(&'static str, Option<i32>, u32)>>,
+        updates: Mutex<Vec<usize>>,
+    }
+
+    impl MetricsSink for Sink {
+        fn call(&self, call: &CallMetrics) {
+            let call = (call.method, call.error_code(), call.retries);
+            self.calls.lock().unwrap().push(call);
+        }
+
+        fn updates_received(&self, count: usize) {
+            self.updates.lock().unwrap().push(count);
+        }
+    }
+
+    let script: &'static Script = Box::leak(Default::default());
+    script.responses.lock().unwrap().extend(vec![
+        (502, "<html>Bad Gateway</html>".to_string()),
+        (200, include_str!("json/getMe.json").to_string()),
+        (401, include_str!("json/error.json").to_string()),
+        (200, include_str!("json/update.json").to_string()),
+    ]);
+    let sink = Arc::new(Sink::default());
+    let bot = Bot::with_transport("42:TOKEN", script);
+    let retry = Retry::new(bot.clone(), |_| async {}).metrics(sink.clone());
+    retry.execute(&GetMe).await.unwrap();
+    let metrics = Metrics::new(bot.clone(), sink.clone());
+    assert!(metrics.execute(&GetMe).await.is_err());
+    let mut updates = UpdateStream::new(bot).metrics(sink.clone());
+    updates.next().await.unwrap().unwrap();
+    assert_eq!(
+        *sink.calls.lock().unwrap(),
+        vec![("getMe", None, 1), ("getMe", Some(401), 0)]
+    );
+    assert_eq!(*sink.updates.lock().unwrap(), vec![1]);
+}
+
+#[tokio::test]
+async fn throttle() {
+    use std::time::Duration;
+    use telegram_types::bot::methods::{ChatTarget, SendMessage};
+    use telegram_types::client::Throttle;
+
+    let script: &'static Script = Box::leak(Default::default());
+    for _ in 0..4 {
+        script
+            .responses
+            .lock()
+            .unwrap()
+            .push_back((200, include_str!("json/getMe.json").to_string()));
+    }
+    let slept: &'static std::sync::Mutex<Vec<Duration>> = Box::leak(Default::default());
+    let sleep = move |duration| {
+        slept.lock().unwrap().push(duration);
+        async {}
+    };
+    let bot = Throttle::new(Bot::with_transport("42:TOKEN", script), sleep);
+    // Not a message, not paced.
+    bot.execute(&GetMe).await.unwrap();
+    let send = |chat| SendMessage::new(ChatTarget::id(chat), "hello");
+    // `getMe.json` isn't a message, only the pacing matters here.
+    let _ = bot.execute(&send(42)).await;
+    let _ = bot.execute(&send(-42)).await;
+    let _ = bot.execute(&send(42)).await;
+    let slept = slept.lock().unwrap().clone();
+    // The group waits at most the global interval, the second message to 42 for a second.
+    let (last, rest) = slept.split_last().unwrap();
+    assert!(rest.iter().all(|wait| *wait <= Duration::from_secs(1) / 30));
+    assert!(*last > Duration::from_millis(900) && *last <= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn dispatcher() {
+    use std::sync::{Arc, Mutex};
+    use telegram_types::bot::types::Update;
+    use telegram_types::client::Dispatcher;
+
+    let update = |json: &str| serde_json::from_str::<Update>(json).unwrap();
+    let dispatcher = Dispatcher::new()
+        .on_message(|seen: Arc<Mutex<Vec<String>>>, message| async move {
+            seen.lock().unwrap().push(message.text.unwrap());
+        })
+        .on_callback_query(|seen: Arc<Mutex<Vec<String>>>, query| async move {
+            seen.lock().unwrap().push(query.data.unwrap());
+        })
+        .fallback(|seen: Arc<Mutex<Vec<String>>>, update| async move {
+            seen.lock()
+                .unwrap()
+                .push(format!("fallback {}", update.update_id.0));
+        });
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    for json in [
+        include_str!("json/updates/message.json"),
+        include_str!("json/updates/callback_query.json"),
+        include_str!("json/updates/edited_message.json"),
+    ] {
+        dispatcher.dispatch(seen.clone(), update(json)).await;
+    }
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec!["/start", "yes", "fallback 65331111"]
+    );
+
+    // Without a fallback, unhandled updates are dropped.
+    let dispatcher = Dispatcher::new().on_message(|(), _| async { panic!("not a message") });
+    let edited = update(include_str!("json/updates/edited_message.json"));
+    dispatcher.dispatch((), edited).await;
+}
+
+#[tokio::test]
+async fn dispatch_polls_and_payments() {
+    use std::sync::{Arc, Mutex};
+    use telegram_types::bot::types::Update;
+    use telegram_types::client::Dispatcher;
+
+    let dispatcher = Dispatcher::new()
+        .on_poll(|seen: Arc<Mutex<Vec<&str>>>, _| async move {
+            seen.lock().unwrap().push("poll");
+        })
+        .on_poll_answer(|seen: Arc<Mutex<Vec<&str>>>, _| async move {
+            seen.lock().unwrap().push("poll_answer");
+        });
+    #[cfg(feature = "payments")]
+    let dispatcher = dispatcher
+        .on_shipping_query(|seen: Arc<Mutex<Vec<&str>>>, _| async move {
+            seen.lock().unwrap().push("shipping_query");
+        })
+        .on_pre_checkout_query(|seen: Arc<Mutex<Vec<&str>>>, _| async move {
+            seen.lock().unwrap().push("pre_checkout_query");
+        });
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    for json in [
+        include_str!("json/updates/poll.json"),
+        include_str!("json/updates/poll_answer.json"),
+        include_str!("json/updates/shipping_query.json"),
+        include_str!("json/updates/pre_checkout_query.json"),
+    ] {
+        let update = serde_json::from_str::<Update>(json).unwrap();
+        dispatcher.dispatch(seen.clone(), update).await;
+    }
+    let mut expected = vec!["poll", "poll_answer"];
+    if cfg!(feature = "payments") {
+        expected.extend(["shipping_query", "pre_checkout_query"]);
+    }
+    assert_eq!(*seen.lock().unwrap(), expected);
+}
+
+#[tokio::test]
+async fn commands() {
+    use std::sync::{Arc, Mutex};
+    use telegram_types::bot::types::Update;
+    use telegram_types::client::{Commands, Dispatcher};
+
+    type Seen = Arc<Mutex<Vec<String>>>;
+    let message = |text: &str, command_len: usize| {
+        let mut update: serde_json::Value =
+            serde_json::from_str(include_str!("json/updates/message.json")).unwrap();
+        update["message"]["text"] = text.into();
+        update["message"]["entities"][0]["length"] = command_len.into();
+        serde_json::from_value::<Update>(update).unwrap()
+    };
+    let commands = Commands::new("@ElpisBot")
+        .command("start", "Say hello", |seen: Seen, _, args| async move {
+            seen.lock().unwrap().push(format!("start {:?}", args));
+        })
+        .command("roll", "Roll dice", |seen: Seen, _, args| async move {
+            seen.lock().unwrap().push(format!("roll {:?}", args));
+        });
+    assert_eq!(
+        serde_json::to_value(commands.set_my_commands()).unwrap(),
+        serde_json::json!({"commands": [
+            {"command": "start", "description": "Say hello"},
+            {"command": "roll", "description": "Roll dice"},
+        ]})
+    );
+    let dispatcher = Dispatcher::new()
+        .on_message(|seen: Seen, message| async move {
+            let text = message.text.unwrap();
+            seen.lock().unwrap().push(format!("message {:?}", text));
+        })
+        .on_commands(commands);
+    let seen = Seen::default();
+    for (text, command_len) in [
+        ("/start", 6),
+        ("/roll@elpisbot  2d6 ", 14),
+        ("/Roll 1d20", 5),
+        ("/roll@OtherBot 2d6", 14),
+        ("/help", 5),
+        ("say /start", 0),
+    ] {
+        dispatcher
+            .dispatch(seen.clone(), message(text, command_len))
+            .await;
+    }
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            r#"start """#,
+            r#"roll "2d6""#,
+            r#"roll "1d20""#,
+            r#"message "/roll@OtherBot 2d6""#,
+            r#"message "/help""#,
+            r#"message "say /start""#,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn bot_info() {
+    use telegram_types::bot::text::Command;
+    use telegram_types::bot::types::UserId;
+    use telegram_types::client::MockTransport;
+
+    let me = Response {
+        status: 200,
+        body: include_bytes!("json/getMe.json").to_vec(),
+    };
+    let mock = MockTransport::new();
+    mock.respond("getMe", me);
+    let bot = Bot::with_transport("42:TOKEN", mock.clone());
+    let info = bot.info().await.unwrap();
+    assert_eq!(bot.clone().info().await.unwrap(), info);
+    assert_eq!(mock.calls().len(), 1);
+
+    assert_eq!(info.id(), UserId(555689948));
+    assert_eq!(info.username(), "elpis_bot");
+    assert!(info.can_join_groups());
+    assert!(!info.can_read_all_group_messages());
+    assert!(info.is_for(&Command::parse("/start@Elpis_Bot").unwrap()));
+    assert!(info.is_for(&Command::parse("/start").unwrap()));
+    assert!(!info.is_for(&Command::parse("/start@other_bot").unwrap()));
+    let start = info.start_link("ref-42_a").unwrap();
+    assert_eq!(start, "https://t.me/elpis_bot?start=ref-42_a");
+    let group = info.start_group_link("welcome").unwrap();
+    assert_eq!(group, "https://t.me/elpis_bot?startgroup=welcome");
+    assert_eq!(info.start_link("not allowed"), None);
+    assert_eq!(info.start_link(&"a".repeat(65)), None);
+}
+
+#[cfg(feature = "full-serde")]
+#[tokio::test]
+async fn mock_transport() {
+    use telegram_types::bot::methods::{ChatTarget, SendMessage, TelegramResult};
+    use telegram_types::bot::types::{Message, Update, UpdateContent, User};
+    use telegram_types::client::{Dispatcher, MockTransport};
+
+    let mock = MockTransport::new();
+    let me: TelegramResult<User> = serde_json::from_str(include_str!("json/getMe.json")).unwrap();
+    mock.expect("getMe", me);
+    let update: Update = serde_json::from_str(include_str!("json/updates/message.json")).unwrap();
+    let message = match update.content.clone() {
+        UpdateContent::Message(message) => message,
+        _ => unreachable!(),
+    };
+    mock.expect("sendMessage", TelegramResult::ok(message));
+    let forbidden = TelegramResult::<Message>::error(403, "Forbidden");
+    mock.expect("sendMessage", forbidden);
+    let bot = Bot::with_transport("42:TOKEN", mock.clone());
+    assert!(bot.call(&GetMe).await.unwrap().is_bot);
+
+    let dispatcher = Dispatcher::new().on_message(|bot: Bot<MockTransport>, message| async move {
+        let reply = SendMessage::new(ChatTarget::Id(message.chat.id), "hi");
+        let result = bot.call(&reply).await;
+        assert!(result.is_ok() || matches!(result, Err(Error::Api(_))));
+    });
+    dispatcher.dispatch(bot.clone(), update.clone()).await;
+    dispatcher.dispatch(bot.clone(), update).await;
+    let calls = mock.calls();
+    assert_eq!(calls.len(), 3);
+    assert_eq!(calls[1].method, "sendMessage");
+    assert_eq!(calls[1].params["text"], "hi");
+    assert!(mock.pending().is_empty());
+
+    // Calls without an expected response fail.
+    let unexpected = mock.execute(&GetMe).await;
+    assert!(matches!(unexpected, Err(Error::Transport(_))));
+}
+
+#[cfg(feature = "full-serde")]
+#[tokio::test]
+async fn send_media_group() {
+    use telegram_types::bot::methods::{ChatTarget, SendMediaGroup, TelegramResult};
+    use telegram_types::bot::types::{FileId, FileToSend, InputMedia, Message};
+    use telegram_types::client::MockTransport;
+
+    let mock = MockTransport::new();
+    mock.expect("sendMediaGroup", TelegramResult::<Vec<Message>>::ok(vec![]));
+    mock.expect("sendMediaGroup", TelegramResult::<Vec<Message>>::ok(vec![]));
+    let bot = Bot::with_transport("42:TOKEN", mock.clone());
+    let id = || FileToSend::FileId(FileId("42".to_string()));
+
+    let upload = SendMediaGroup::upload(ChatTarget::id(42))
+        .photo("cocona.jpg", vec![1])
+        .media(InputMedia::photo(id()));
+    bot.send_media_group(upload).await.unwrap();
+    let hosted = SendMediaGroup::upload(ChatTarget::id(42))
+        .media(InputMedia::photo(id()))
+        .media(InputMedia::photo(id()));
+    bot.send_media_group(hosted).await.unwrap();
+    let calls = mock.calls();
+    // The upload goes as a multipart form, the rest as JSON.
+    assert_eq!(calls[0].params, serde_json::Value::Null);
+    assert_eq!(calls[1].params["media"][0]["media"], "42");
+
+    let single = SendMediaGroup::upload(ChatTarget::id(42)).photo("papika.jpg", vec![]);
+    let result = bot.send_media_group(single).await;
+    assert!(matches!(result, Err(Error::MediaGroup(_))));
+    assert_eq!(mock.calls().len(), 2);
+}
+
+#[cfg(feature = "full-serde")]
+#[tokio::test]
+async fn shortcuts() {
+    use telegram_types::bot::methods::TelegramResult;
+    use telegram_types::bot::types::{Message, Update, UpdateContent};
+    use telegram_types::client::MockTransport;
+
+    let json = include_str!("json/updates/callback_query.json");
+    let update: Update = serde_json::from_str(json).unwrap();
+    let query = match update.content {
+        UpdateContent::CallbackQuery(query) => query,
+        _ => unreachable!(),
+    };
+    let message = query.message.clone().unwrap();
+    let mock = MockTransport::new();
+    mock.expect("sendMessage", TelegramResult::ok((*message).clone()));
+    mock.expect("editMessageText", TelegramResult::ok((*message).clone()));
+    mock.expect("deleteMessage", TelegramResult::ok(true));
+    mock.expect("answerCallbackQuery", TelegramResult::ok(true));
+    mock.expect("answerCallbackQuery", TelegramResult::ok(true));
+
+    let reply: Message = message.reply_text(&mock, "pong").await.unwrap();
+    assert_eq!(reply.message_id, message.message_id);
+    message.edit_text(&mock, "edited").await.unwrap();
+    message.delete(&mock).await.unwrap();
+    query.answer(&mock).await.unwrap();
+    query.answer_text(&mock, "Saved").await.unwrap();
+
+    let calls = mock.calls();
+    let chat_id = serde_json::json!(message.chat.id);
+    let message_id = serde_json::json!(message.message_id);
+    assert_eq!(calls[0].params["chat_id"], chat_id);
+    assert_eq!(calls[0].params["reply_to_message_id"], message_id);
+    assert_eq!(calls[1].params["message_id"], message_id);
+    assert_eq!(calls[1].params["text"], "edited");
+    assert_eq!(calls[2].params["chat_id"], chat_id);
+    assert_eq!(calls[3].params["callback_query_id"], query.id);
+    assert_eq!(calls[4].params["text"], "Saved");
+    assert!(mock.pending().is_empty());
+}
+
+#[tokio::test]
+async fn keep_chat_action() {
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use telegram_types::bot::methods::{ChatAction, ChatTarget};
+    use telegram_types::client::{MockTransport, CHAT_ACTION_INTERVAL};
+
+    let mock = MockTransport::new();
+    let sleeps = Mutex::new(Vec::new());
+    let sleep = |delay: Duration| {
+        sleeps.lock().unwrap().push(delay);
+        tokio::task::yield_now()
+    };
+    let work = async {
+        while mock.calls().len() < 3 {
+            tokio::task::yield_now().await;
+        }
+        "done"
+    };
+    let chat = ChatTarget::id(42);
+    let action = ChatAction::Typing;
+    let done = telegram_types::client::keep_chat_action(&mock, chat, action, sleep, work);
+    // The mock has no responses, failing calls don't stop the work.
+    assert_eq!(done.await, "done");
+    let calls = mock.calls();
+    assert!(calls.iter().all(|call| call.method == "sendChatAction"));
+    assert_eq!(calls[0].params["action"], "typing");
+    assert_eq!(calls.len(), 3);
+    let sleeps = sleeps.into_inner().unwrap();
+    assert!(sleeps.iter().all(|&delay| delay == CHAT_ACTION_INTERVAL));
+
+    // Work done at once shows nothing.
+    let chat = ChatTarget::id(42);
+    let quick = async { 1 };
+    let action = ChatAction::UploadPhoto;
+    let sleep = |_| async {};
+    let one = telegram_types::client::keep_chat_action(&mock, chat, action, sleep, quick);
+    assert_eq!(one.await, 1);
+    assert_eq!(mock.calls().len(), 3);
+}
+
+#[tokio::test]
+async fn update_queue() {
+    use futures_util::{stream, StreamExt};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use telegram_types::client::{queue, Overflow};
+
+    // Blocking: polling stops while the queue is full.
+    let pulled = Arc::new(AtomicUsize::new(0));
+    let counter = pulled.clone();
+    let updates = stream::iter(0..10).inspect(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    let (feed, queued) = queue(updates, 2, Overflow::Block);
+    let feed = tokio::spawn(feed);
+    for _ in 0..10 {
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(queued.len(), 2);
+    assert_eq!(pulled.load(Ordering::SeqCst), 2);
+    let all: Vec<i32> = queued.collect().await;
+    assert_eq!(all, (0..10).collect::<Vec<_>>());
+    feed.await.unwrap();
+
+    // Dropping the oldest: the queue keeps the latest.
+    let dropped = Arc::new(Mutex::new(Vec::new()));
+    let on_drop = dropped.clone();
+    let overflow = Overflow::drop_oldest(move |n| on_drop.lock().unwrap().push(n));
+    let (feed, queued) = queue(stream::iter(0..10), 3, overflow);
+    feed.await;
+    assert_eq!(*dropped.lock().unwrap(), (0..7).collect::<Vec<_>>());
+    assert_eq!(queued.collect::<Vec<_>>().await, [7, 8, 9]);
+
+    // Polling stops once nothing takes the updates.
+    let (feed, queued) = queue(stream::iter(0..10), 1, Overflow::Block);
+    drop(queued);
+    feed.await;
+}
+
+#[cfg(feature = "full-serde")]
+#[tokio::test]
+async fn local_server() {
+    use telegram_types::bot::methods::{ChatTarget, SendDocument, TelegramResult};
+    use telegram_types::bot::types::{Attachment, File, FileId, FileToSend};
+    use telegram_types::client::{FileLocation, MockTransport, Server};
+
+    let canned = Canned(include_str!("json/getMe.json"), Default::default());
+    let server = Server::new("https://api.telegram.org/").test();
+    let bot = Bot::with_transport("42:TOKEN", canned).server(server);
+    bot.call(&GetMe).await.unwrap();
+    let request = bot.transport().1.lock().unwrap().take().unwrap();
+    let url = "https://api.telegram.org/bot42:TOKEN/test/getMe";
+    assert_eq!(request.url, url);
+
+    let mut file = File {
+        file_id: FileId("42".to_string()),
+        file_size: None,
+        file_path: Some("documents/file_0.pdf".to_string()),
+    };
+    let url = "https://api.telegram.org/file/bot42:TOKEN/test/documents/file_0.pdf";
+    let location = bot.file_location(&file);
+    assert_eq!(location, Some(FileLocation::Url(url.into())));
+    file.file_path = Some("/var/lib/telegram-bot-api/42:TOKEN/documents/file_0.pdf".into());
+    let location = bot.file_location(&file);
+    assert!(matches!(location, Some(FileLocation::Path(path)) if path.ends_with("file_0.pdf")));
+
+    // Moving to a local server logs out of the cloud, moving on closes the bot there.
+    let mock = MockTransport::new();
+    mock.expect("logOut", TelegramResult::ok(true));
+    mock.expect("deleteWebhook", TelegramResult::ok(true));
+    mock.expect("close", TelegramResult::ok(true));
+    let cloud = Bot::with_transport("42:TOKEN", mock.clone());
+    let local = Server::local("http://localhost:8081");
+    let local = cloud.move_to(local).await.unwrap();
+    let other = Server::local("http://10.0.0.2:8081");
+    local.move_to(other).await.unwrap();
+    let methods: Vec<String> = mock.calls().into_iter().map(|call| call.method).collect();
+    assert_eq!(methods, ["logOut", "deleteWebhook", "close"]);
+
+    // Files over the limit of the cloud server are refused before sending them.
+    let data = vec![0; Server::UPLOAD_LIMIT as usize + 1];
+    let big = Attachment::new("file", "big.bin", data);
+    let document = FileToSend::InputFile(big.input_file());
+    let send = SendDocument::new(ChatTarget::id(42), document);
+    let result = cloud.upload(&send, std::slice::from_ref(&big)).await;
+    assert!(matches!(result, Err(Error::FileTooLarge { .. })));
+    let refused = TelegramResult::<()>::error(400, "Bad Request");
+    mock.expect("sendDocument", refused);
+    let result = local.upload(&send, &[big]).await;
+    assert!(matches!(result, Err(Error::Api(_))));
+}
+
+#[tokio::test]
+async fn dispatch_by_chat() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use telegram_types::bot::types::{ChatId, Update, UpdateContent, UpdateId};
+    use telegram_types::client::Dispatcher;
+
+    let update: Update = serde_json::from_str(include_str!("json/updates/message.json")).unwrap();
+    let message = |id: i64, chat: i64, text: &str| {
+        let mut update = update.clone();
+        update.update_id = UpdateId(id);
+        if let UpdateContent::Message(message) = &mut update.content {
+            message.chat.id = ChatId(chat);
+            message.text = Some(text.to_string());
+        }
+        update
+    };
+    let updates = vec![
+        message(1, 1, "slow"),
+        message(2, 1, "after slow"),
+        message(3, 2, "fast"),
+        message(4, 1, "last"),
+        message(5, 2, "fast again"),
+    ];
+    type Handled = Arc<Mutex<Vec<String>>>;
+    let dispatcher = Dispatcher::new().on_message(|handled: Handled, message| async move {
+        let text = message.text.unwrap();
+        if text == "slow" {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        handled.lock().unwrap().push(text);
+    });
+    let run = |limit| {
+        let handled = Handled::default();
+        let updates = futures_util::stream::iter(updates.clone());
+        let dispatch = dispatcher.dispatch_by_chat(handled.clone(), updates, limit);
+        async move {
+            dispatch.await;
+            let handled = handled.lock().unwrap().clone();
+            handled
+        }
+    };
+    // Chat 2 doesn't wait for chat 1, which keeps its order.
+    let expected = ["fast", "fast again", "slow", "after slow", "last"];
+    assert_eq!(run(10).await, expected);
+    let one_at_a_time = ["slow", "after slow", "fast", "last", "fast again"];
+    assert_eq!(run(1).await, one_at_a_time);
+}
+
+#[cfg(feature = "full-serde")]
+#[tokio::test]
+async fn broadcast() {
+    use telegram_types::bot::methods::{SendMessage, TelegramResult};
+    use telegram_types::bot::types::{ChatId, Message, ResponseParameters, Update, UpdateContent};
+    use telegram_types::client::{MockTransport, Throttle};
+
+    let update: Update = serde_json::from_str(include_str!("json/updates/message.json")).unwrap();
+    let message = match update.content {
+        UpdateContent::Message(message) => message,
+        _ => unreachable!(),
+    };
+    let error = |code, description: &str| TelegramResult::<Message>::error(code, description);
+    let mut migrated = error(400, "Bad Request: group chat was upgraded to a supergroup");
+    migrated.parameters = Some(ResponseParameters {
+        migrate_to_chat_id: Some(ChatId(-1001)),
+        retry_after: None,
+    });
+    let mock = MockTransport::new();
+    for response in [
+        TelegramResult::ok(message),
+        error(403, "Forbidden: bot was blocked by the user"),
+        migrated,
+        error(400, "Bad Request: chat not found"),
+        error(400, "Bad Request: message is too long"),
+    ] {
+        mock.expect("sendMessage", response);
+    }
+    let throttle = Throttle::new(mock.clone(), |_| std::future::ready(()));
+    let chats = (1..=5).map(ChatId);
+    let report = throttle
+        .broadcast(chats, |chat| SendMessage::new(chat, "news"))
+        .await;
+    assert_eq!(report.delivered[0].0, ChatId(1));
+    let unreachable: Vec<_> = report.unreachable.iter().map(|(chat, _)| *chat).collect();
+    assert_eq!(unreachable, vec![ChatId(2), ChatId(4)]);
+    assert_eq!(report.migrated, vec![(ChatId(3), ChatId(-1001))]);
+    assert_eq!(report.failed[0].0, ChatId(5));
+    assert_eq!(
+        mock.calls()[4].params["chat_id"],
+        serde_json::json!(ChatId(5))
+    );
+}
+
+#[tokio::test]
+async fn cache() {
+    use std::time::Duration;
+    use telegram_types::bot::methods::{ChatTarget, GetChatAdministrators, GetChatMember};
+    use telegram_types::bot::types::UserId;
+    use telegram_types::client::{Cache, Layered, MockTransport};
+
+    let ok = |body: &str| Response {
+        status: 200,
+        body: body.as_bytes().to_vec(),
+    };
+    let user = r#"{"id":7,"is_bot":false,"first_name":"A"}"#;
+    let member = format!(r#"{{"ok":true,"result":{{"status":"left","user":{user}}}}}"#);
+    let mock = MockTransport::new();
+    mock.respond("getChatAdministrators", ok(r#"{"ok":true,"result":[]}"#));
+    mock.respond("getChatAdministrators", ok(r#"{"ok":true,"result":[]}"#));
+    mock.respond("getChatMember", ok(&member));
+    mock.respond("getChatMember", ok(&member));
+    mock.respond("getChatMember", ok(&member));
+    let cache = Cache::new(Duration::from_secs(60));
+    let bot = Bot::with_transport("42:TOKEN", Layered::new(mock.clone(), cache.clone()));
+
+    let admins = GetChatAdministrators {
+        chat_id: ChatTarget::id(1),
+    };
+    bot.call(&admins).await.unwrap();
+    bot.call(&admins).await.unwrap();
+    let seven = GetChatMember {
+        chat_id: ChatTarget::id(1),
+        user_id: UserId(7),
+    };
+    bot.call(&seven).await.unwrap();
+    bot.call(&seven).await.unwrap();
+    let eight = GetChatMember {
+        chat_id: ChatTarget::id(1),
+        user_id: UserId(8),
+    };
+    bot.call(&eight).await.unwrap();
+    assert_eq!(mock.calls().len(), 3);
+    assert_eq!(cache.len(), 3);
+
+    cache.invalidate_member(&ChatTarget::id(1), UserId(7));
+    assert_eq!(cache.len(), 1);
+    bot.call(&admins).await.unwrap();
+    bot.call(&seven).await.unwrap();
+    bot.call(&eight).await.unwrap();
+    assert_eq!(mock.calls().len(), 5);
+    assert!(mock.pending().is_empty());
+
+    cache.invalidate_chat(&ChatTarget::id(1));
+    assert!(cache.is_empty());
+
+    mock.respond("getChatAdministrators", ok(r#"{"ok":true,"result":[]}"#));
+    mock.respond("getChatAdministrators", ok(r#"{"ok":true,"result":[]}"#));
+    let other = Bot::with_transport("43:TOKEN", Layered::new(mock.clone(), cache.clone()));
+    bot.call(&admins).await.unwrap();
+    other.call(&admins).await.unwrap();
+    assert_eq!(mock.calls().len(), 7);
+    assert_eq!(cache.len(), 2);
+}
+
+#[tokio::test]
+async fn profile_photo() {
+    use telegram_types::bot::types::UserId;
+    use telegram_types::client::MockTransport;
+
+    let ok = |result: &str| Response {
+        status: 200,
+        body: format!(r#"{{"ok":true,"result":{result}}}"#).into_bytes(),
+    };
+    let size = |id, side| format!(r#"{{"file_id":"{id}","width":{side},"height":{side}}}"#);
+    let newest = [size("a", 160), size("b", 320), size("c", 640)].join(",");
+    let older = size("d", 640);
+    let photos = format!(r#"{{"total_count":2,"photos":[[{newest}],[{older}]]}}"#);
+    let mock = MockTransport::new();
+    mock.respond("getUserProfilePhotos", ok(&photos));
+    let file = r#"{"file_id":"b","file_path":"photos/file_1.jpg"}"#;
+    mock.respond("getFile", ok(file));
+    let jpeg = Response {
+        status: 200,
+        body: b"JPEG".to_vec(),
+    };
+    mock.respond("file_1.jpg", jpeg);
+    mock.respond("getUserProfilePhotos", ok(&photos));
+    mock.respond("getFile", ok(r#"{"file_id":"c"}"#));
+    let none = r#"{"total_count":0,"photos":[]}"#;
+    mock.respond("getUserProfilePhotos", ok(none));
+    let bot = Bot::with_transport("42:TOKEN", mock.clone());
+
+    let (photo, content) = bot.profile_photo(UserId(7), 200).await.unwrap().unwrap();
+    assert_eq!(photo.file_id.0, "b");
+    assert_eq!(content, b"JPEG");
+    // Too large to download, Telegram gave no path.
+    assert!(bot.profile_photo(UserId(7), 1000).await.unwrap().is_none());
+    assert!(bot.profile_photo(UserId(8), 200).await.unwrap().is_none());
+
+    let calls = mock.calls();
+    assert_eq!(calls[0].params["limit"], 1);
+    assert_eq!(calls[1].params["file_id"], "b");
+    assert_eq!(calls[2].method, "file_1.jpg");
+    assert!(mock.pending().is_empty());
+}
+
+/// A one-shot HTTP server for the transports of this crate.
+#[cfg(any(feature = "reqwest", feature = "hyper-client", feature = "blocking"))]
+mod server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answer one request with `status` and `body`, and hand back the request line and body it
+    /// carried.
+    pub fn serve(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header == "\r\n" {
+                    break;
+                }
+                let header = header.to_ascii_lowercase();
+                if let Some(value) = header.strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+            let response = format!(
+                "HTTP/1.1 {}\r\ncontent-length: {}\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            (
+                request_line.trim_end().to_string(),
+                String::from_utf8(request_body).unwrap(),
+            )
+        });
+        (url, handle)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+mod reqwest {
+    use super::server::serve;
+    use telegram_types::bot::methods::{ChatTarget, GetMe, SendMessage};
+    use telegram_types::client::{Bot, Error};
+
+    #[tokio::test]
+    async fn call() {
+        let (url, server) = serve("200 OK", include_str!("json/getMe.json"));
+        let bot = Bot::new("42:TOKEN").api_url(url);
+        let me = bot.call(&GetMe).await.unwrap();
+        assert_eq!(me.first_name, "ELPIS");
+        let (request_line, _) = server.join().unwrap();
+        assert_eq!(request_line, "POST /bot42:TOKEN/getMe HTTP/1.1");
+    }
+
+    #[tokio::test]
+    async fn download() {
+        use telegram_types::client::FileLocation;
+
+        let (url, server) = serve("200 OK", "JPEG");
+        let bot = Bot::new("42:TOKEN");
+        let path = "/file/bot42:TOKEN/photos/file_1.jpg";
+        let location = FileLocation::Url(format!("{}{}", url, path));
+        assert_eq!(bot.download(&location).await.unwrap(), b"JPEG");
+        let (request_line, _) = server.join().unwrap();
+        assert_eq!(request_line, format!("GET {} HTTP/1.1", path));
+    }
+
+    #[tokio::test]
+    async fn call_error() {
+        let (url, server) = serve("401 Unauthorized", include_str!("json/error.json"));
+        let bot = Bot::new("42:TOKEN").api_url(url);
+        let send = SendMessage::new(ChatTarget::id(42), "hello");
+        match bot.call(&send).await {
+            Err(Error::Api(e)) => assert_eq!(e.error_code, 401),
+            other => panic!("{:?}", other),
+        }
+        let (_, body) = server.join().unwrap();
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["text"], "hello");
+    }
+
+    #[tokio::test]
+    async fn bad_gateway() {
+        let (url, server) = serve("502 Bad Gateway", "<html>Bad Gateway</html>");
+        let bot = Bot::new("42:TOKEN").api_url(url);
+        match bot.call(&GetMe).await {
+            Err(Error::Status(502)) => {}
+            other => panic!("{:?}", other),
+        }
+        server.join().unwrap();
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        use std::time::Duration;
+        use telegram_types::client::Timeouts;
+
+        // Accepts the connection and never answers.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let timeouts = Timeouts {
+            default: Some(Duration::from_millis(50)),
+            ..Timeouts::default()
+        };
+        let bot = Bot::new("42:TOKEN").api_url(url).timeouts(timeouts);
+        assert!(matches!(bot.call(&GetMe).await, Err(Error::Transport(_))));
+        drop(listener);
+    }
+
+    #[tokio::test]
+    async fn error_hides_token() {
+        // Nothing listens on the port once the listener is dropped.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let bot = Bot::new("42:TOKEN").api_url(url);
+        let error = bot.call(&GetMe).await.unwrap_err();
+        assert!(matches!(error, Error::Transport(_)));
+        assert!(!error.to_string().contains("TOKEN"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn proxy() {
+        let (proxy, server) = serve("200 OK", include_str!("json/getMe.json"));
+        let bot = Bot::builder("42:TOKEN")
+            .api_url("http://api.telegram.invalid")
+            .proxy(proxy)
+            .build()
+            .unwrap();
+        bot.call(&GetMe).await.unwrap();
+        let (request_line, _) = server.join().unwrap();
+        let url = "http://api.telegram.invalid/bot42:TOKEN/getMe";
+        assert_eq!(request_line, format!("POST {} HTTP/1.1", url));
+
+        let invalid = Bot::builder("42:TOKEN").proxy("not a url").build();
+        assert!(matches!(invalid, Err(Error::Transport(_))));
+    }
+
+    #[tokio::test]
+    async fn resolve() {
+        let (url, server) = serve("200 OK", include_str!("json/getMe.json"));
+        let address: std::net::SocketAddr = url.trim_start_matches("http://").parse().unwrap();
+        let bot = Bot::builder("42:TOKEN")
+            .api_url(format!("http://api.telegram.invalid:{}", address.port()))
+            .resolve("api.telegram.invalid", address)
+            .local_address("127.0.0.1".parse().unwrap())
+            .build()
+            .unwrap();
+        bot.call(&GetMe).await.unwrap();
+        server.join().unwrap();
+    }
+}
+
+#[cfg(feature = "hyper-client")]
+mod hyper {
+    use super::server::serve;
+    use telegram_types::bot::methods::GetMe;
+    use telegram_types::client::{Bot, Error};
+
+    #[tokio::test]
+    async fn call() {
+        let (url, server) = serve("200 OK", include_str!("json/getMe.json"));
+        let bot = Bot::with_transport("42:TOKEN", hyper::Client::new()).api_url(url);
+        let me = bot.call(&GetMe).await.unwrap();
+        assert_eq!(me.first_name, "ELPIS");
+        let (request_line, body) = server.join().unwrap();
+        assert_eq!(request_line, "POST /bot42:TOKEN/getMe HTTP/1.1");
+        assert_eq!(body, "null");
+    }
+
+    #[tokio::test]
+    async fn download() {
+        use telegram_types::client::FileLocation;
+
+        let (url, server) = serve("200 OK", "JPEG");
+        let bot = Bot::with_transport("42:TOKEN", hyper::Client::new());
+        let path = "/file/bot42:TOKEN/photos/file_1.jpg";
+        let location = FileLocation::Url(format!("{}{}", url, path));
+        assert_eq!(bot.download(&location).await.unwrap(), b"JPEG");
+        let (request_line, _) = server.join().unwrap();
+        assert_eq!(request_line, format!("GET {} HTTP/1.1", path));
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        use std::time::Duration;
+        use telegram_types::client::{HyperError, Timeouts};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let timeouts = Timeouts {
+            default: Some(Duration::from_millis(50)),
+            ..Timeouts::default()
+        };
+        let bot = Bot::with_transport("42:TOKEN", hyper::Client::new())
+            .api_url(url)
+            .timeouts(timeouts);
+        match bot.call(&GetMe).await {
+            Err(Error::Transport(e)) => {
+                assert!(matches!(e.downcast_ref(), Some(HyperError::Timeout)))
+            }
+            other => panic!("{:?}", other),
+        }
+        drop(listener);
+    }
+
+    #[tokio::test]
+    async fn bad_gateway() {
+        let (url, server) = serve("502 Bad Gateway", "<html>Bad Gateway</html>");
+        let bot = Bot::with_transport("42:TOKEN", hyper::Client::new()).api_url(url);
+        match bot.call(&GetMe).await {
+            Err(Error::Status(502)) => {}
+            other => panic!("{:?}", other),
+        }
+        server.join().unwrap();
+    }
+
+    #[tokio::test]
+    async fn invalid_url() {
+        let bot = Bot::with_transport("42:TOKEN", hyper::Client::new()).api_url("not a url");
+        assert!(matches!(bot.call(&GetMe).await, Err(Error::Transport(_))));
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing {
+    use super::Canned;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use telegram_types::bot::methods::{ChatTarget, GetMe, SendMessage};
+    use telegram_types::bot::types::Update;
+    use telegram_types::client::{Bot, Dispatcher, Request, Response, Transport};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata};
+
+    /// Keeps every field of every span and event as `name=value`.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for &Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            let field = format!("{}={:?}", field.name(), value);
+            self.0.lock().unwrap().push(field);
+        }
+    }
+
+    impl tracing::Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes) -> Id {
+            span.record(&mut &*self);
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, values: &Record) {
+            values.record(&mut &*self);
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event) {
+            event.record(&mut &*self);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[tokio::test]
+    async fn call_span() {
+        let recorder = Recorder::default();
+        let _default = tracing::subscriber::set_default(recorder.clone());
+        let canned = Canned(include_str!("json/error.json"), Default::default());
+        let bot = Bot::with_transport("42:TOKEN", canned);
+        let send = SendMessage::new(ChatTarget::id(42), "hello");
+        assert!(bot.call(&send).await.is_err());
+        let fields = recorder.0.lock().unwrap();
+        assert!(fields.contains(&r#"method="sendMessage""#.to_string()));
+        assert!(fields.contains(&"chat_id=42".to_string()));
+        assert!(fields.contains(&"error_code=401".to_string()));
+        assert!(fields.iter().any(|field| field.starts_with("latency_ms=")));
+    }
+
+    /// Fails every call with an error quoting the URL, as reqwest's do.
+    struct Unreachable;
+
+    impl Transport for Unreachable {
+        type Error = std::io::Error;
+
+        async fn send(&self, request: Request) -> Result<Response, std::io::Error> {
+            let message = format!("error sending request for url ({})", request.url);
+            Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionRefused,
+                message,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn error_hides_token() {
+        use telegram_types::client::{Execute, Retry};
+
+        let recorder = Recorder::default();
+        let _default = tracing::subscriber::set_default(recorder.clone());
+        let bot = Retry::new(Bot::with_transport("42:TOKEN", Unreachable), |_| async {});
+        assert!(bot.execute(&GetMe).await.is_err());
+        let fields = recorder.0.lock().unwrap();
+        let errors: Vec<_> = fields.iter().filter(|f| f.starts_with("error=")).collect();
+        assert!(errors.len() > 1);
+        for error in errors {
+            assert!(error.contains("/bot<token>/getMe"), "{}", error);
+            assert!(!error.contains("TOKEN"), "{}", error);
+        }
+    }
+
+    #[tokio::test]
+    async fn update_span() {
+        let recorder = Recorder::default();
+        let _default = tracing::subscriber::set_default(recorder.clone());
+        let update = include_str!("json/updates/callback_query.json");
+        let update = serde_json::from_str::<Update>(update).unwrap();
+        Dispatcher::new().dispatch((), update).await;
+        let fields = recorder.0.lock().unwrap();
+        assert!(fields.contains(&"update_id=65331116".to_string()));
+        assert!(fields.contains(&r#"kind="callback_query""#.to_string()));
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use super::server::serve;
+    use telegram_types::bot::methods::{ChatTarget, GetMe, SendMessage};
+    use telegram_types::bot::types::UpdateId;
+    use telegram_types::client::blocking::{Bot, Transport, Updates};
+    use telegram_types::client::{Error, Request, Response};
+
+    #[test]
+    fn call() {
+        let (url, server) = serve("200 OK", include_str!("json/getMe.json"));
+        let bot = Bot::new("42:TOKEN").api_url(url);
+        let me = bot.call(&GetMe).unwrap();
+        assert_eq!(me.first_name, "ELPIS");
+        let (request_line, _) = server.join().unwrap();
+        assert_eq!(request_line, "POST /bot42:TOKEN/getMe HTTP/1.1");
+    }
+
+    #[test]
+    fn download() {
+        use telegram_types::client::FileLocation;
+
+        let (url, server) = serve("200 OK", "JPEG");
+        let bot = Bot::new("42:TOKEN");
+        let path = "/file/bot42:TOKEN/photos/file_1.jpg";
+        let location = FileLocation::Url(format!("{}{}", url, path));
+        assert_eq!(bot.download(&location).unwrap(), b"JPEG");
+        let (request_line, _) = server.join().unwrap();
+        assert_eq!(request_line, format!("GET {} HTTP/1.1", path));
+    }
+
+    #[test]
+    fn call_error() {
+        let (url, server) = serve("401 Unauthorized", include_str!("json/error.json"));
+        let bot = Bot::new("42:TOKEN").api_url(url);
+        let send = SendMessage::new(ChatTarget::id(42), "hello");
+        match bot.call(&send) {
+            Err(Error::Api(e)) => assert_eq!(e.error_code, 401),
+            other => panic!("{:?}", other),
+        }
+        let (_, body) = server.join().unwrap();
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["text"], "hello");
+    }
+
+    #[test]
+    fn bad_gateway() {
+        let (url, server) = serve("502 Bad Gateway", "<html>Bad Gateway</html>");
+        let bot = Bot::new("42:TOKEN").api_url(url);
+        match bot.call(&GetMe) {
+            Err(Error::Status(502)) => {}
+            other => panic!("{:?}", other),
+        }
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn error_hides_token() {
+        // Nothing listens on the port once the listener is dropped.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let bot = Bot::new("42:TOKEN").api_url(url);
+        let error = bot.call(&GetMe).unwrap_err();
+        assert!(matches!(error, Error::Transport(_)));
+        assert!(!error.to_string().contains("TOKEN"), "{}", error);
+        assert!(!format!("{:?}", error).contains("TOKEN"), "{:?}", error);
+    }
+
+    impl Transport for super::Script {
+        type Error = std::io::Error;
+
+        fn send(&self, request: Request) -> Result<Response, std::io::Error> {
+            let body = serde_json::from_slice(&request.body).unwrap();
+            self.requests.lock().unwrap().push(body);
+            let (status, body) = self.responses.lock().unwrap().pop_front().unwrap();
+            Ok(Response {
+                status,
+                body: body.into_bytes(),
+            })
+        }
+    }
+
+    #[test]
+    fn reconnect_updates() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::time::Duration;
+        use telegram_types::client::RetryPolicy;
+
+        let script = super::Script::default();
+        let batch = format!(
+            r#"{{"ok": true, "result": [{}]}}"#,
+            include_str!("json/updates/message.json")
+        );
+        script.responses.lock().unwrap().extend(vec![
+            (502, "<html>Bad Gateway</html>".to_string()),
+            (502, "<html>Bad Gateway</html>".to_string()),
+            (200, batch),
+        ]);
+        let policy = RetryPolicy {
+            max_retries: 1,
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        };
+        let retried = Arc::new(AtomicUsize::new(0));
+        let counter = retried.clone();
+        let on_error = move |_: &Error, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        };
+        let bot = Bot::with_transport("42:TOKEN", script);
+        let mut updates = Updates::new(bot).reconnect(policy).on_error(on_error);
+        // One retry, then the second failure in a row is yielded.
+        assert!(matches!(updates.next(), Some(Err(Error::Status(502)))));
+        let update = updates.next().unwrap().unwrap();
+        assert_eq!(update.update_id, UpdateId(65331110));
+        assert_eq!(retried.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn updates_after_error() {
+        let script = super::Script::default();
+        let batch = format!(
+            r#"{{"ok": true, "result": [{}]}}"#,
+            include_str!("json/updates/message.json")
+        );
+        script.responses.lock().unwrap().extend(vec![
+            (502, "<html>Bad Gateway</html>".to_string()),
+            (200, batch),
+        ]);
+        let mut updates = Updates::new(Bot::with_transport("42:TOKEN", script));
+        assert!(matches!(updates.next(), Some(Err(Error::Status(502)))));
+        let update = updates.next().unwrap().unwrap();
+        assert_eq!(update.update_id, UpdateId(65331110));
+        assert_eq!(updates.offset(), Some(UpdateId(65331111)));
+    }
+}
+
+#[test]
+fn multipart_upload() {
+    use telegram_types::bot::methods::{ChatTarget, SendDocument};
+    use telegram_types::bot::types::FileToSend;
+    use telegram_types::client::Attachment;
+
+    let file = Attachment::new("report", "report.txt", b"all good".to_vec());
+    let mut send = SendDocument::new(ChatTarget::id(42), FileToSend::InputFile(file.input_file()));
+    send.caption = Some("weekly \"report\"".into());
+    let bot = Bot::with_transport("42:TOKEN", ());
+    let request = bot.upload_request(&send, &[file]).unwrap();
+    assert_eq!(
+        request.url,
+        "https://api.telegram.org/bot42:TOKEN/sendDocument"
+    );
+    let boundary = request
+        .content_type
+        .strip_prefix("multipart/form-data; boundary=")
+        .unwrap();
+    let body = String::from_utf8(request.body).unwrap();
+    let part = |name: &str| {
+        let start = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            boundary, name
+        );
+        let part = &body[body.find(&start).unwrap() + start.len()..];
+        part[..part.find(&format!("\r\n--{}", boundary)).unwrap()].to_string()
+    };
+    assert_eq!(part("chat_id"), "\r\n\r\n42");
+    assert_eq!(part("document"), "\r\n\r\nattach://report");
+    assert_eq!(part("caption"), "\r\n\r\nweekly \"report\"");
+    assert_eq!(
+        part("report"),
+        "; filename=\"report.txt\"\r\nContent-Type: application/octet-stream\r\n\r\nall good"
+    );
+    assert!(body.ends_with(&format!("--{}--\r\n", boundary)));
+}
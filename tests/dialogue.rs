@@ -0,0 +1,113 @@
+#![cfg(feature = "dialogue")]
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use telegram_types::bot::types::{ChatId, Message, Update, UpdateContent, UserId};
+use telegram_types::dialogue::{Dialogue, DialogueKey, FileStorage, MemoryStorage, Storage};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Signup {
+    Name,
+    Photo { name: String },
+    Confirm { name: String },
+}
+
+fn message(text: &str) -> Message {
+    let mut message: Message = serde_json::from_str(include_str!("json/message.json")).unwrap();
+    message.text = Some(text.to_string());
+    message
+}
+
+/// Ask the name, then a photo, then confirm.
+fn step(dialogue: &Dialogue<Signup>, text: &str) -> Option<Signup> {
+    let next = match dialogue.get().unwrap() {
+        None => Some(Signup::Name),
+        Some(Signup::Name) => Some(Signup::Photo {
+            name: text.to_string(),
+        }),
+        Some(Signup::Photo { name }) => Some(Signup::Confirm { name }),
+        Some(Signup::Confirm { .. }) => None,
+    };
+    match &next {
+        Some(state) => dialogue.update(state.clone()).unwrap(),
+        None => dialogue.exit().unwrap(),
+    }
+    next
+}
+
+#[test]
+fn memory_storage() {
+    let storage = Arc::new(MemoryStorage::new());
+    let start = message("/start");
+    let dialogue = Dialogue::of_message(storage.clone(), &start).unwrap();
+    assert_eq!(
+        dialogue.key(),
+        DialogueKey::new(ChatId(88569449), UserId(42234))
+    );
+    assert_eq!(step(&dialogue, "/start"), Some(Signup::Name));
+    let name = Signup::Photo {
+        name: "Homura".to_string(),
+    };
+    assert_eq!(step(&dialogue, "Homura"), Some(name));
+
+    // Another user in the same chat has a dialogue of their own.
+    let mut other = message("/start");
+    other.from.as_mut().unwrap().id = UserId(1);
+    let other = Dialogue::of_message(storage.clone(), &other).unwrap();
+    assert_eq!(other.get().unwrap(), None);
+    assert_eq!(step(&other, "/start"), Some(Signup::Name));
+    assert_eq!(storage.len(), 2);
+
+    assert!(matches!(step(&dialogue, ""), Some(Signup::Confirm { .. })));
+    assert_eq!(step(&dialogue, "yes"), None);
+    assert_eq!(dialogue.get().unwrap(), None);
+    assert_eq!(storage.len(), 1);
+
+    let mut post = message("news");
+    post.from = None;
+    assert!(Dialogue::of_message(storage, &post).is_none());
+
+    let update: Update =
+        serde_json::from_str(include_str!("json/updates/callback_query.json")).unwrap();
+    let query = match update.content {
+        UpdateContent::CallbackQuery(query) => query,
+        _ => unreachable!(),
+    };
+    let key = DialogueKey::of_callback_query(&query).unwrap();
+    assert_eq!(key.user, query.from.id);
+    assert_eq!(key.chat, query.message.unwrap().chat.id);
+}
+
+#[test]
+fn file_storage() {
+    let path = std::env::temp_dir().join("telegram-types-dialogue.json");
+    let _ = std::fs::remove_file(&path);
+    let key = DialogueKey::new(ChatId(-100), UserId(42));
+    let state = Signup::Photo {
+        name: "Madoka".to_string(),
+    };
+
+    let storage: FileStorage<Signup> = FileStorage::open(&path).unwrap();
+    assert_eq!(storage.get(key).unwrap(), None);
+    storage.set(key, Signup::Name).unwrap();
+    storage.set(key, state.clone()).unwrap();
+    storage
+        .set(DialogueKey::new(ChatId(1), UserId(1)), Signup::Name)
+        .unwrap();
+    storage
+        .remove(DialogueKey::new(ChatId(1), UserId(1)))
+        .unwrap();
+
+    // A restarted bot picks the dialogue up where it was.
+    let storage = Arc::new(FileStorage::<Signup>::open(&path).unwrap());
+    assert_eq!(storage.path(), path);
+    let dialogue = Dialogue::new(storage, key);
+    assert_eq!(dialogue.get().unwrap(), Some(state));
+    dialogue.exit().unwrap();
+    let storage = FileStorage::<Signup>::open(&path).unwrap();
+    assert_eq!(storage.get(key).unwrap(), None);
+    std::fs::remove_file(&path).unwrap();
+
+    std::fs::write(&path, "not json").unwrap();
+    assert!(FileStorage::<Signup>::open(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "test-util")]
+use telegram_types::bot::text::Command;
+use telegram_types::bot::types::{
+    CallbackPayload, ChatId, ChatType, MessageEntityKind, UpdateContent, UserId,
+};
+use telegram_types::fake;
+
+#[test]
+fn text_message() {
+    let message = fake::text_message(7, "hi");
+    assert_eq!(message.chat.id, ChatId(7));
+    assert!(matches!(message.chat.kind, ChatType::Private { .. }));
+    assert_eq!(message.from.as_ref().unwrap().id, UserId(7));
+    assert_eq!(message.text.as_deref(), Some("hi"));
+    assert!(message.entities.is_empty());
+    assert_eq!(message.date, fake::date());
+
+    let group = fake::text_message(-100, "/roll@dice_bot 2d6");
+    assert!(matches!(group.chat.kind, ChatType::Supergroup { .. }));
+    assert_eq!(group.from.as_ref().unwrap().id, fake::USER_ID);
+    assert_eq!(group.entities[0].kind, MessageEntityKind::BotCommand);
+    let commands: Vec<Command> = group.commands().collect();
+    assert_eq!(commands[0].name, "roll");
+    assert_eq!(commands[0].username, Some("dice_bot"));
+    assert_ne!(group.message_id, message.message_id);
+
+    assert_eq!(fake::message(7).text, None);
+    assert_eq!(fake::user(3).id, UserId(3));
+    assert_eq!(fake::chat(-3).id, ChatId(-3));
+}
+
+#[test]
+fn callback_query_update() {
+    let query = fake::callback_query("vote:1");
+    assert_eq!(query.payload, CallbackPayload::Data("vote:1".to_string()));
+    assert_eq!(query.from.id, fake::USER_ID);
+    assert!(
+        query
+            .message
+            .as_ref()
+            .unwrap()
+            .from
+            .as_ref()
+            .unwrap()
+            .is_bot
+    );
+
+    let first = fake::update(UpdateContent::CallbackQuery(query));
+    let second = fake::update(UpdateContent::Message(fake::text_message(7, "hi")));
+    assert!(second.update_id > first.update_id);
+    assert_eq!(first.content.chat_id(), Some(ChatId(fake::USER_ID.0)));
+}
+
+#[cfg(feature = "inline-mode")]
+#[test]
+fn inline_query() {
+    let query = fake::inline_query("cats");
+    assert_eq!(query.query, "cats");
+    assert_eq!(query.from.id, fake::USER_ID);
+    assert_eq!(query.offset, "");
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "login")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use telegram_types::login::{LoginError, LoginWidget, WebApp};
+
+const TOKEN: &str = "123456:ABC-DEF1234ghIkl-zyx57W2v1u123ew11";
+const HASH: &str = "485d9c5d994ccbf9a5f2ab5eb12e3fa2b5f93d48489880e358018c3cabc8823c";
+
+fn at(seconds: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+#[test]
+fn verify_query() {
+    let widget = LoginWidget::new(TOKEN);
+    let query = format!(
+        "?id=42&first_name=Ada+Lovelace&username=ada&auth_date=1700000000\
+         &photo_url=https%3A%2F%2Ft.me%2Fi%2Fuserpic%2F320%2Fada.jpg&hash={}",
+        HASH
+    );
+    let login = widget.verify_query_at(&query, at(1700000060)).unwrap();
+    assert_eq!(login.id.0, 42);
+    assert_eq!(login.first_name, "Ada Lovelace");
+    assert_eq!(login.username.as_deref(), Some("ada"));
+    assert_eq!(login.last_name, None);
+    assert_eq!(login.auth_date.unix(), 1700000000);
+
+    // Changing any field breaks the hash.
+    let forged = query.replace("id=42", "id=43");
+    let result = widget.verify_query_at(&forged, at(1700000060));
+    assert_eq!(result, Err(LoginError::InvalidHash));
+    let other_bot = LoginWidget::new("654321:other");
+    let result = other_bot.verify_query_at(&query, at(1700000060));
+    assert_eq!(result, Err(LoginError::InvalidHash));
+
+    let two_days_later = at(1700000000 + 2 * 24 * 60 * 60);
+    let result = widget.verify_query_at(&query, two_days_later);
+    assert_eq!(result, Err(LoginError::Expired));
+    let widget = widget.max_age(Duration::from_secs(7 * 24 * 60 * 60));
+    assert!(widget.verify_query_at(&query, two_days_later).is_ok());
+}
+
+#[test]
+fn verify_fields() {
+    let widget = LoginWidget::new(TOKEN);
+    let fields = [
+        ("auth_date", "1700000000"),
+        ("first_name", "Ada Lovelace"),
+        ("hash", HASH),
+        ("id", "42"),
+        ("photo_url", "https://t.me/i/userpic/320/ada.jpg"),
+        ("username", "ada"),
+    ];
+    assert!(widget.verify_at(fields, at(1700000000)).is_ok());
+    let unsigned = fields.iter().copied().filter(|(name, _)| *name != "hash");
+    let result = widget.verify_at(unsigned, at(1700000000));
+    assert_eq!(result, Err(LoginError::Missing("hash")));
+    let result = widget.verify_query("id=42&hash=%zz");
+    assert_eq!(result, Err(LoginError::MalformedQuery));
+}
+
+#[test]
+fn web_app_init_data() {
+    use telegram_types::bot::types::ChatType;
+
+    let web_app = WebApp::new(TOKEN);
+    let init_data = include_str!("json/web_app_init_data.txt");
+    let data = web_app.verify_at(init_data, at(1700000060)).unwrap();
+    assert_eq!(data.query_id.as_deref(), Some("AAHdF6IQAAAAAN0XohDhrOrc"));
+    let user = data.user.unwrap();
+    assert_eq!((user.id.0, user.is_bot, user.is_premium), (42, false, true));
+    assert_eq!(user.last_name.as_deref(), Some("Lovelace"));
+    let chat = data.chat.unwrap();
+    assert_eq!(chat.id.0, -1001);
+    assert!(matches!(chat.kind, ChatType::Supergroup { .. }));
+    assert_eq!(data.chat_type.as_deref(), Some("supergroup"));
+    assert_eq!(data.start_param.as_deref(), Some("ref42"));
+    assert_eq!(data.auth_date.unix(), 1700000000);
+    assert!(data.receiver.is_none());
+
+    // Signed for the Login Widget's key, not the Mini App one.
+    let widget = LoginWidget::new(TOKEN);
+    let result = widget.verify_query_at(init_data, at(1700000060));
+    assert_eq!(result, Err(LoginError::InvalidHash));
+    let tampered = init_data.replace("ref42", "ref43");
+    let result = web_app.verify_at(&tampered, at(1700000060));
+    assert_eq!(result, Err(LoginError::InvalidHash));
+    let result = web_app.verify_at(init_data, at(1700000000 + 2 * 24 * 60 * 60));
+    assert_eq!(result, Err(LoginError::Expired));
+}
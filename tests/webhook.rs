@@ -0,0 +1,221 @@
+#![cfg(feature = "webhook")]
+use telegram_types::webhook::{Webhook, WebhookError, SECRET_TOKEN_HEADER};
+
+#[test]
+fn receive_http() {
+    let webhook = Webhook::new().secret("s3cret");
+    let request = |secret, content_type| {
+        http1::Request::post("/telegram")
+            .header(SECRET_TOKEN_HEADER, secret)
+            .header("Content-Type", content_type)
+            .body(include_str!("json/updates/message.json"))
+            .unwrap()
+    };
+    let update = webhook
+        .receive_http(&request("s3cret", "application/json; charset=utf-8"))
+        .unwrap();
+    assert_eq!(update.update_id.0, 65331110);
+    assert!(matches!(
+        webhook.receive_http(&request("guess", "application/json")),
+        Err(WebhookError::Unauthorized)
+    ));
+    assert!(matches!(
+        webhook.receive_http(&request("s3cret", "text/plain")),
+        Err(WebhookError::UnsupportedMediaType)
+    ));
+}
+
+#[test]
+fn receive_parts() {
+    let webhook = Webhook::new().max_body(16);
+    let body = include_str!("json/updates/message.json").as_bytes();
+    // No secret configured, any request passes the check.
+    assert!(matches!(
+        webhook.receive(None, Some(b"application/json"), body),
+        Err(WebhookError::TooLarge)
+    ));
+    assert!(matches!(
+        webhook.receive(None, None, b"{}"),
+        Err(WebhookError::UnsupportedMediaType)
+    ));
+    assert!(matches!(
+        webhook.receive(None, Some(b"Application/JSON"), b"{}"),
+        Err(WebhookError::Malformed(_))
+    ));
+}
+
+#[cfg(feature = "axum")]
+mod axum {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use telegram_types::bot::methods::{ChatTarget, SendMessage, WebhookReply};
+    use telegram_types::webhook::{Webhook, WebhookUpdate, SECRET_TOKEN_HEADER};
+    use tower::ServiceExt;
+
+    async fn handle(WebhookUpdate(update): WebhookUpdate) -> String {
+        update.update_id.0.to_string()
+    }
+
+    async fn reply(WebhookUpdate(update): WebhookUpdate) -> WebhookReply<SendMessage<'static>> {
+        let text = format!("update {}", update.update_id.0);
+        WebhookReply(SendMessage::new(ChatTarget::id(42), text))
+    }
+
+    fn app() -> Router {
+        let webhook = Webhook::new().secret("s3cret").max_body(16 * 1024);
+        Router::new()
+            .route("/telegram", post(handle))
+            .route("/reply", post(reply))
+            .with_state(webhook)
+    }
+
+    async fn send(path: &str, secret: Option<&str>, body: impl Into<Body>) -> (StatusCode, String) {
+        let mut request = Request::post(path).header("content-type", "application/json");
+        if let Some(secret) = secret {
+            request = request.header(SECRET_TOKEN_HEADER, secret);
+        }
+        let response = app()
+            .oneshot(request.body(body.into()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn receive_update() {
+        let update = include_str!("json/updates/message.json");
+        let (status, body) = send("/telegram", Some("s3cret"), update).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "65331110");
+    }
+
+    #[tokio::test]
+    async fn answer_with_method() {
+        let update = include_str!("json/updates/message.json");
+        let (status, body) = send("/reply", Some("s3cret"), update).await;
+        assert_eq!(status, StatusCode::OK);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["method"], "sendMessage");
+        assert_eq!(body["text"], "update 65331110");
+    }
+
+    #[tokio::test]
+    async fn wrong_secret() {
+        let update = include_str!("json/updates/message.json");
+        assert_eq!(
+            send("/telegram", None, update).await.0,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            send("/telegram", Some("guess"), update).await.0,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn refused_bodies() {
+        assert_eq!(
+            send("/telegram", Some("s3cret"), "{\"ok\": true}").await.0,
+            StatusCode::BAD_REQUEST
+        );
+        let large = format!(
+            "{{\"update_id\": 1, \"padding\": \"{}\"}}",
+            "x".repeat(32 * 1024)
+        );
+        assert_eq!(
+            send("/telegram", Some("s3cret"), large).await.0,
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+        // Without a content length the limit applies while reading.
+        let chunks =
+            futures_util::stream::iter(vec![Ok::<_, std::io::Error>(vec![b' '; 32 * 1024])]);
+        assert_eq!(
+            send("/telegram", Some("s3cret"), Body::from_stream(chunks))
+                .await
+                .0,
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+    }
+}
+
+#[cfg(all(feature = "client", feature = "full-serde"))]
+#[tokio::test]
+async fn ensure() {
+    use telegram_types::bot::methods::{SetWebhook, TelegramResult, UpdateTypes};
+    use telegram_types::bot::types::{Attachment, WebhookInfo};
+    use telegram_types::client::{Bot, MockTransport};
+    use telegram_types::webhook::WebhookChange;
+
+    let info = |json: serde_json::Value| -> TelegramResult<WebhookInfo> {
+        TelegramResult::ok(serde_json::from_value(json).unwrap())
+    };
+    let url = "https://example.com/telegram";
+    let updates = [UpdateTypes::Message, UpdateTypes::CallbackQuery];
+    let set = || SetWebhook::new(url).allowed_updates(&updates[..]);
+    let webhook = Webhook::new().secret("s3cret");
+    let mock = MockTransport::new();
+    let bot = Bot::with_transport("42:TOKEN", mock.clone());
+
+    // Nothing set yet.
+    let unset = serde_json::json!({
+        "url": "",
+        "has_custom_certificate": false,
+        "pending_update_count": 0,
+    });
+    mock.expect("getWebhookInfo", info(unset));
+    mock.expect("setWebhook", TelegramResult::ok(true));
+    let changes = webhook.ensure(&bot, set(), &[]).await.unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(
+        changes[0],
+        WebhookChange::Url {
+            current: "".to_string(),
+            wanted: url.to_string(),
+        }
+    );
+    let calls = mock.calls();
+    assert_eq!(calls[1].params["secret_token"], "s3cret");
+    assert_eq!(calls[1].params["allowed_updates"][1], "callback_query");
+
+    // Up to date, in another order.
+    let current = serde_json::json!({
+        "url": url,
+        "has_custom_certificate": false,
+        "pending_update_count": 3,
+        "max_connections": 40,
+        "allowed_updates": ["callback_query", "message"],
+    });
+    mock.expect("getWebhookInfo", info(current.clone()));
+    let changes = webhook.ensure(&bot, set(), &[]).await.unwrap();
+    assert!(changes.is_empty());
+    assert_eq!(mock.calls().len(), 3);
+
+    // A new certificate, pending updates to drop and a secret Telegram's requests were refused
+    // with.
+    let mut refused = current;
+    refused["last_error_message"] = "Wrong response from the webhook: 401 Unauthorized".into();
+    mock.expect("getWebhookInfo", info(refused));
+    mock.expect("setWebhook", TelegramResult::ok(true));
+    let certificate = Attachment::new("certificate", "cert.pem", b"PEM".to_vec());
+    let set = set()
+        .certificate(certificate.input_file())
+        .drop_pending_updates();
+    let changes = webhook.ensure(&bot, set, &[certificate]).await.unwrap();
+    let expected = [
+        WebhookChange::Certificate {
+            current: false,
+            wanted: true,
+        },
+        WebhookChange::PendingUpdates(3),
+        WebhookChange::SecretToken,
+    ];
+    assert_eq!(changes, expected);
+    assert_eq!(changes[1].to_string(), "drop 3 pending updates");
+    assert!(mock.pending().is_empty());
+}
@@ -0,0 +1,446 @@
+//! Development tasks, run with `cargo xtask <task>`.
+//!
+//! `codegen` turns a machine-readable Bot API description into Rust definitions in the style of
+//! this crate, and `coverage` lists what the description has that the crate doesn't. The
+//! description is the JSON format of the community spec at
+//! <https://github.com/PaulSonOfLars/telegram-bot-api-spec> (`api.json`).
+//!
+//! `fuzz-corpus` seeds the corpora of the targets in `fuzz/` with the JSON fixtures under
+//! `tests/json`.
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::{env, fs};
+
+const USAGE: &str = "\
+usage:
+    cargo xtask codegen <api.json> [out-dir]   write types.rs, methods.rs and method_table.rs
+                                               to out-dir (default: target/codegen)
+    cargo xtask coverage <api.json>            list types, fields and methods missing from src
+    cargo xtask fuzz-corpus                    copy the test fixtures into fuzz/corpus";
+
+/// The fixtures each fuzz target starts from, as paths under `tests/json`.
+const FUZZ_SEEDS: &[(&str, &[&str])] = &[
+    ("update", &["updates"]),
+    ("message", &["media", "message.json"]),
+    (
+        "telegram_result",
+        &[
+            "errors",
+            "error.json",
+            "update.json",
+            "update_my_chat_member.json",
+        ],
+    ),
+];
+
+#[derive(Deserialize)]
+struct Spec {
+    version: String,
+    methods: BTreeMap<String, Item>,
+    types: BTreeMap<String, Item>,
+}
+
+#[derive(Deserialize)]
+struct Item {
+    name: String,
+    #[serde(default)]
+    description: Vec<String>,
+    #[serde(default)]
+    returns: Vec<String>,
+    #[serde(default)]
+    fields: Vec<Field>,
+    #[serde(default)]
+    subtypes: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Field {
+    name: String,
+    types: Vec<String>,
+    required: bool,
+    #[serde(default)]
+    description: String,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["codegen", spec] => codegen(&load(spec), &workspace_root().join("target/codegen")),
+        ["codegen", spec, out] => codegen(&load(spec), Path::new(out)),
+        ["coverage", spec] => coverage(&load(spec)),
+        ["fuzz-corpus"] => fuzz_corpus(&workspace_root()),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn load(path: &str) -> Spec {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("failed to read {}: {}", path, e);
+        process::exit(1);
+    });
+    serde_json::from_str(&text).unwrap_or_else(|e| {
+        eprintln!("failed to parse {}: {}", path, e);
+        process::exit(1);
+    })
+}
+
+fn codegen(spec: &Spec, out: &Path) -> Result<(), String> {
+    fs::create_dir_all(out).map_err(|e| e.to_string())?;
+    let header = format!(
+        "// Generated by `cargo xtask codegen` from {}.\n",
+        spec.version
+    );
+
+    let mut types = header.clone();
+    for item in spec.types.values() {
+        types.push('\n');
+        write_type(&mut types, item);
+    }
+
+    // The spec doesn't record when each method appeared, so new entries get the spec version and
+    // older ones have to be corrected by hand.
+    let since = spec.version.trim_start_matches("Bot API ");
+    let mut methods = header.clone();
+    let mut table = Vec::new();
+    for item in spec.methods.values() {
+        methods.push('\n');
+        let struct_name = write_method(&mut methods, item);
+        // Methods sending a message name their chat for `Method::message_chat`.
+        let sends = ["send", "forward", "copy"]
+            .iter()
+            .any(|prefix| item.name.starts_with(prefix));
+        let chat = if sends && item.fields.iter().any(|field| field.name == "chat_id") {
+            ", chat_id"
+        } else {
+            ""
+        };
+        table.push([
+            struct_name,
+            format!("{:?}", item.name),
+            return_type(&item.returns),
+            format!("{:?}{}", since, chat),
+        ]);
+    }
+
+    let files = [
+        ("types.rs", types),
+        ("methods.rs", methods),
+        ("method_table.rs", header + "\n" + &method_table(&table)),
+    ];
+    for (name, text) in files.iter() {
+        let path = out.join(name);
+        fs::write(&path, text).map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+        // Formatting is best effort, the table is laid out by hand and skipped by rustfmt.
+        let _ = Command::new("rustfmt")
+            .arg("--edition=2018")
+            .arg(&path)
+            .status();
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn fuzz_corpus(root: &Path) -> Result<(), String> {
+    let fixtures = root.join("tests/json");
+    for (target, seeds) in FUZZ_SEEDS {
+        let corpus = root.join("fuzz/corpus").join(target);
+        fs::create_dir_all(&corpus).map_err(|e| e.to_string())?;
+        let mut files = Vec::new();
+        for seed in *seeds {
+            let path = fixtures.join(seed);
+            if path.is_dir() {
+                for entry in fs::read_dir(&path).map_err(|e| e.to_string())? {
+                    files.push(entry.map_err(|e| e.to_string())?.path());
+                }
+            } else {
+                files.push(path);
+            }
+        }
+        for file in &files {
+            let relative = file.strip_prefix(&fixtures).unwrap();
+            let name = relative.to_string_lossy().replace(['/', '\\'], "_");
+            fs::copy(file, corpus.join(name))
+                .map_err(|e| format!("failed to copy {}: {}", file.display(), e))?;
+        }
+        println!("{}: {} seeds", target, files.len());
+    }
+    Ok(())
+}
+
+fn coverage(spec: &Spec) -> Result<(), String> {
+    let source = read_sources(&workspace_root().join("src"))?;
+    let structs = declared(&source, &["pub struct ", "pub enum "]);
+    let mut missing = 0;
+
+    for item in spec.types.values() {
+        if !structs.contains(&item.name) {
+            println!("type {}", item.name);
+            missing += 1;
+        } else if let Some(body) = struct_body(&source, &item.name) {
+            for field in &item.fields {
+                let declared = body.contains(&format!("pub {}:", field.name))
+                    || body.contains(&format!("rename = \"{}\"", field.name));
+                if !declared {
+                    println!("field {}.{}", item.name, field.name);
+                    missing += 1;
+                }
+            }
+        }
+    }
+    for item in spec.methods.values() {
+        if !source.contains(&format!("{:?}", item.name)) {
+            println!("method {}", item.name);
+            missing += 1;
+        }
+    }
+    println!("{} items of {} missing", missing, spec.version);
+    Ok(())
+}
+
+fn read_sources(dir: &Path) -> Result<String, String> {
+    let mut source = String::new();
+    let entries = fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.is_dir() {
+            source += &read_sources(&path)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            source += &fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(source)
+}
+
+fn declared(source: &str, prefixes: &[&str]) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    for line in source.lines().map(str::trim_start) {
+        for prefix in prefixes {
+            if let Some(rest) = line.strip_prefix(prefix) {
+                let name: String = rest.chars().take_while(|c| c.is_alphanumeric()).collect();
+                names.insert(name);
+            }
+        }
+    }
+    names
+}
+
+/// The text between the braces of `pub struct name`, if it has any.
+fn struct_body<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let start = source
+        .find(&format!("pub struct {} ", name))
+        .or_else(|| source.find(&format!("pub struct {}<", name)))?;
+    let rest = &source[start..];
+    let open = rest.find('{')?;
+    let close = rest.find("\n}")?;
+    rest.get(open..close)
+}
+
+fn write_type(out: &mut String, item: &Item) {
+    if !item.subtypes.is_empty() {
+        let _ = writeln!(
+            out,
+            "// One of {}, write the enum by hand.",
+            item.subtypes.join(", ")
+        );
+        let _ = writeln!(out, "// pub enum {} {{}}", item.name);
+        return;
+    }
+    write_doc(out, "", &item.description.join("\n\n"));
+    out.push_str("#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]\n");
+    out.push_str(DERIVES);
+    let _ = writeln!(out, "pub struct {} {{", item.name);
+    for field in &item.fields {
+        write_field(out, field, false);
+    }
+    out.push_str("}\n");
+}
+
+fn write_method(out: &mut String, item: &Item) -> String {
+    let name = camel_case(&item.name);
+    let borrowed = item.fields.iter().any(|f| is_borrowed(&rust_type(f, true)));
+    let (generics, table_name) = if borrowed {
+        ("<'a>", format!("{}<'_>", name))
+    } else {
+        ("", name.clone())
+    };
+    write_doc(out, "", &item.description.join("\n\n"));
+    out.push_str("#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]\n");
+    out.push_str(DERIVES);
+    if item.fields.is_empty() {
+        let _ = writeln!(out, "pub struct {};", name);
+    } else {
+        let _ = writeln!(out, "pub struct {}{} {{", name, generics);
+        for field in &item.fields {
+            write_field(out, field, true);
+        }
+        out.push_str("}\n");
+    }
+    table_name
+}
+
+const DERIVES: &str = "\
+#[cfg_attr(feature = \"schemars\", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = \"arbitrary\", derive(arbitrary::Arbitrary))]
+";
+
+fn write_field(out: &mut String, field: &Field, borrowed: bool) {
+    write_doc(out, "    ", &field.description);
+    let ty = rust_type(field, borrowed);
+    if field.types.len() > 1 && !is_known_union(field) {
+        let _ = writeln!(out, "    // TODO: one of {}", field.types.join(", "));
+    }
+    let ident = match field.name.as_str() {
+        "type" => {
+            out.push_str("    #[serde(rename = \"type\")]\n");
+            "kind"
+        }
+        name => name,
+    };
+    if !field.required {
+        out.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+    }
+    if is_borrowed(&ty) && ty.starts_with("Cow") {
+        let helper = if field.required {
+            "arbitrary_cow"
+        } else {
+            "arbitrary_optional_cow"
+        };
+        let _ = writeln!(
+            out,
+            "    #[cfg_attr(feature = \"arbitrary\", arbitrary(with = crate::bot::utils::{}))]",
+            helper
+        );
+    }
+    if field.required {
+        let _ = writeln!(out, "    pub {}: {},", ident, ty);
+    } else {
+        let _ = writeln!(out, "    pub {}: Option<{}>,", ident, ty);
+    }
+}
+
+fn is_borrowed(ty: &str) -> bool {
+    ty.contains("'a")
+}
+
+fn is_known_union(field: &Field) -> bool {
+    matches!(field.name.as_str(), "chat_id" | "reply_markup") || is_file(field)
+}
+
+fn is_file(field: &Field) -> bool {
+    field.types.iter().any(|t| t == "InputFile")
+}
+
+/// The Rust type of a field, with request parameters borrowing like the handwritten methods.
+fn rust_type(field: &Field, borrowed: bool) -> String {
+    match field.name.as_str() {
+        "chat_id" if borrowed && field.types.len() > 1 => return "ChatTarget<'a>".to_string(),
+        "chat_id" => return "ChatId".to_string(),
+        "user_id" => return "UserId".to_string(),
+        "message_id" => return "MessageId".to_string(),
+        "update_id" => return "UpdateId".to_string(),
+        "parse_mode" => return "ParseMode".to_string(),
+        "reply_markup" if borrowed => return "ReplyMarkup".to_string(),
+        _ if is_file(field) => return "FileToSend".to_string(),
+        _ => (),
+    }
+    map_type(&field.types[0], borrowed)
+}
+
+fn map_type(spec_type: &str, borrowed: bool) -> String {
+    if let Some(inner) = spec_type.strip_prefix("Array of ") {
+        return format!("Vec<{}>", map_type(inner, false));
+    }
+    match spec_type {
+        "Integer" => "i64".to_string(),
+        "Float" => "f64".to_string(),
+        "Boolean" | "True" => "bool".to_string(),
+        "String" if borrowed => "Cow<'a, str>".to_string(),
+        "String" => "String".to_string(),
+        other => format!("types::{}", other),
+    }
+}
+
+fn return_type(returns: &[String]) -> String {
+    match returns.first() {
+        Some(ty) => map_type(ty, false),
+        None => "()".to_string(),
+    }
+}
+
+fn method_table(rows: &[[String; 4]]) -> String {
+    let mut widths = [0; 4];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let mut out = String::from("#[rustfmt::skip]\nimpl_method_table!(\n");
+    for (i, row) in rows.iter().enumerate() {
+        let separator = if i + 1 == rows.len() { "" } else { "," };
+        let _ = writeln!(
+            out,
+            "    [{:>w0$}, {:>w1$}, {:>w2$}, {:>w3$}]{}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            separator,
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+    out.push_str(");\n");
+    out
+}
+
+fn write_doc(out: &mut String, indent: &str, text: &str) {
+    for paragraph in text.split("\n\n").filter(|p| !p.trim().is_empty()) {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && indent.len() + 4 + line.len() + word.len() > 100 {
+                let _ = writeln!(out, "{}/// {}", indent, line);
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        let _ = writeln!(out, "{}/// {}", indent, line);
+        let _ = writeln!(out, "{}///", indent);
+    }
+    // Drop the trailing empty doc line.
+    let trailing = format!("{}///\n", indent);
+    if out.ends_with(&trailing) {
+        out.truncate(out.len() - trailing.len());
+    }
+}
+
+fn camel_case(method: &str) -> String {
+    let mut chars = method.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}